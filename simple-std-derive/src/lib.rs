@@ -0,0 +1,503 @@
+//!
+//! Derive macros for [simple-std](https://docs.rs/simple-std), use them through the `derive`
+//! feature of `simple-std` instead of depending on this crate directly.
+
+use proc_macro::{Delimiter, Spacing, TokenStream, TokenTree};
+use std::iter::Peekable;
+
+type Tokens = Peekable<proc_macro::token_stream::IntoIter>;
+
+///
+/// Implements `simple_std::Random` for an enum or struct
+///
+/// For enums, every variant has the same chance to be picked. For structs and variants with
+/// fields, every field is created with its own `Random` implementation.
+///
+/// # Example
+/// ```
+/// use simple_std::Random;
+///
+/// #[derive(Debug, Random)]
+/// enum Direction {
+///     Up,
+///     Down,
+///     Left,
+///     Right,
+/// }
+///
+/// #[derive(Debug, Random)]
+/// struct Enemy {
+///     direction: Direction,
+///     is_angry: bool,
+/// }
+///
+/// println!("The enemy walks {:?}", Direction::random());
+/// println!("A wild {:?} appears!", Enemy::random());
+/// ```
+#[proc_macro_derive(Random)]
+pub fn derive_random(input: TokenStream) -> TokenStream {
+    expand(parse(input, "Random").and_then(random_impl))
+}
+
+///
+/// Implements `simple_std::Storable` for an enum or struct, so it can be saved to a file
+///
+/// Every field has to be `Storable` too. Structs with named fields are stored as JSON objects,
+/// tuple structs as arrays and unit structs as `null`. Enum variants without fields are stored
+/// as their name, and variants with fields as an object with the name as the only key.
+///
+/// # Example
+/// ```no_run
+/// use simple_std::Storable;
+///
+/// #[derive(Storable)]
+/// enum Class {
+///     Warrior,
+///     Wizard { spells: Vec<String> },
+/// }
+///
+/// #[derive(Storable)]
+/// struct Player {
+///     name: String,
+///     level: u32,
+///     class: Class,
+/// }
+///
+/// let player = Player {
+///     name: "Ferris".to_owned(),
+///     level: 1,
+///     class: Class::Warrior,
+/// };
+/// player.save("player.json");
+///
+/// let player = Player::load("player.json");
+/// println!("Welcome back, {}!", player.name);
+/// ```
+#[proc_macro_derive(Storable)]
+pub fn derive_storable(input: TokenStream) -> TokenStream {
+    expand(parse(input, "Storable").and_then(storable_impl))
+}
+
+fn expand(result: Result<String, String>) -> TokenStream {
+    match result {
+        Ok(code) => code.parse().expect("generated invalid code"),
+        Err(message) => format!("compile_error!({:?});", message)
+            .parse()
+            .expect("generated invalid error"),
+    }
+}
+
+enum Fields {
+    Unit,
+    Tuple(usize),
+    Named(Vec<String>),
+}
+
+enum Item {
+    Struct(String, Fields),
+    Enum(String, Vec<(String, Fields)>),
+}
+
+/// parses the struct or enum that `#[derive(<derive>)]` was put on
+fn parse(input: TokenStream, derive: &str) -> Result<Item, String> {
+    let mut tokens = input.into_iter().peekable();
+    skip_attributes_and_visibility(&mut tokens);
+
+    let kind = next_ident(&mut tokens)?;
+    let name = next_ident(&mut tokens)?;
+
+    if let Some(TokenTree::Punct(punct)) = tokens.peek() {
+        if punct.as_char() == '<' {
+            return Err(format!("`#[derive({})]` does not support generics", derive));
+        }
+    }
+
+    match kind.as_str() {
+        "struct" => {
+            let fields = match tokens.next() {
+                Some(TokenTree::Group(group)) => fields_of(group.delimiter(), group.stream()),
+                _ => Fields::Unit,
+            };
+            Ok(Item::Struct(name, fields))
+        }
+        "enum" => {
+            let variants = match tokens.next() {
+                Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
+                    variants_of(group.stream())?
+                }
+                _ => return Err("expected the variants of the enum".to_owned()),
+            };
+
+            if variants.is_empty() {
+                return Err(format!(
+                    "`#[derive({})]` needs at least one variant",
+                    derive
+                ));
+            }
+            Ok(Item::Enum(name, variants))
+        }
+        _ => Err(format!(
+            "`#[derive({})]` only works on enums and structs",
+            derive
+        )),
+    }
+}
+
+fn random_impl(item: Item) -> Result<String, String> {
+    let (name, body) = match item {
+        Item::Struct(name, fields) => {
+            let body = construct(&name, &fields);
+            (name, body)
+        }
+        Item::Enum(name, variants) => {
+            let arms = variants
+                .iter()
+                .enumerate()
+                .map(|(i, (variant, fields))| {
+                    let pattern = if i == variants.len() - 1 {
+                        "_".to_owned()
+                    } else {
+                        i.to_string()
+                    };
+                    let path = format!("{}::{}", name, variant);
+                    format!("{} => {},", pattern, construct(&path, fields))
+                })
+                .collect::<String>();
+
+            let body = format!(
+                "match ::simple_std::SimpleRng::next_int_range_u64(rng, 0..{}u64) {{ {} }}",
+                variants.len(),
+                arms
+            );
+            (name, body)
+        }
+    };
+
+    Ok(format!(
+        "impl ::simple_std::Random for {} {{
+            #[allow(unused_variables)]
+            fn random_with(rng: &mut ::simple_std::SimpleRng) -> Self {{
+                {}
+            }}
+        }}",
+        name, body
+    ))
+}
+
+/// the expression that creates `path` with random fields
+fn construct(path: &str, fields: &Fields) -> String {
+    const RANDOM_FIELD: &str = "::simple_std::Random::random_with(rng)";
+
+    match fields {
+        Fields::Unit => path.to_owned(),
+        Fields::Tuple(count) => format!("{}({})", path, vec![RANDOM_FIELD; *count].join(", ")),
+        Fields::Named(names) => {
+            let fields = names
+                .iter()
+                .map(|name| format!("{}: {}", name, RANDOM_FIELD))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("{} {{ {} }}", path, fields)
+        }
+    }
+}
+
+fn storable_impl(item: Item) -> Result<String, String> {
+    let (name, to_json, from_json) = match item {
+        Item::Struct(name, fields) => {
+            let pattern = pattern(&name, &fields);
+            let to_json = format!(
+                "let {} = self; {}",
+                pattern,
+                fields_to_json(&fields).unwrap_or_else(|| "::simple_std::Json::Null".to_owned())
+            );
+            let from_json = match fields {
+                Fields::Unit => format!(
+                    "if __simple_std_json.is_null() {{ Some({}) }} else {{ None }}",
+                    name
+                ),
+                _ => fields_from_json(&name, &fields),
+            };
+            (name, to_json, from_json)
+        }
+        Item::Enum(name, variants) => {
+            let to_json_arms = variants
+                .iter()
+                .map(|(variant, fields)| {
+                    let path = format!("{}::{}", name, variant);
+                    let json = match fields_to_json(fields) {
+                        Some(json) => format!(
+                            "{{
+                                let mut __simple_std_object = ::std::collections::BTreeMap::new();
+                                __simple_std_object.insert({:?}.to_owned(), {});
+                                ::simple_std::Json::Object(__simple_std_object)
+                            }}",
+                            variant, json
+                        ),
+                        None => format!("::simple_std::Json::String({:?}.to_owned())", variant),
+                    };
+                    format!("{} => {},", pattern(&path, fields), json)
+                })
+                .collect::<String>();
+
+            let (unit_arms, field_arms) = variants.iter().fold(
+                (String::new(), String::new()),
+                |(mut unit_arms, mut field_arms), (variant, fields)| {
+                    let path = format!("{}::{}", name, variant);
+                    match fields {
+                        Fields::Unit => unit_arms += &format!("{:?} => Some({}),", variant, path),
+                        _ => {
+                            field_arms += &format!(
+                                "{:?} => {{ let __simple_std_json = __simple_std_value; {} }}",
+                                variant,
+                                fields_from_json(&path, fields)
+                            )
+                        }
+                    }
+                    (unit_arms, field_arms)
+                },
+            );
+
+            let to_json = format!("match self {{ {} }}", to_json_arms);
+            let from_json = format!(
+                "if let Some(__simple_std_variant) = __simple_std_json.as_str() {{
+                    return match __simple_std_variant {{ {} _ => None }};
+                }}
+                let __simple_std_object = __simple_std_json.as_object()?;
+                if __simple_std_object.len() != 1 {{
+                    return None;
+                }}
+                let (__simple_std_variant, __simple_std_value) =
+                    __simple_std_object.iter().next()?;
+                match __simple_std_variant.as_str() {{ {} _ => None }}",
+                unit_arms, field_arms
+            );
+            (name, to_json, from_json)
+        }
+    };
+
+    Ok(format!(
+        "impl ::simple_std::Storable for {} {{
+            fn to_json(&self) -> ::simple_std::Json {{
+                {}
+            }}
+
+            #[allow(unused_variables)]
+            fn from_json(__simple_std_json: &::simple_std::Json) -> ::std::option::Option<Self> {{
+                {}
+            }}
+        }}",
+        name, to_json, from_json
+    ))
+}
+
+/// the pattern that binds all fields of `path` to the variables from [`binding`]
+fn pattern(path: &str, fields: &Fields) -> String {
+    match fields {
+        Fields::Unit => path.to_owned(),
+        Fields::Tuple(count) => {
+            let bindings = (0..*count)
+                .map(|i| binding(&i.to_string()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("{}({})", path, bindings)
+        }
+        Fields::Named(names) => {
+            let bindings = names
+                .iter()
+                .map(|name| format!("{}: {}", name, binding(name)))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("{} {{ {} }}", path, bindings)
+        }
+    }
+}
+
+/// the variable that a field is bound to, which can't be the same as a variable of the
+/// generated code, like a field called `object` would be
+fn binding(field: &str) -> String {
+    format!("__simple_std_field_{}", json_key(field))
+}
+
+/// the name of the field in the stored object
+fn json_key(field: &str) -> &str {
+    field.trim_start_matches("r#")
+}
+
+/// the expression that stores the fields bound by [`pattern`], or `None` for unit fields
+fn fields_to_json(fields: &Fields) -> Option<String> {
+    const TO_JSON: &str = "::simple_std::Storable::to_json";
+
+    match fields {
+        Fields::Unit => None,
+        Fields::Tuple(count) => {
+            let values = (0..*count)
+                .map(|i| format!("{}({})", TO_JSON, binding(&i.to_string())))
+                .collect::<Vec<_>>()
+                .join(", ");
+            Some(format!("::simple_std::Json::Array(vec![{}])", values))
+        }
+        Fields::Named(names) => {
+            let inserts = names
+                .iter()
+                .map(|name| {
+                    format!(
+                        "__simple_std_object.insert({:?}.to_owned(), {}({}));",
+                        json_key(name),
+                        TO_JSON,
+                        binding(name)
+                    )
+                })
+                .collect::<String>();
+            Some(format!(
+                "{{
+                    let mut __simple_std_object = ::std::collections::BTreeMap::new();
+                    {}
+                    ::simple_std::Json::Object(__simple_std_object)
+                }}",
+                inserts
+            ))
+        }
+    }
+}
+
+/// the expression that loads `path` with its fields from the variable `__simple_std_json`
+fn fields_from_json(path: &str, fields: &Fields) -> String {
+    const FROM_JSON: &str = "::simple_std::Storable::from_json";
+
+    match fields {
+        Fields::Unit => format!("Some({})", path),
+        Fields::Tuple(count) => {
+            let values = (0..*count)
+                .map(|i| format!("{}(&__simple_std_array[{}])?", FROM_JSON, i))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                "let __simple_std_array = __simple_std_json.as_array()?;
+                if __simple_std_array.len() != {} {{
+                    return None;
+                }}
+                Some({}({}))",
+                count, path, values
+            )
+        }
+        Fields::Named(names) => {
+            let values = names
+                .iter()
+                .map(|name| {
+                    format!(
+                        "{}: {}(&__simple_std_json[{:?}])?",
+                        name,
+                        FROM_JSON,
+                        json_key(name)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                "__simple_std_json.as_object()?; Some({} {{ {} }})",
+                path, values
+            )
+        }
+    }
+}
+
+fn fields_of(delimiter: Delimiter, stream: TokenStream) -> Fields {
+    match delimiter {
+        Delimiter::Parenthesis => Fields::Tuple(split_commas(stream).len()),
+        Delimiter::Brace => Fields::Named(
+            split_commas(stream)
+                .into_iter()
+                .filter_map(|field| {
+                    let mut tokens = field
+                        .into_iter()
+                        .collect::<TokenStream>()
+                        .into_iter()
+                        .peekable();
+                    skip_attributes_and_visibility(&mut tokens);
+                    next_ident(&mut tokens).ok()
+                })
+                .collect(),
+        ),
+        _ => Fields::Unit,
+    }
+}
+
+fn variants_of(stream: TokenStream) -> Result<Vec<(String, Fields)>, String> {
+    split_commas(stream)
+        .into_iter()
+        .map(|variant| {
+            let mut tokens = variant
+                .into_iter()
+                .collect::<TokenStream>()
+                .into_iter()
+                .peekable();
+            skip_attributes_and_visibility(&mut tokens);
+            let name = next_ident(&mut tokens)?;
+            let fields = match tokens.next() {
+                Some(TokenTree::Group(group)) => fields_of(group.delimiter(), group.stream()),
+                // no fields, or a `= discriminant`
+                _ => Fields::Unit,
+            };
+            Ok((name, fields))
+        })
+        .collect()
+}
+
+/// splits the tokens at every comma that isn't inside of a group or generic arguments
+fn split_commas(stream: TokenStream) -> Vec<Vec<TokenTree>> {
+    let mut parts = vec![Vec::new()];
+    let mut angle_depth = 0usize;
+    let mut previous_joint_minus = false;
+
+    for token in stream {
+        if let TokenTree::Punct(punct) = &token {
+            match punct.as_char() {
+                ',' if angle_depth == 0 => {
+                    parts.push(Vec::new());
+                    previous_joint_minus = false;
+                    continue;
+                }
+                '<' => angle_depth += 1,
+                // the `>` of `->` in function pointer types doesn't close anything
+                '>' if !previous_joint_minus => angle_depth = angle_depth.saturating_sub(1),
+                _ => {}
+            }
+            previous_joint_minus = punct.as_char() == '-' && punct.spacing() == Spacing::Joint;
+        } else {
+            previous_joint_minus = false;
+        }
+        parts.last_mut().expect("parts is never empty").push(token);
+    }
+
+    parts.retain(|part| !part.is_empty());
+    parts
+}
+
+/// skips `#[...]` attributes (including doc comments) and `pub`, `pub(crate)`, ...
+fn skip_attributes_and_visibility(tokens: &mut Tokens) {
+    loop {
+        match tokens.peek() {
+            Some(TokenTree::Punct(punct)) if punct.as_char() == '#' => {
+                tokens.next();
+                tokens.next();
+            }
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "pub" => {
+                tokens.next();
+                if let Some(TokenTree::Group(group)) = tokens.peek() {
+                    if group.delimiter() == Delimiter::Parenthesis {
+                        tokens.next();
+                    }
+                }
+            }
+            _ => return,
+        }
+    }
+}
+
+fn next_ident(tokens: &mut Tokens) -> Result<String, String> {
+    match tokens.next() {
+        Some(TokenTree::Ident(ident)) => Ok(ident.to_string()),
+        other => Err(format!("expected a name, found {:?}", other)),
+    }
+}
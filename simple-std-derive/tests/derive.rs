@@ -0,0 +1,194 @@
+use simple_std::{temp_file, Json, Random, SimpleRng, Storable};
+use std::iter::repeat_with;
+
+#[derive(Debug, PartialEq, Random)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Debug, Random)]
+#[allow(dead_code)]
+enum Shape {
+    /// a circle with a radius
+    Circle(f64),
+    Rectangle {
+        width: f64,
+        height: f64,
+    },
+    Point,
+}
+
+#[derive(Debug, Random)]
+pub struct Enemy {
+    pub direction: Direction,
+    pub(crate) health: u8,
+    is_angry: bool,
+    #[allow(clippy::type_complexity)]
+    callback: Wrapper<Option<fn(u8, u8) -> u8>>,
+    generic: Wrapper<Vec<(u8, u8)>>,
+}
+
+#[derive(Debug, Random)]
+#[allow(dead_code)]
+struct Pair(u8, Direction);
+
+#[derive(Debug, Random)]
+struct Unit;
+
+#[derive(Debug)]
+struct Wrapper<T>(T);
+
+impl<T: Default> Random for Wrapper<T> {
+    fn random_with(_: &mut SimpleRng) -> Self {
+        Wrapper(T::default())
+    }
+}
+
+#[test]
+fn all_variants() {
+    [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ]
+    .iter()
+    .for_each(|expected| {
+        assert!(repeat_with(Direction::random)
+            .take(1000)
+            .any(|direction| direction == *expected));
+    });
+}
+
+#[test]
+fn variants_with_fields() {
+    let shapes = repeat_with(Shape::random).take(1000).collect::<Vec<_>>();
+    assert!(shapes
+        .iter()
+        .any(|s| matches!(s, Shape::Circle(r) if (0.0..1.0).contains(r))));
+    assert!(shapes.iter().any(|s| matches!(s, Shape::Rectangle { .. })));
+    assert!(shapes.iter().any(|s| matches!(s, Shape::Point)));
+}
+
+#[test]
+fn structs() {
+    let mut rng = SimpleRng::from_seed(0);
+    let enemy = Enemy::random_with(&mut rng);
+    assert!(enemy.callback.0.is_none());
+    assert!(enemy.generic.0.is_empty());
+    let _ = (enemy.direction, enemy.health, enemy.is_angry);
+
+    let Pair(_, _) = Pair::random();
+    let Unit = Unit::random();
+}
+
+#[derive(Debug, PartialEq, Storable)]
+enum Class {
+    Warrior,
+    Archer(u8),
+    Wizard { spells: Vec<String> },
+}
+
+#[derive(Debug, PartialEq, Storable)]
+pub struct Player {
+    pub name: String,
+    r#type: Option<char>,
+    classes: Vec<Class>,
+    position: Position,
+    marker: Marker,
+}
+
+#[derive(Debug, PartialEq, Storable)]
+struct Position(i32, i32);
+
+#[derive(Debug, PartialEq, Storable)]
+struct Marker;
+
+fn player() -> Player {
+    Player {
+        name: "Ferris".to_owned(),
+        r#type: Some('🦀'),
+        classes: vec![
+            Class::Warrior,
+            Class::Archer(3),
+            Class::Wizard {
+                spells: vec!["fireball".to_owned()],
+            },
+        ],
+        position: Position(-1, 2),
+        marker: Marker,
+    }
+}
+
+#[test]
+fn storable_format() {
+    let expected = r#"{"classes":["Warrior",{"Archer":[3]},{"Wizard":{"spells":["fireball"]}}],"marker":null,"name":"Ferris","position":[-1,2],"type":"🦀"}"#;
+    assert_eq!(player().to_json().to_string(), expected);
+    assert_eq!(
+        Player::from_json(&expected.parse::<Json>().unwrap()),
+        Some(player())
+    );
+}
+
+#[test]
+fn storable_mismatches() {
+    let json = |text: &str| text.parse::<Json>().unwrap();
+    assert_eq!(Class::from_json(&json(r#""Healer""#)), None);
+    assert_eq!(Class::from_json(&json(r#"{"Archer":[1,2]}"#)), None);
+    assert_eq!(
+        Class::from_json(&json(r#"{"Warrior":[],"Archer":[1]}"#)),
+        None
+    );
+    assert_eq!(Position::from_json(&json("[1]")), None);
+    assert_eq!(Marker::from_json(&json("1")), None);
+    // the missing `type` is `None`, but a missing `position` can't be loaded
+    let partial = r#"{"classes":[],"name":"Ferris","position":[0,0]}"#;
+    assert!(Player::from_json(&json(partial)).is_some());
+    let partial = r#"{"classes":[],"name":"Ferris"}"#;
+    assert_eq!(Player::from_json(&json(partial)), None);
+}
+
+#[test]
+fn storable_save_and_load() {
+    let file = temp_file();
+    player().save(&file);
+    assert_eq!(Player::load(&file), player());
+}
+
+#[derive(Debug, PartialEq, Storable)]
+struct Room {
+    object: String,
+    json: Json,
+    array: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Storable)]
+enum Event {
+    Found { object: String, value: u8 },
+    Said(String),
+}
+
+#[test]
+fn storable_field_names_of_the_generated_code() {
+    let room = Room {
+        object: "chest".to_owned(),
+        json: Json::Bool(true),
+        array: vec![1],
+    };
+    assert_eq!(
+        room.to_json().to_string(),
+        r#"{"array":[1],"json":true,"object":"chest"}"#
+    );
+    assert_eq!(Room::from_json(&room.to_json()), Some(room));
+
+    let found = Event::Found {
+        object: "key".to_owned(),
+        value: 2,
+    };
+    assert_eq!(Event::from_json(&found.to_json()), Some(found));
+    let said = Event::Said("hi".to_owned());
+    assert_eq!(Event::from_json(&said.to_json()), Some(said));
+}
@@ -1,4 +1,20 @@
 //!
+//! # Reproducible random numbers
+//!
+//! The random functions like [`random_float`] return different numbers every time the program
+//! runs. If the `SIMPLE_STD_SEED` environment variable is set to a number, they return the same
+//! numbers every time instead, which is useful for grading exercises or running tests in CI.
+//!
+//! ```text
+//! SIMPLE_STD_SEED=42 cargo run
+//! ```
+//!
+//! With the same seed and the same version of `simple-std`, the numbers are guaranteed to be the
+//! same in every run. The first thread that uses a random function gets the same numbers as
+//! `SimpleRng::from_seed(seed)`, every further thread gets those of the next seed (`seed + 1`,
+//! `seed + 2`, ...). Programs that use random numbers from multiple threads are therefore only
+//! reproducible if the threads start using them in the same order.
+//!
 //! # Example: guessing game
 //! ```
 //! use std::cmp::Ordering;
@@ -22,36 +38,508 @@
 //! }
 //! ```
 
+pub use color::{
+    colorize, colors_enabled, random_color, set_colors_enabled, strip_ansi, Color, Colored, Styled,
+    TerminalColor,
+};
+pub use dice::{roll, Dice, DiceRoll, ParseDiceError};
+pub use display::{
+    format_bytes, hexdump, print_banner, print_bar_chart, print_boxed, print_error, print_slowly,
+    print_table, print_warning, print_wrapped, show, wrap_text,
+};
+pub use fs::{
+    config_dir, copy_dir, data_dir, dir_exists, ensure_dir, file_exists, file_size, find_files,
+    home_dir, list_dir, read_bytes, read_config, read_csv, read_file, read_lines,
+    remove_dir_all_safe, temp_dir, temp_file, try_read_bytes, try_read_file, try_read_lines,
+    watch_file, watch_file_events, write_bytes, write_config, write_csv, write_file_atomic,
+    DirListing, FileError, FileEvent, FileEvents, FileWatcher, Logger, TempDir, TempFile,
+};
+pub use io::{
+    confirm, confirm_default, edit_in_editor, input, input_lines, input_multiline, multi_select,
+    pause, prompt, prompt_date, prompt_dir, prompt_float, prompt_int_range, prompt_new_password,
+    prompt_parse, prompt_parse_with_retries, prompt_password, prompt_path, prompt_timeout,
+    prompt_validated, read_all_stdin, read_floats, read_key, read_numbers, reset_input_source,
+    select, set_input_source, stdin_lines, try_input, try_prompt, InputError, InputSource, Key,
+    Prompt, PromptError,
+};
+pub use json::{read_json, Json, ParseJsonError};
+pub use net::{download_file, try_download_file, DownloadError};
+pub use progress::{ProgressBar, Spinner, Status};
+pub use random::{
+    choose, choose_from_iter, choose_mut, coin_flip, distributions, fifty_fifty, fill_random,
+    jitter, random_bool, random_bytes, random_char, random_char_from, random_date, random_duration,
+    random_float, random_float_range, random_floats, random_gaussian, random_int_range,
+    random_int_range_i64, random_int_range_u64, random_ints, random_letter, random_state,
+    random_string, random_uuid, random_uuid_bytes, sample, set_random_seed, set_random_state,
+    shuffle, weighted_choice_map, Charset, Coin, IntoIntRange, Random, SimpleRng, SliceRandomExt,
+};
+#[cfg(feature = "derive")]
+pub use simple_std_derive::Random;
+#[cfg(feature = "derive")]
+pub use simple_std_derive::Storable;
+pub use std::thread::sleep;
+pub use storage::{LoadError, Storable};
+pub use time::{
+    benchmark, countdown, countdown_in_background, format_duration, format_duration_verbose,
+    measure, now_string, repeat_every, sleep_millis, sleep_secs, time_now, today, with_timeout,
+    Benchmark, Countdown, Date, ParseDateError, Repeater, Stopwatch,
+};
+pub use toml::{parse_toml, read_toml, ParseTomlError};
+
+// used by the exported macros
+#[doc(hidden)]
+pub use io::write_output as __write_output;
+
+// the derived code uses `::simple_std`, which has to work in the tests of this crate too
+#[cfg(all(test, feature = "derive"))]
+extern crate self as simple_std;
+
+///
+/// The traits that add methods to types, so that they can all be imported at once with
+/// `use simple_std::prelude::*;`
+///
+/// # Example
+/// ```
+/// use simple_std::prelude::*;
+///
+/// let mut numbers = [1, 2, 3, 4, 5];
+/// numbers.shuffle();
+///
+/// println!("{:?}, random bool: {}", numbers, bool::random());
+/// ```
+pub mod prelude {
+    pub use crate::distributions::Distribution;
+    pub use crate::{Colored, Random, SliceRandomExt};
+}
+
+/// prints like `print!`, but can be captured by `testing::capture_output`
+macro_rules! output {
+    ($($arg:tt)*) => {
+        $crate::io::write_output(format_args!($($arg)*))
+    };
+}
+
+/// prints like `println!`, but can be captured by `testing::capture_output`
+macro_rules! outputln {
+    () => {
+        output!("\n")
+    };
+    ($($arg:tt)*) => {
+        output!("{}\n", format_args!($($arg)*))
+    };
+}
+
+/// prints like `eprintln!`, but can be captured by `testing::capture_output`
+macro_rules! eoutputln {
+    ($($arg:tt)*) => {
+        $crate::io::write_error_output(format_args!("{}\n", format_args!($($arg)*)))
+    };
+}
+
+///
+/// Prints like `print!`, but with colors and styles from tags like `<red>` and `</red>`, see
+/// [`colorize`]
+///
+/// # Example
+/// ```
+/// use simple_std::cprint;
+///
+/// let points = 10;
+/// cprint!("<bold>Score:</bold> <yellow>{}</yellow>", points);
+/// cprint!(" <gray>(new record)</gray>\n");
+/// ```
+///
+/// # Why is this not in std?
+///
+/// See [`Colored`]
+#[macro_export]
+macro_rules! cprint {
+    ($($arg:tt)*) => {
+        $crate::__write_output(::std::format_args!(
+            "{}",
+            $crate::colorize(&::std::format!($($arg)*))
+        ))
+    };
+}
+
+///
+/// Prints like `println!`, but with colors and styles from tags like `<red>` and `</red>`, see
+/// [`colorize`]
+///
+/// # Example
+/// ```
+/// use simple_std::cprintln;
+///
+/// let file = "story.txt";
+/// cprintln!("<red><bold>Error:</bold></red> {} was not found", file);
+/// cprintln!("<green>Saved {file}</green>");
+/// ```
+///
+/// # Why is this not in std?
+///
+/// See [`Colored`]
+#[macro_export]
+macro_rules! cprintln {
+    () => {
+        $crate::__write_output(::std::format_args!("\n"))
+    };
+    ($($arg:tt)*) => {
+        $crate::__write_output(::std::format_args!(
+            "{}\n",
+            $crate::colorize(&::std::format!($($arg)*))
+        ))
+    };
+}
+
+///
+/// Runs the code and prints how long it took, and evaluates to its value
+///
+/// The code can be given a name that is printed with the time. Without one, the code itself is
+/// printed. See [`measure`] for getting the time instead of printing it.
+///
+/// # Example
+/// ```
+/// use simple_std::time_it;
+///
+/// let mut numbers = (0..100_000).rev().collect::<Vec<u64>>();
+///
+/// // prints something like "sorting took 4ms"
+/// time_it!("sorting", {
+///     numbers.sort();
+/// });
+///
+/// // prints something like "numbers.iter().sum::<u64>() took 51µs"
+/// let sum = time_it!(numbers.iter().sum::<u64>());
+/// ```
+///
+/// # Why is this not in std?
+///
+/// See [`Stopwatch`]
+#[macro_export]
+macro_rules! time_it {
+    (@named $name:expr; $code:expr) => {{
+        let start = ::std::time::Instant::now();
+        let value = $code;
+        $crate::__write_output(::std::format_args!(
+            "{} took {}\n",
+            $name,
+            $crate::format_duration(start.elapsed())
+        ));
+        value
+    }};
+    ($name:literal, $code:expr) => {
+        $crate::time_it!(@named $name; $code)
+    };
+    ($code:expr) => {
+        $crate::time_it!(@named ::std::stringify!($code); $code)
+    };
+}
+
+///
+/// Helpers for testing programs that use the input functions like [`input`] and [`prompt`]
+///
+/// # Example
+/// ```
+/// use simple_std::{prompt, prompt_parse, testing};
+///
+/// fn greet() -> String {
+///     let name = prompt("Your name: ");
+///     let age = prompt_parse::<u32>("Your age: ");
+///     format!("Hello {}, next year you will be {}", name, age + 1)
+/// }
+///
+/// testing::set_input(["Ferris", "7"]);
+/// assert_eq!(greet(), "Hello Ferris, next year you will be 8");
+/// ```
+pub mod testing {
+    use std::io::Cursor;
+
+    ///
+    /// Makes the input functions like [`input`](crate::input) and [`prompt`](crate::prompt)
+    /// return the given lines, one line per call, instead of reading from stdin
+    ///
+    /// Once all lines are used up, the input has ended, just like when piping in a file. This
+    /// only affects the current thread, so tests that run at the same time don't get each
+    /// other's input. It replaces any input source set before with
+    /// [`set_input_source`](crate::set_input_source).
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::{confirm, input, testing, try_input};
+    ///
+    /// testing::set_input(["bob", "yes"]);
+    ///
+    /// assert_eq!(input(), "bob");
+    /// assert!(confirm("Are you sure?"));
+    /// assert!(try_input().is_err());
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// The input functions of this crate are global, so they need a global way to be replaced
+    /// in tests. Code that only uses `std` usually takes something implementing
+    /// [`BufRead`](std::io::BufRead) as a parameter instead.
+    pub fn set_input<I>(lines: I)
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut text = String::new();
+        for line in lines {
+            text.push_str(line.as_ref());
+            text.push('\n');
+        }
+
+        crate::set_input_source(Cursor::new(text));
+    }
+
+    ///
+    /// Runs `f` and returns everything that the functions of this crate printed while it ran,
+    /// instead of printing it
+    ///
+    /// This includes the messages of [`prompt`](crate::prompt) and its relatives and the ones
+    /// that [`print_error`](crate::print_error) prints to stderr, but not the input itself or
+    /// anything printed with `println!`. Like [`set_input`], it only affects the current
+    /// thread.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::{prompt_int_range, testing};
+    ///
+    /// testing::set_input(["12", "7"]);
+    /// let output = testing::capture_output(|| {
+    ///     let guess = prompt_int_range("Guess: ", 1..=10);
+    ///     assert_eq!(guess, 7);
+    /// });
+    ///
+    /// assert_eq!(
+    ///     output,
+    ///     "Guess: Please enter a whole number from 1 to 10.\nGuess: "
+    /// );
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// `std` can only capture the output of tests as a whole. Code that only uses `std` usually
+    /// takes something implementing [`Write`](std::io::Write) as a parameter instead, which can
+    /// be a `Vec<u8>` in tests.
+    pub fn capture_output(f: impl FnOnce()) -> String {
+        crate::io::capture_output(f)
+    }
+}
+
+///
+/// Functions for controlling the terminal, to draw things like game boards or animations that
+/// are redrawn instead of scrolling by
+///
+/// They use escape sequences, or the console functions on Windows, where escape sequences don't
+/// always work. When the output is captured with [`testing::capture_output`], the escape
+/// sequences are captured.
+///
+/// # Example
+/// ```no_run
+/// use simple_std::terminal;
+///
+/// terminal::hide_cursor();
+/// for i in 0..10 {
+///     terminal::clear_screen();
+///     terminal::move_cursor(5, i * 2);
+///     print!("o");
+///     std::thread::sleep(std::time::Duration::from_millis(100));
+/// }
+/// terminal::show_cursor();
+/// ```
+pub mod terminal {
+    use crate::sys::{self, ConsoleCommand};
+
+    /// runs the command with the console functions, or prints the escape sequence for it
+    fn run(command: ConsoleCommand, escape_sequence: &str) {
+        if crate::io::capturing_output() || !sys::run_console_command(command) {
+            output!("{}", escape_sequence);
+        }
+    }
+
+    ///
+    /// Removes everything from the terminal and moves the cursor to the top left corner
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::terminal;
+    ///
+    /// terminal::clear_screen();
+    /// println!("Welcome to the game!");
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// `std` only writes text and doesn't know what it is written to, so it can't know how to
+    /// control it. Crates like `crossterm` handle all the different terminals.
+    pub fn clear_screen() {
+        run(ConsoleCommand::Clear, "\x1b[2J\x1b[H");
+    }
+
+    ///
+    /// Moves the cursor to the row and column, both starting at 0 in the top left corner, so
+    /// that the next text is printed there
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::terminal;
+    ///
+    /// terminal::clear_screen();
+    /// terminal::move_cursor(2, 10);
+    /// print!("X");
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`clear_screen`]
+    pub fn move_cursor(row: u16, column: u16) {
+        let escape_sequence = format!("\x1b[{};{}H", u32::from(row) + 1, u32::from(column) + 1);
+        run(ConsoleCommand::MoveCursor { row, column }, &escape_sequence);
+    }
+
+    ///
+    /// Stops showing the cursor, until [`show_cursor`] is called
+    ///
+    /// It isn't shown again automatically when the program exits, so [`show_cursor`] should
+    /// always be called at the end.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::terminal;
+    ///
+    /// terminal::hide_cursor();
+    /// // draw the animation
+    /// terminal::show_cursor();
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`clear_screen`]
+    pub fn hide_cursor() {
+        run(ConsoleCommand::ShowCursor(false), "\x1b[?25l");
+    }
+
+    ///
+    /// Shows the cursor again after [`hide_cursor`]
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::terminal;
+    ///
+    /// terminal::show_cursor();
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`clear_screen`]
+    pub fn show_cursor() {
+        run(ConsoleCommand::ShowCursor(true), "\x1b[?25h");
+    }
+
+    ///
+    /// Returns how many columns and rows of characters fit into the terminal, or `None` if the
+    /// output doesn't go to a terminal
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::terminal;
+    ///
+    /// match terminal::terminal_size() {
+    ///     Some((columns, rows)) => println!("The terminal is {}x{} characters big", columns, rows),
+    ///     None => println!("This is not a terminal"),
+    /// }
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`clear_screen`]
+    pub fn terminal_size() -> Option<(u16, u16)> {
+        sys::terminal_size().filter(|&(columns, rows)| columns > 0 && rows > 0)
+    }
+
+    /// how many characters fit into a line, guessing 80 if it isn't known
+    pub(crate) fn line_width() -> usize {
+        match terminal_size() {
+            Some((columns, _)) => usize::from(columns),
+            // set by some shells, even though they don't pass it on to programs by default
+            None => std::env::var("COLUMNS")
+                .ok()
+                .and_then(|columns| columns.parse().ok())
+                .filter(|&columns| columns > 0)
+                .unwrap_or(80),
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use crate::terminal::{clear_screen, hide_cursor, move_cursor, show_cursor};
+        use crate::testing::capture_output;
 
-pub use io::{input, prompt};
-pub use random::{random_float, random_int_range};
+        #[test]
+        fn escape_sequences() {
+            let output = capture_output(|| {
+                clear_screen();
+                move_cursor(0, 4);
+                hide_cursor();
+                show_cursor();
+            });
+            assert_eq!(output, "\x1b[2J\x1b[H\x1b[1;5H\x1b[?25l\x1b[?25h");
+        }
+    }
+}
 
 mod io {
+    use crate::{Date, IntoIntRange};
+    use std::cell::RefCell;
+    use std::fmt::Display;
+    use std::io::BufRead;
+    use std::ops::RangeInclusive;
+    use std::path::PathBuf;
+    use std::process::Command;
+    use std::str::FromStr;
+    use std::sync::mpsc::{Receiver, RecvTimeoutError};
+    use std::sync::Mutex;
+    use std::time::Duration;
+
     ///
     /// Reads a single line of input, similar to Pythons `input` function
     ///
+    /// The newline at the end (`\n` or `\r\n`) is removed, so the input can be compared directly.
+    /// If there is no more input to read, an empty string is returned.
+    ///
     /// # Example
     /// ```
     /// use simple_std::input;
     ///
     /// println!("What is your name?");
     /// let name = input();
-    /// println!("Hello {}!", name)
+    ///
+    /// if name == "Ferris" {
+    ///     println!("Hello crab!");
+    /// } else {
+    ///     println!("Hello {}!", name);
+    /// }
     /// ```
     ///
     /// # Why is this not in std?
     ///
-    /// The implementation is fairly simple, just 2 lines, but it has a little complexity to it,
-    /// that's why there is the simplified version here.
+    /// The implementation is fairly simple, just a few lines, but it has a little complexity to
+    /// it, that's why there is the simplified version here.
     pub fn input() -> String {
-        let mut buffer = String::new();
-        std::io::stdin().read_line(&mut buffer).unwrap();
-        buffer
+        match try_input() {
+            Ok(line) => line,
+            Err(InputError::Ended) => String::new(),
+            Err(err) => panic!("{}", err),
+        }
     }
 
     ///
     /// Reads a single line of input, while providing a message that comes on the same line.
     ///
+    /// Like with [`input`], the newline at the end is removed.
+    ///
     /// # Example
     /// ```
     /// use simple_std::prompt;
@@ -64,158 +552,13599 @@ mod io {
     ///
     /// see [`input`]
     pub fn prompt(message: &str) -> String {
-        use std::io::Write;
-
-        print!("{}", message);
-        std::io::stdout().flush().unwrap();
+        print_flushed(message);
         input()
     }
-}
-
-mod random {
-    use std::ops::Range;
 
     ///
-    /// Returns a random number from 0 to 1, like Javascript `Math.random`
+    /// Reads lines until an empty line is entered or the input ends, and returns them joined
+    /// with newlines
     ///
     /// # Example
+    /// ```no_run
+    /// use simple_std::input_multiline;
+    ///
+    /// println!("Paste your text, and finish with an empty line:");
+    /// let text = input_multiline();
+    /// println!("Your text has {} words", text.split_whitespace().count());
     /// ```
-    /// use simple_std::random_float;
     ///
-    /// let number = random_float();
+    /// # Why is this not in std?
     ///
-    /// println!("Number between 0 and 1: {}", number);
+    /// See [`input`]
+    pub fn input_multiline() -> String {
+        input_lines().join("\n")
+    }
+
     ///
-    /// assert!(number < 1.0);
-    /// assert!(number >= 0.0);
+    /// Reads lines until an empty line is entered or the input ends, like [`input_multiline`],
+    /// but returns every line on its own
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::input_lines;
+    ///
+    /// println!("Enter your shopping list, and finish with an empty line:");
+    /// for (i, item) in input_lines().iter().enumerate() {
+    ///     println!("{}. {}", i + 1, item);
+    /// }
     /// ```
     ///
     /// # Why is this not in std?
     ///
-    /// Rust aims to be correct, that's why its major random number library is cryptographically secure,
-    /// meaning its randomness can't easily be guessed. And cryptographically secure random number generation
-    /// is a big task, that's why it has its own crate.
-    pub fn random_float() -> f64 {
-        ((random_u64() >> 11) as f64) / ((1u64 << 53) as f64)
+    /// See [`input`]
+    pub fn input_lines() -> Vec<String> {
+        std::iter::repeat_with(input)
+            .take_while(|line| !line.is_empty())
+            .collect()
     }
 
     ///
-    /// Returns an integer number contained in the range
+    /// Reads all input until it ends, and returns it
+    ///
+    /// This is useful for programs that get their input piped in, like `cat story.txt | cargo run`.
+    /// When typing into a terminal, the input is ended with Ctrl+D (or Ctrl+Z and Enter on
+    /// Windows).
     ///
     /// # Example
+    /// ```no_run
+    /// use simple_std::read_all_stdin;
+    ///
+    /// let text = read_all_stdin();
+    /// println!("The input has {} lines", text.lines().count());
     /// ```
-    /// use simple_std::random_int_range;
     ///
-    /// let number = random_int_range(0..100);
+    /// # Why is this not in std?
     ///
-    /// println!("Number between 0 and 100: {}", number);
+    /// It is, as `std::io::read_to_string(std::io::stdin())`, but that is hard to find and
+    /// returns a `Result`.
+    pub fn read_all_stdin() -> String {
+        let mut text = String::new();
+        loop {
+            match try_read_line() {
+                Ok(line) => text.push_str(&line),
+                Err(InputError::Ended) => return text,
+                Err(err) => panic!("{}", err),
+            }
+        }
+    }
+
     ///
-    /// assert!(number < 100);
-    /// assert!(number >= 0);
+    /// Returns an iterator over all lines of input until it ends, without the newlines
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::stdin_lines;
+    ///
+    /// // prints the input with line numbers, for example of `cat main.rs | cargo run`
+    /// for (i, line) in stdin_lines().enumerate() {
+    ///     println!("{:>4} {}", i + 1, line);
+    /// }
     /// ```
     ///
     /// # Why is this not in std?
     ///
-    /// See [`random_float`]
-    ///
-    pub fn random_int_range(range: Range<i32>) -> i32 {
-        let difference = range.end - range.start;
-        range.start + ((random_u64() as i32).abs() % difference)
+    /// It is, as `std::io::stdin().lines()`, but that returns a `Result` for every line.
+    pub fn stdin_lines() -> impl Iterator<Item = String> {
+        std::iter::from_fn(|| match try_input() {
+            Ok(line) => Some(line),
+            Err(InputError::Ended) => None,
+            Err(err) => panic!("{}", err),
+        })
     }
 
-    /// generates a pseudo-random u64
-    fn random_u64() -> u64 {
-        use std::sync::atomic::{AtomicU64, Ordering};
-
-        static STATE0: AtomicU64 = AtomicU64::new(0);
-        static STATE1: AtomicU64 = AtomicU64::new(0);
-
-        if STATE0.load(Ordering::SeqCst) == 0 {
-            // more or less random initial state
-            STATE0.store((system_time_random()) as u64, Ordering::SeqCst);
-            STATE1.store((system_time_random()) as u64, Ordering::SeqCst);
-        }
-
-        // use xorshift128+ because it's easy https://v8.dev/blog/math-random
+    ///
+    /// Opens a text editor with `initial_text`, waits until it's closed and returns the edited
+    /// text
+    ///
+    /// The editor from the `VISUAL` or `EDITOR` environment variable is used, or otherwise
+    /// Notepad on Windows and `nano` or `vi` everywhere else.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::edit_in_editor;
+    ///
+    /// let story = edit_in_editor("Once upon a time, ");
+    /// println!("Your story has {} words", story.split_whitespace().count());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if no editor was found, or if the file for the text can't be created in the
+    /// temporary directory.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// Which editor should be used depends on the conventions of the operating system, and
+    /// `std` tries to stay out of those.
+    pub fn edit_in_editor(initial_text: &str) -> String {
+        let mut editors = ["VISUAL", "EDITOR"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .map(|editor| editor.split_whitespace().map(str::to_owned).collect())
+            .collect::<Vec<Vec<String>>>();
 
-        // not a bug
-        let mut s1 = STATE0.load(Ordering::SeqCst);
-        let s0 = STATE1.load(Ordering::SeqCst);
+        let defaults: &[&str] = if cfg!(windows) {
+            &["notepad"]
+        } else {
+            &["nano", "vi"]
+        };
+        editors.extend(defaults.iter().map(|&editor| vec![editor.to_owned()]));
 
-        STATE0.store(s0, Ordering::SeqCst);
+        edit_with(initial_text, &editors)
+    }
 
-        s1 ^= s1 << 23;
-        s1 ^= s1 >> 17;
-        s1 ^= s0;
-        s1 ^= s0 >> 26;
+    /// edits the text with the first editor that exists, which is a program and its arguments
+    fn edit_with(initial_text: &str, editors: &[Vec<String>]) -> String {
+        // with the extension, editors know that it is text
+        let file = crate::fs::temp_file_with_extension(".txt");
+        let path = file.path();
+        std::fs::write(path, initial_text)
+            .unwrap_or_else(|err| panic!("failed to create the file to edit: {}", err));
 
-        STATE1.store(s1, Ordering::SeqCst);
+        let found = editors.iter().any(|editor| {
+            let (program, args) = match editor.split_first() {
+                Some(editor) => editor,
+                None => return false,
+            };
+            // the editor is found even if it fails, then the text just stays the same
+            Command::new(program).args(args).arg(path).status().is_ok()
+        });
 
-        s0.wrapping_add(s1)
+        let text = std::fs::read_to_string(path);
+        drop(file);
+        if !found {
+            panic!("no text editor was found, set the EDITOR environment variable to one");
+        }
+        text.unwrap_or_else(|err| panic!("failed to read the edited file: {}", err))
     }
 
-    fn system_time_random() -> u128 {
-        use std::time::SystemTime;
+    ///
+    /// Reads a single line of input like [`input`], but returns an error instead of panicking
+    /// if the input can't be read, or an empty string if there is no more input
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::{try_input, InputError};
+    ///
+    /// // add up all numbers until the input ends, for example when it's piped in from a file
+    /// let mut sum = 0;
+    /// loop {
+    ///     match try_input() {
+    ///         Ok(line) => sum += line.parse::<i64>().expect("not a number"),
+    ///         Err(InputError::Ended) => break,
+    ///         Err(err) => panic!("{}", err),
+    ///     }
+    /// }
+    /// println!("The sum is {}", sum);
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`input`]
+    pub fn try_input() -> Result<String, InputError> {
+        let mut line = try_read_line()?;
+        let len = trim_newline(&line).len();
+        line.truncate(len);
+        Ok(line)
+    }
 
-        SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .unwrap()
-            .as_micros()
-            ^ SystemTime::now()
-                .duration_since(SystemTime::UNIX_EPOCH)
-                .unwrap()
-                .as_nanos()
+    ///
+    /// Reads a single line of input like [`prompt`], but returns an error instead of panicking,
+    /// see [`try_input`]
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::try_prompt;
+    ///
+    /// match try_prompt("Your name: ") {
+    ///     Ok(name) => println!("Hello {}!", name),
+    ///     Err(err) => println!("Goodbye, {}", err),
+    /// }
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`input`]
+    pub fn try_prompt(message: &str) -> Result<String, InputError> {
+        print_flushed(message);
+        try_input()
     }
 
-    #[cfg(test)]
-    mod test {
-        use crate::{random_float, random_int_range};
-        use std::iter::repeat_with;
+    /// The error returned by [`try_input`] and [`try_prompt`]
+    #[derive(Debug)]
+    pub enum InputError {
+        /// There is no more input to read, for example because it was piped in from a file that
+        /// has ended
+        Ended,
+        /// The input is not valid UTF-8
+        InvalidUtf8,
+        /// Reading the input failed
+        Io(std::io::Error),
+    }
 
-        #[test]
-        fn not_equal() {
-            repeat_with(random_float)
-                .take(100)
-                .collect::<Vec<_>>()
-                .windows(2)
-                .for_each(|win| assert_ne!(win[0], win[1]));
+    impl From<std::io::Error> for InputError {
+        fn from(err: std::io::Error) -> Self {
+            match err.kind() {
+                // `read_line` uses this kind for invalid UTF-8
+                std::io::ErrorKind::InvalidData => InputError::InvalidUtf8,
+                _ => InputError::Io(err),
+            }
         }
+    }
 
-        #[test]
-        fn between_0_1() {
-            assert!(repeat_with(random_float)
-                .take(100000)
-                .all(|n| n >= 0.0 && n < 1.0))
+    impl Display for InputError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                InputError::Ended => f.write_str("there is no more input to read"),
+                InputError::InvalidUtf8 => f.write_str("the input is not valid UTF-8"),
+                InputError::Io(err) => write!(f, "failed to read input: {}", err),
+            }
         }
+    }
 
-        #[test]
-        fn distributed() {
-            assert!(repeat_with(random_float).take(100000).any(|n| n > 0.999));
-            assert!(repeat_with(random_float).take(100000).any(|n| n < 0.001));
+    impl std::error::Error for InputError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            match self {
+                InputError::Io(err) => Some(err),
+                _ => None,
+            }
         }
+    }
 
-        #[test]
-        fn range_in_range() {
-            [0..10, 5..15, 1000..1004, (-5)..5, (-10)..(-5)]
-                .iter()
-                .for_each(|range| {
-                    assert!(repeat_with(|| random_int_range(range.clone()))
-                        .take(10000)
-                        .all(|n| n < range.end && n >= range.start));
-                })
+    ///
+    /// Something that the input functions like [`input`] and [`prompt`] can read lines from
+    ///
+    /// It is implemented for everything that implements [`BufRead`], like a
+    /// [`BufReader`](std::io::BufReader) around a file or a network connection, or a
+    /// [`Cursor`](std::io::Cursor) around a string. Use [`set_input_source`] to read from it.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`set_input_source`]
+    pub trait InputSource {
+        /// Appends the next line including its newline to `buffer`, returning how many bytes
+        /// were read, or 0 if there is no more input
+        fn read_line(&mut self, buffer: &mut String) -> std::io::Result<usize>;
+    }
+
+    impl<R: BufRead> InputSource for R {
+        fn read_line(&mut self, buffer: &mut String) -> std::io::Result<usize> {
+            BufRead::read_line(self, buffer)
         }
+    }
 
-        #[test]
-        fn distributed_range() {
-            [0..10, 5..15, 1000..1004, (-5)..5, (-10)..(-5)]
-                .iter()
-                .for_each(|range| {
-                    range.clone().for_each(|expected| {
-                        assert!(repeat_with(|| random_int_range(range.clone()))
-                            .take(100000)
-                            .any(|n| n == expected));
+    ///
+    /// Makes all input functions like [`input`] and [`prompt`] read from `source` instead of
+    /// from stdin
+    ///
+    /// This only affects the current thread, every thread has its own input source. Use
+    /// [`reset_input_source`] to read from stdin again.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::{input, prompt_parse, set_input_source};
+    /// use std::io::Cursor;
+    ///
+    /// set_input_source(Cursor::new("Ferris\n7\n"));
+    ///
+    /// assert_eq!(input(), "Ferris");
+    /// assert_eq!(prompt_parse::<u32>("Your age: "), 7);
+    /// ```
+    ///
+    /// Reading from a file:
+    /// ```no_run
+    /// use simple_std::{input, set_input_source};
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    ///
+    /// let file = File::open("answers.txt").expect("the file doesn't exist");
+    /// set_input_source(BufReader::new(file));
+    ///
+    /// let first_answer = input();
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// `std` doesn't have functions like [`input`] that read from a fixed place, instead it lets
+    /// you pass around whatever you want to read from. That is more flexible, but more work
+    /// for small programs.
+    pub fn set_input_source(source: impl InputSource + 'static) {
+        INPUT_SOURCE.with(|current| *current.borrow_mut() = Some(Box::new(source)));
+    }
+
+    ///
+    /// Makes all input functions read from stdin again, after [`set_input_source`] was used
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::{reset_input_source, set_input_source};
+    /// use std::io::Cursor;
+    ///
+    /// set_input_source(Cursor::new("yes\n"));
+    /// // ...
+    /// reset_input_source();
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`set_input_source`]
+    pub fn reset_input_source() {
+        INPUT_SOURCE.with(|current| *current.borrow_mut() = None);
+    }
+
+    thread_local! {
+        /// the source set by `set_input_source`, stdin is used if it's `None`
+        static INPUT_SOURCE: RefCell<Option<Box<dyn InputSource>>> = RefCell::new(None);
+    }
+
+    fn reads_from_stdin() -> bool {
+        INPUT_SOURCE.with(|current| current.borrow().is_none())
+    }
+
+    ///
+    /// Reads a single line of input like [`prompt`], but returns `None` if nothing was entered
+    /// before the time runs out
+    ///
+    /// If the time runs out, a newline is printed, and whatever is entered afterwards is
+    /// returned by the next input function instead. `None` is also returned if there is no more
+    /// input to read.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::prompt_timeout;
+    /// use std::time::Duration;
+    ///
+    /// match prompt_timeout("What is 7 * 8? ", Duration::from_secs(5)) {
+    ///     Some(answer) if answer == "56" => println!("Correct!"),
+    ///     Some(_) => println!("Wrong!"),
+    ///     None => println!("Too slow!"),
+    /// }
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// Reading from stdin can't be stopped once it has started, so the line has to be read on
+    /// another thread, which can't be stopped either. Working around that takes more code
+    /// than most programs want to have.
+    pub fn prompt_timeout(message: &str, timeout: Duration) -> Option<String> {
+        print_flushed(message);
+
+        let line = if reads_from_stdin() {
+            let receiver = PENDING_LINE.lock().unwrap().take();
+            let receiver = receiver.unwrap_or_else(|| {
+                let (sender, receiver) = std::sync::mpsc::channel();
+                std::thread::spawn(move || {
+                    // the receiver might be gone already, but then nobody wants the line anyway
+                    let _ = sender.send(read_stdin_line());
+                });
+                receiver
+            });
+
+            match receiver.recv_timeout(timeout) {
+                Ok(line) => line,
+                Err(RecvTimeoutError::Timeout) => {
+                    *PENDING_LINE.lock().unwrap() = Some(receiver);
+                    outputln!();
+                    return None;
+                }
+                Err(RecvTimeoutError::Disconnected) => Err(InputError::Ended),
+            }
+        } else {
+            // other sources answer immediately
+            try_read_line()
+        };
+
+        match line {
+            Ok(line) => Some(trim_newline(&line).to_owned()),
+            Err(InputError::Ended) => None,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    ///
+    /// Asks for input until it can be parsed into the type, like a number, and returns it
+    ///
+    /// If the input is invalid, a message is printed and the user is asked again. Spaces and
+    /// the newline around the input are ignored.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::prompt_parse;
+    ///
+    /// let age = prompt_parse::<u32>("Your age: ");
+    /// println!("Next year you will be {}", age + 1);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no more input to read, for example because it was piped in from a
+    /// file that has ended.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// Programs usually handle invalid input in very different ways, and asking again is only
+    /// one of them. But it's the most common one for small exercises.
+    pub fn prompt_parse<T>(message: &str) -> T
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        prompt_validated(message, |input| {
+            input
+                .parse()
+                .map_err(|err| format!("Invalid input: {}. Please try again.", err))
+        })
+    }
+
+    ///
+    /// Asks for a whole number until one inside of the range is entered, and returns it
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::{prompt_int_range, random_int_range};
+    ///
+    /// let number = random_int_range(1..=100);
+    /// let guess = prompt_int_range("Guess a number from 1 to 100: ", 1..=100);
+    ///
+    /// if guess == number {
+    ///     println!("You win!");
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty, or if there is no more input to read, see [`prompt_parse`].
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`prompt_parse`]
+    pub fn prompt_int_range(message: &str, range: impl IntoIntRange) -> i32 {
+        let range = range.into_int_range();
+        prompt_validated(message, |input| parse_int_in_range(input, &range))
+    }
+
+    ///
+    /// Asks for a number until a valid one is entered, and returns it
+    ///
+    /// Both `3.14` and `3,14` are accepted, since many countries use the comma for decimal
+    /// numbers.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::prompt_float;
+    ///
+    /// let price = prompt_float("Price in euros: ");
+    /// println!("With taxes: {:.2}", price * 1.2);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no more input to read, see [`prompt_parse`].
+    ///
+    /// # Why is this not in std?
+    ///
+    /// Parsing numbers in `std` doesn't depend on the language, so that programs work the same
+    /// everywhere. That's the right choice for data, but not for what people type.
+    pub fn prompt_float(message: &str) -> f64 {
+        prompt_validated(message, parse_float)
+    }
+
+    /// parses a finite number, with a `.` or a `,` as the decimal separator
+    fn parse_float(input: &str) -> Result<f64, String> {
+        let normalized = if input.contains('.') || input.matches(',').count() != 1 {
+            input.to_owned()
+        } else {
+            input.replace(',', ".")
+        };
+
+        match normalized.parse::<f64>() {
+            Ok(number) if number.is_finite() => Ok(number),
+            _ => Err(format!("`{}` is not a number. Please try again.", input)),
+        }
+    }
+
+    ///
+    /// Asks for a date until a valid one is entered, and returns it
+    ///
+    /// Dates can be entered like `2024-05-01`, `01.05.2024`, `01/05/2024` or `May 1 2024`, see
+    /// [`Date`] for all formats.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::prompt_date;
+    ///
+    /// let birthday = prompt_date("When is your birthday? ");
+    /// println!("You were born in {}", birthday.year());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no more input to read, see [`prompt_parse`].
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`Date`]
+    pub fn prompt_date(message: &str) -> Date {
+        prompt_parse(message)
+    }
+
+    ///
+    /// Asks for input until it can be parsed into the type like [`prompt_parse`], but gives up
+    /// after `attempts` invalid inputs
+    ///
+    /// An error is also returned if the input can't be read, for example because there is no
+    /// more input, so this never panics or waits forever when the input is piped in.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::prompt_parse_with_retries;
+    ///
+    /// match prompt_parse_with_retries::<u32>("Your age: ", 3) {
+    ///     Ok(age) => println!("Next year you will be {}", age + 1),
+    ///     Err(err) => println!("No age then: {}", err),
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `attempts` is 0.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`prompt_parse`]
+    pub fn prompt_parse_with_retries<T>(message: &str, attempts: u32) -> Result<T, PromptError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        Prompt::new(message).max_attempts(attempts).try_ask()
+    }
+
+    /// The error returned by [`prompt_parse_with_retries`] and [`Prompt::try_ask`]
+    #[derive(Debug)]
+    pub enum PromptError {
+        /// All attempts were used up by invalid inputs
+        TooManyAttempts,
+        /// The input couldn't be read
+        Input(InputError),
+    }
+
+    impl Display for PromptError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                PromptError::TooManyAttempts => f.write_str("there were too many invalid inputs"),
+                PromptError::Input(err) => Display::fmt(err, f),
+            }
+        }
+    }
+
+    impl std::error::Error for PromptError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            match self {
+                PromptError::Input(err) => Some(err),
+                PromptError::TooManyAttempts => None,
+            }
+        }
+    }
+
+    ///
+    /// Asks for input until `validate` accepts it, and returns the value returned by `validate`
+    ///
+    /// `validate` gets the input without the spaces and newline around it. If it returns an
+    /// error, the error is printed and the user is asked again.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::prompt_validated;
+    ///
+    /// let name = prompt_validated("Your name: ", |name| {
+    ///     if name.is_empty() {
+    ///         Err("The name must not be empty")
+    ///     } else {
+    ///         Ok(name.to_owned())
+    ///     }
+    /// });
+    ///
+    /// let even = prompt_validated("An even number: ", |input| match input.parse::<i32>() {
+    ///     Ok(n) if n % 2 == 0 => Ok(n),
+    ///     Ok(n) => Err(format!("{} is odd", n)),
+    ///     Err(_) => Err(format!("{} is not a number", input)),
+    /// });
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no more input to read, see [`prompt_parse`].
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`prompt_parse`]
+    pub fn prompt_validated<T, E: Display>(
+        message: &str,
+        mut validate: impl FnMut(&str) -> Result<T, E>,
+    ) -> T {
+        loop {
+            let line = prompt_line(message);
+            match validate(line.trim()) {
+                Ok(value) => return value,
+                Err(err) => outputln!("{}", err),
+            }
+        }
+    }
+
+    ///
+    /// Asks for the path of a file until one that exists is entered, and returns it
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::prompt_path;
+    ///
+    /// let path = prompt_path("Which file should be read? ");
+    /// let content = std::fs::read_to_string(path).expect("the file can't be read");
+    /// println!("{}", content);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no more input to read, see [`prompt_parse`].
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`prompt_parse`]
+    pub fn prompt_path(message: &str) -> PathBuf {
+        prompt_validated(message, |input| check_path(input, false))
+    }
+
+    ///
+    /// Asks for the path of a directory until one that exists is entered, and returns it, like
+    /// [`prompt_path`] does for files
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::prompt_dir;
+    ///
+    /// let dir = prompt_dir("Where should the game be saved? ");
+    /// let save_file = dir.join("save.txt");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no more input to read, see [`prompt_parse`].
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`prompt_parse`]
+    pub fn prompt_dir(message: &str) -> PathBuf {
+        prompt_validated(message, |input| check_path(input, true))
+    }
+
+    /// checks that the path exists and is a directory or file
+    fn check_path(input: &str, dir: bool) -> Result<PathBuf, String> {
+        let path = PathBuf::from(input);
+        match (path.is_dir(), path.is_file()) {
+            (true, _) if dir => Ok(path),
+            (_, true) if !dir => Ok(path),
+            (true, _) => Err(format!("`{}` is a directory, not a file.", input)),
+            (_, true) => Err(format!("`{}` is a file, not a directory.", input)),
+            _ => Err(format!("`{}` doesn't exist. Please try again.", input)),
+        }
+    }
+
+    ///
+    /// Reads a password (or anything else secret) without showing what's typed, while providing
+    /// a message that comes on the same line
+    ///
+    /// The newline at the end is removed. If the input doesn't come from a terminal, it is read
+    /// normally.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::prompt_password;
+    ///
+    /// let password = prompt_password("Password: ");
+    ///
+    /// if password == "hunter2" {
+    ///     println!("Welcome back!");
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no more input to read, see [`prompt_parse`].
+    ///
+    /// # Why is this not in std?
+    ///
+    /// Hiding the input works differently on every operating system, and `std` doesn't deal
+    /// with terminals at all.
+    pub fn prompt_password(message: &str) -> String {
+        print_flushed(message);
+
+        let echo_guard = if reads_from_stdin() && !line_pending() {
+            crate::sys::disable_echo()
+        } else {
+            None
+        };
+        let line = match echo_guard {
+            // without line editing, which would show the input again
+            Some(_) => read_stdin_line().unwrap_or_else(|err| panic!("{}", err)),
+            None => read_line(),
+        };
+        if echo_guard.is_some() {
+            // the newline that was typed wasn't shown either
+            outputln!();
+        }
+
+        trim_newline(&line).to_owned()
+    }
+
+    ///
+    /// Asks for a new password twice without showing it, until both are the same and not empty,
+    /// and returns it
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::{prompt, prompt_new_password};
+    ///
+    /// let name = prompt("Username: ");
+    /// let password = prompt_new_password("Password: ");
+    /// println!("Welcome, {}! Your password has {} characters.", name, password.len());
+    /// ```
+    ///
+    /// This prints something like
+    /// ```text
+    /// Username: Ferris
+    /// Password:
+    /// Repeat the password:
+    /// The passwords are not the same. Please try again.
+    /// Password:
+    /// Repeat the password:
+    /// Welcome, Ferris! Your password has 7 characters.
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no more input to read, see [`prompt_parse`].
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`prompt_password`]
+    pub fn prompt_new_password(message: &str) -> String {
+        loop {
+            let password = prompt_password(message);
+            if password.is_empty() {
+                outputln!("The password must not be empty.");
+                continue;
+            }
+
+            if prompt_password("Repeat the password: ") == password {
+                return password;
+            }
+            outputln!("The passwords are not the same. Please try again.");
+        }
+    }
+
+    ///
+    /// Asks for numbers separated by spaces or commas until they are all valid whole numbers,
+    /// and returns them
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::read_numbers;
+    ///
+    /// let numbers = read_numbers("Enter five numbers separated by spaces: ");
+    /// let sum = numbers.iter().sum::<i64>();
+    /// println!("The sum is {}", sum);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no more input to read, see [`prompt_parse`].
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`prompt_parse`]
+    pub fn read_numbers(message: &str) -> Vec<i64> {
+        prompt_validated(message, parse_numbers)
+    }
+
+    ///
+    /// Asks for numbers separated by spaces or commas until they are all valid numbers, like
+    /// [`read_numbers`], but allows decimal numbers like `3.5`
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::read_floats;
+    ///
+    /// let grades = read_floats("Your grades: ");
+    /// let average = grades.iter().sum::<f64>() / grades.len() as f64;
+    /// println!("Your average grade is {:.1}", average);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no more input to read, see [`prompt_parse`].
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`prompt_parse`]
+    pub fn read_floats(message: &str) -> Vec<f64> {
+        prompt_validated(message, parse_numbers)
+    }
+
+    /// parses all numbers separated by spaces or commas
+    fn parse_numbers<T: FromStr>(input: &str) -> Result<Vec<T>, String> {
+        input
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|number| !number.is_empty())
+            .map(|number| {
+                number
+                    .parse()
+                    .map_err(|_| format!("`{}` is not a number. Please try again.", number))
+            })
+            .collect()
+    }
+
+    ///
+    /// A prompt with more options than the prompt functions like [`prompt_parse`], which are
+    /// set one after another
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::Prompt;
+    ///
+    /// let age = Prompt::new("Age: ")
+    ///     .default("18")
+    ///     .validate(|age| {
+    ///         if age.starts_with('-') {
+    ///             Err("You can't be younger than 0")
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     })
+    ///     .max_attempts(3)
+    ///     .ask::<u32>();
+    ///
+    /// match age {
+    ///     Some(age) => println!("You are {} years old", age),
+    ///     None => println!("That's enough attempts"),
+    /// }
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`prompt_parse`]
+    pub struct Prompt<'a> {
+        message: String,
+        default: Option<String>,
+        validators: Vec<Validator<'a>>,
+        max_attempts: Option<u32>,
+        completer: Option<Box<editing::Completer<'a>>>,
+    }
+
+    /// checks the input of a [`Prompt`], returning the message to print if it's invalid
+    type Validator<'a> = Box<dyn FnMut(&str) -> Result<(), String> + 'a>;
+
+    impl<'a> Prompt<'a> {
+        /// Creates a prompt that shows the message on the same line as the input
+        pub fn new(message: &str) -> Self {
+            Prompt {
+                message: message.to_owned(),
+                default: None,
+                validators: Vec::new(),
+                max_attempts: None,
+                completer: None,
+            }
+        }
+
+        /// Uses `default` as the input if nothing is entered, and shows it after the message
+        pub fn default(mut self, default: &str) -> Self {
+            self.default = Some(default.to_owned());
+            self
+        }
+
+        /// Checks the input before it's parsed, and asks again with the error printed if
+        /// `validate` returns an error
+        ///
+        /// Like with [`prompt_validated`], the input is checked without the spaces and newline
+        /// around it. If this is called multiple times, all checks have to succeed.
+        pub fn validate<E: Display>(
+            mut self,
+            mut validate: impl FnMut(&str) -> Result<(), E> + 'a,
+        ) -> Self {
+            self.validators.push(Box::new(move |input| {
+                validate(input).map_err(|err| err.to_string())
+            }));
+            self
+        }
+
+        /// Gives up after `attempts` invalid inputs instead of asking forever, panicking if
+        /// `attempts` is 0
+        pub fn max_attempts(mut self, attempts: u32) -> Self {
+            assert!(attempts > 0, "there has to be at least one attempt");
+            self.max_attempts = Some(attempts);
+            self
+        }
+
+        /// Completes the input with Tab, using the completions returned by `complete`
+        ///
+        /// `complete` gets the input so far and returns all inputs that could be meant by it.
+        /// If there is only one, it replaces the input. Otherwise the input is completed as far
+        /// as all of them are the same, and pressing Tab again shows all of them. This can only
+        /// be done if the input comes from a terminal.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use simple_std::Prompt;
+        ///
+        /// let commands = ["look", "take", "talk", "quit"];
+        ///
+        /// let command = Prompt::new("> ")
+        ///     .complete(|input| {
+        ///         commands
+        ///             .iter()
+        ///             .filter(|command| command.starts_with(input))
+        ///             .map(|command| command.to_string())
+        ///             .collect()
+        ///     })
+        ///     .ask::<String>();
+        /// ```
+        pub fn complete(mut self, complete: impl FnMut(&str) -> Vec<String> + 'a) -> Self {
+            self.completer = Some(Box::new(complete));
+            self
+        }
+
+        /// Asks until the input is valid and can be parsed into the type, like
+        /// [`prompt_parse`], and returns it
+        ///
+        /// Returns `None` if the maximum attempts were used up or the input can't be read, see
+        /// [`try_ask`](Prompt::try_ask) for the reason.
+        pub fn ask<T>(&mut self) -> Option<T>
+        where
+            T: FromStr,
+            T::Err: Display,
+        {
+            self.try_ask().ok()
+        }
+
+        /// Asks until the input is valid and can be parsed into the type like
+        /// [`ask`](Prompt::ask), but returns why it gave up
+        pub fn try_ask<T>(&mut self) -> Result<T, PromptError>
+        where
+            T: FromStr,
+            T::Err: Display,
+        {
+            let message = match &self.default {
+                Some(default) => format!("{}[{}] ", self.message, default),
+                None => self.message.clone(),
+            };
+            let default = self.default.clone();
+
+            let mut attempts = 0;
+            loop {
+                print_flushed(&message);
+                let line = self.read_line(&message).map_err(PromptError::Input)?;
+
+                let input = match (line.trim(), &default) {
+                    ("", Some(default)) => default.as_str(),
+                    (input, _) => input,
+                };
+                match self.check(input) {
+                    Ok(value) => return Ok(value),
+                    Err(err) => outputln!("{}", err),
+                }
+
+                attempts += 1;
+                if self.max_attempts == Some(attempts) {
+                    return Err(PromptError::TooManyAttempts);
+                }
+            }
+        }
+
+        /// reads a line like `try_read_line`, which can be completed if there is a completer
+        fn read_line(&mut self, message: &str) -> Result<String, InputError> {
+            if let Some(completer) = &mut self.completer {
+                if reads_from_stdin() && !line_pending() {
+                    if let Some(raw_mode) = crate::sys::raw_mode() {
+                        return editing::edit_line(raw_mode, message, Some(&mut **completer));
+                    }
+                }
+            }
+            try_read_line()
+        }
+
+        /// runs all validators and parses the input
+        fn check<T>(&mut self, input: &str) -> Result<T, String>
+        where
+            T: FromStr,
+            T::Err: Display,
+        {
+            for validate in &mut self.validators {
+                validate(input)?;
+            }
+            input
+                .parse()
+                .map_err(|err| format!("Invalid input: {}. Please try again.", err))
+        }
+    }
+
+    /// A key that was pressed, returned by [`read_key`]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Key {
+        /// A normal character, like `a`, `A`, `7` or a space
+        Char(char),
+        Enter,
+        Tab,
+        Backspace,
+        Delete,
+        Esc,
+        Up,
+        Down,
+        Left,
+        Right,
+        Home,
+        End,
+    }
+
+    ///
+    /// Waits until a key is pressed and returns it, without the need to press Enter
+    ///
+    /// The key isn't shown. If the input doesn't come from a terminal, a whole line is read
+    /// instead, and its first key is returned (or [`Key::Enter`] if it's empty), so that every
+    /// line counts as one key press.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::{read_key, Key};
+    ///
+    /// let mut position = 0;
+    /// println!("Move with the arrow keys, press q to quit");
+    ///
+    /// loop {
+    ///     match read_key() {
+    ///         Key::Left => position -= 1,
+    ///         Key::Right => position += 1,
+    ///         Key::Char('q') | Key::Esc => break,
+    ///         _ => {}
+    ///     }
+    ///     println!("You are at {}", position);
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no more input to read, see [`prompt_parse`]. Pressing Ctrl+C exits the
+    /// program, like it does everywhere else.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// Reading single keys needs to put the terminal into a special mode, which works
+    /// differently on every operating system. The keys that aren't characters, like the arrow
+    /// keys, are sent as special sequences of characters that need to be decoded.
+    pub fn read_key() -> Key {
+        let raw_mode = if reads_from_stdin() && !line_pending() {
+            crate::sys::raw_mode()
+        } else {
+            None
+        };
+
+        let raw_mode = match raw_mode {
+            Some(raw_mode) => raw_mode,
+            None => {
+                let line = read_line();
+                return match trim_newline(&line) {
+                    "" => Key::Enter,
+                    line => parse_key(line.as_bytes()).0,
+                };
+            }
+        };
+
+        read_key_in_raw_mode(raw_mode).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    ///
+    /// Prints `Press any key to continue...` and waits until a key is pressed
+    ///
+    /// If the input doesn't come from a terminal, it waits for Enter instead. If there is no
+    /// more input to read, it doesn't wait at all.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::pause;
+    ///
+    /// println!("The game is over!");
+    /// // so that the window doesn't close immediately when the program was started by
+    /// // double-clicking on it
+    /// pause();
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`read_key`]
+    pub fn pause() {
+        print_flushed("Press any key to continue...");
+
+        let raw_mode = if reads_from_stdin() && !line_pending() {
+            crate::sys::raw_mode()
+        } else {
+            None
+        };
+
+        match raw_mode {
+            Some(raw_mode) => {
+                // it doesn't matter if reading failed, then there is nothing to wait for
+                let _ = read_key_in_raw_mode(raw_mode);
+                outputln!();
+            }
+            None => {
+                let _ = try_read_line();
+            }
+        }
+    }
+
+    /// reads the next key and leaves raw mode, exiting the program on Ctrl+C, which doesn't
+    /// do that by itself in raw mode
+    fn read_key_in_raw_mode(raw_mode: crate::sys::ModeGuard) -> Result<Key, InputError> {
+        let key = read_raw_key()?;
+        if key == CTRL_C {
+            drop(raw_mode);
+            std::process::exit(130);
+        }
+        Ok(key)
+    }
+
+    /// the key that is read for Ctrl+C in raw mode
+    const CTRL_C: Key = Key::Char('\u{3}');
+
+    /// reads the next key from stdin, which has to be a terminal in raw mode
+    fn read_raw_key() -> Result<Key, InputError> {
+        let stdin = std::io::stdin();
+        let mut stdin = stdin.lock();
+        let buffer = stdin.fill_buf()?;
+        if buffer.is_empty() {
+            return Err(InputError::Ended);
+        }
+        let (key, len) = parse_key(buffer);
+        stdin.consume(len);
+        Ok(key)
+    }
+
+    /// parses the first key in the bytes that were read from the terminal, returning it with
+    /// the amount of bytes it takes up
+    fn parse_key(bytes: &[u8]) -> (Key, usize) {
+        match bytes {
+            [b'\r', ..] | [b'\n', ..] => (Key::Enter, 1),
+            [b'\t', ..] => (Key::Tab, 1),
+            [0x7f, ..] | [0x08, ..] => (Key::Backspace, 1),
+            [0x1b, b'[', b'3', b'~', ..] => (Key::Delete, 4),
+            [0x1b, b'[', b'1', b'~', ..] | [0x1b, b'[', b'7', b'~', ..] => (Key::Home, 4),
+            [0x1b, b'[', b'4', b'~', ..] | [0x1b, b'[', b'8', b'~', ..] => (Key::End, 4),
+            [0x1b, b'[', code, ..] | [0x1b, b'O', code, ..] if b"ABCDHF".contains(code) => {
+                let key = match code {
+                    b'A' => Key::Up,
+                    b'B' => Key::Down,
+                    b'C' => Key::Right,
+                    b'D' => Key::Left,
+                    b'H' => Key::Home,
+                    _ => Key::End,
+                };
+                (key, 3)
+            }
+            [0x1b, b'[', rest @ ..] => {
+                // an unknown sequence, which ends with a byte from `@` to `~`
+                let len = rest
+                    .iter()
+                    .position(|byte| (0x40..=0x7e).contains(byte))
+                    .map_or(bytes.len(), |end| end + 3);
+                (Key::Esc, len)
+            }
+            [0x1b, ..] => (Key::Esc, 1),
+            [first, ..] => {
+                let len = match first {
+                    0xf0..=0xff => 4,
+                    0xe0..=0xef => 3,
+                    0xc0..=0xdf => 2,
+                    _ => 1,
+                };
+                let c = bytes
+                    .get(..len)
+                    .and_then(|bytes| std::str::from_utf8(bytes).ok())
+                    .and_then(|c| c.chars().next());
+                match c {
+                    Some(c) => (Key::Char(c), len),
+                    None => (Key::Char(char::REPLACEMENT_CHARACTER), 1),
+                }
+            }
+            [] => unreachable!("there is always at least one byte"),
+        }
+    }
+
+    ///
+    /// Asks a yes or no question until it's answered, returning `true` for yes
+    ///
+    /// `y`, `yes`, `n` and `no` are accepted, no matter if they are uppercase or lowercase.
+    /// ` [y/n] ` is added after the message.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::confirm;
+    ///
+    /// if confirm("Do you want to play again?") {
+    ///     println!("Let's go!");
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no more input to read, see [`prompt_parse`].
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`prompt_parse`]
+    pub fn confirm(message: &str) -> bool {
+        let message = format!("{} [y/n] ", message);
+        prompt_validated(&message, |answer| {
+            parse_yes_no(answer).ok_or("Please answer with yes or no.")
+        })
+    }
+
+    ///
+    /// Asks a yes or no question like [`confirm`], but returns `default` if nothing is entered
+    ///
+    /// ` [Y/n] ` or ` [y/N] ` is added after the message, depending on the default.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::confirm_default;
+    ///
+    /// if confirm_default("Delete all files?", false) {
+    ///     println!("Deleting...");
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no more input to read, see [`prompt_parse`].
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`prompt_parse`]
+    pub fn confirm_default(message: &str, default: bool) -> bool {
+        let hint = if default { "[Y/n]" } else { "[y/N]" };
+        let message = format!("{} {} ", message, hint);
+        prompt_validated(&message, |answer| {
+            if answer.is_empty() {
+                Ok(default)
+            } else {
+                parse_yes_no(answer).ok_or("Please answer with yes or no.")
+            }
+        })
+    }
+
+    ///
+    /// Shows a numbered list of options and asks until one of them is chosen, returning the
+    /// index of the chosen option (starting at 0)
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::select;
+    ///
+    /// let options = ["Start game", "Settings", "Quit"];
+    ///
+    /// match select("Main menu", &options) {
+    ///     0 => println!("Starting..."),
+    ///     1 => println!("There are no settings yet"),
+    ///     _ => return,
+    /// }
+    /// ```
+    ///
+    /// This prints
+    /// ```text
+    /// Main menu
+    ///   1) Start game
+    ///   2) Settings
+    ///   3) Quit
+    /// Your choice:
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no options, or if there is no more input to read, see [`prompt_parse`].
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`prompt_parse`]
+    pub fn select<T: Display>(message: &str, options: &[T]) -> usize {
+        assert!(!options.is_empty(), "there must be options to select from");
+
+        outputln!("{}", message);
+        for (i, option) in options.iter().enumerate() {
+            outputln!("  {}) {}", i + 1, option);
+        }
+
+        prompt_validated("Your choice: ", |input| parse_choice(input, options.len()))
+    }
+
+    ///
+    /// Shows a numbered list of options and asks for the numbers of any amount of them,
+    /// returning the sorted indices of the chosen options (starting at 0)
+    ///
+    /// The numbers can be separated by commas or spaces, like `1, 3` or `1 3`. Entering nothing
+    /// chooses no options.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::multi_select;
+    ///
+    /// let toppings = ["Cheese", "Mushrooms", "Pineapple", "Olives"];
+    /// let chosen = multi_select("Choose your toppings", &toppings);
+    ///
+    /// for index in chosen {
+    ///     println!("Adding {}", toppings[index]);
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no options, or if there is no more input to read, see [`prompt_parse`].
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`prompt_parse`]
+    pub fn multi_select<T: Display>(message: &str, options: &[T]) -> Vec<usize> {
+        assert!(!options.is_empty(), "there must be options to select from");
+
+        outputln!("{}", message);
+        for (i, option) in options.iter().enumerate() {
+            outputln!("  {}) {}", i + 1, option);
+        }
+
+        prompt_validated("Your choices (separated by commas): ", |input| {
+            parse_choices(input, options.len())
+        })
+    }
+
+    fn parse_int_in_range(input: &str, range: &RangeInclusive<i32>) -> Result<i32, String> {
+        match input.parse::<i32>() {
+            Ok(number) if range.contains(&number) => Ok(number),
+            _ => Err(format!(
+                "Please enter a whole number from {} to {}.",
+                range.start(),
+                range.end()
+            )),
+        }
+    }
+
+    /// parses the numbers of options of a menu with `len` options into their sorted indices
+    fn parse_choices(input: &str, len: usize) -> Result<Vec<usize>, String> {
+        let mut indices = input
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|number| !number.is_empty())
+            .map(|number| parse_choice(number, len))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        indices.sort_unstable();
+        indices.dedup();
+        Ok(indices)
+    }
+
+    /// parses the number of an option of a menu with `len` options into its index
+    fn parse_choice(input: &str, len: usize) -> Result<usize, String> {
+        match input.parse::<usize>() {
+            Ok(number) if (1..=len).contains(&number) => Ok(number - 1),
+            _ => Err(format!(
+                "Please enter the number of an option, from 1 to {}.",
+                len
+            )),
+        }
+    }
+
+    fn parse_yes_no(answer: &str) -> Option<bool> {
+        match answer.to_lowercase().as_str() {
+            "y" | "yes" => Some(true),
+            "n" | "no" => Some(false),
+            _ => None,
+        }
+    }
+
+    /// like [`prompt`], but panics when the input has ended instead of returning an empty
+    /// string, so that asking again doesn't loop forever
+    fn prompt_line(message: &str) -> String {
+        print_flushed(message);
+        read_line()
+    }
+
+    fn print_flushed(message: &str) {
+        output!("{}", message);
+    }
+
+    thread_local! {
+        /// the buffer of `capture_output`, output is printed normally if it's `None`
+        static CAPTURED_OUTPUT: RefCell<Option<String>> = const { RefCell::new(None) };
+    }
+
+    /// prints and flushes the output, or adds it to the buffer of [`capture_output`]
+    pub fn write_output(args: std::fmt::Arguments<'_>) {
+        if !capture(args) {
+            use std::io::Write;
+
+            let mut stdout = std::io::stdout();
+            stdout.write_fmt(args).unwrap();
+            stdout.flush().unwrap();
+        }
+    }
+
+    /// prints the output to stderr, or adds it to the buffer of [`capture_output`]
+    pub(crate) fn write_error_output(args: std::fmt::Arguments<'_>) {
+        if !capture(args) {
+            use std::io::Write;
+
+            std::io::stderr().write_fmt(args).unwrap();
+        }
+    }
+
+    /// adds the output to the buffer of [`capture_output`] and returns `true`, or returns
+    /// `false` if the output isn't captured
+    fn capture(args: std::fmt::Arguments<'_>) -> bool {
+        CAPTURED_OUTPUT.with(|captured| match &mut *captured.borrow_mut() {
+            Some(buffer) => {
+                buffer.push_str(&args.to_string());
+                true
+            }
+            None => false,
+        })
+    }
+
+    /// whether the output of this thread is captured by [`capture_output`]
+    pub(crate) fn capturing_output() -> bool {
+        CAPTURED_OUTPUT.with(|captured| captured.borrow().is_some())
+    }
+
+    /// runs `f` and returns everything it printed with [`write_output`]
+    pub(crate) fn capture_output(f: impl FnOnce()) -> String {
+        /// puts back the previous buffer, even if `f` panics
+        struct Restore(Option<String>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                let previous = self.0.take();
+                CAPTURED_OUTPUT.with(|captured| *captured.borrow_mut() = previous);
+            }
+        }
+
+        let _restore =
+            Restore(CAPTURED_OUTPUT.with(|captured| captured.replace(Some(String::new()))));
+        f();
+        CAPTURED_OUTPUT
+            .with(|captured| captured.borrow_mut().take())
+            .unwrap_or_default()
+    }
+
+    /// reads a line including the newline, panicking if the input has ended
+    fn read_line() -> String {
+        try_read_line().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// reads a line including the newline
+    fn try_read_line() -> Result<String, InputError> {
+        let mut buffer = String::new();
+        let read = INPUT_SOURCE.with(|current| match &mut *current.borrow_mut() {
+            Some(source) => source.read_line(&mut buffer).map(Some),
+            None => Ok(None),
+        })?;
+
+        match read {
+            Some(0) => Err(InputError::Ended),
+            Some(_) => Ok(buffer),
+            None => {
+                // the line that `prompt_timeout` stopped waiting for comes first
+                let pending = PENDING_LINE.lock().unwrap().take();
+                match pending {
+                    Some(pending) => pending.recv().unwrap_or(Err(InputError::Ended)),
+                    None => read_terminal_line(),
+                }
+            }
+        }
+    }
+
+    /// reads a line including the newline from stdin, which can be edited if line editing is
+    /// enabled and stdin is a terminal
+    fn read_terminal_line() -> Result<String, InputError> {
+        #[cfg(feature = "line-editing")]
+        {
+            if let Some(raw_mode) = crate::sys::raw_mode() {
+                return editing::edit_line(raw_mode, "", None);
+            }
+        }
+        read_stdin_line()
+    }
+
+    /// reads a line including the newline from stdin
+    fn read_stdin_line() -> Result<String, InputError> {
+        let mut buffer = String::new();
+        if std::io::stdin().read_line(&mut buffer)? == 0 {
+            return Err(InputError::Ended);
+        }
+        Ok(buffer)
+    }
+
+    /// the line that is still being read by the thread of a `prompt_timeout` that ran out of time
+    static PENDING_LINE: Mutex<Option<Receiver<Result<String, InputError>>>> = Mutex::new(None);
+
+    fn line_pending() -> bool {
+        PENDING_LINE.lock().unwrap().is_some()
+    }
+
+    /// editing the line with the arrow keys, going through the lines entered before and
+    /// completing it with Tab, which the terminal doesn't do by itself
+    ///
+    /// This is used for every line with the `line-editing` feature, and otherwise only for a
+    /// [`Prompt`] with completion.
+    mod editing {
+        use super::{read_raw_key, InputError, Key, CTRL_C};
+        use crate::sys::ModeGuard;
+        use std::sync::Mutex;
+
+        /// returns the possible completions of the line, see [`Prompt::complete`]
+        ///
+        /// [`Prompt::complete`]: crate::Prompt::complete
+        pub(crate) type Completer<'a> = dyn FnMut(&str) -> Vec<String> + 'a;
+
+        /// the lines entered before, the oldest first
+        static HISTORY: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+        /// reads a line including the newline from the terminal, which has to be in raw mode
+        ///
+        /// `message` is what was printed before on the same line, which is printed again after
+        /// the possible completions were shown.
+        pub(super) fn edit_line(
+            raw_mode: ModeGuard,
+            message: &str,
+            completer: Option<&mut Completer<'_>>,
+        ) -> Result<String, InputError> {
+            let history = HISTORY.lock().unwrap().clone();
+
+            let mut raw_mode = Some(raw_mode);
+            let line = edit(&history, message, completer, || {
+                let key = read_raw_key()?;
+                if key == CTRL_C {
+                    drop(raw_mode.take());
+                    std::process::exit(130);
+                }
+                Ok(key)
+            })?;
+
+            let entered = line.trim_end_matches('\n');
+            if !entered.is_empty() && history.last().map(String::as_str) != Some(entered) {
+                HISTORY.lock().unwrap().push(entered.to_owned());
+            }
+            Ok(line)
+        }
+
+        /// edits a line with the keys until Enter is pressed, showing the changes
+        fn edit(
+            history: &[String],
+            message: &str,
+            mut completer: Option<&mut Completer<'_>>,
+            mut next_key: impl FnMut() -> Result<Key, InputError>,
+        ) -> Result<String, InputError> {
+            let mut line = Line::default();
+            let mut history_index = history.len();
+            // the line that was edited before going through the history
+            let mut draft = String::new();
+
+            loop {
+                match next_key()? {
+                    Key::Enter => {
+                        outputln!();
+                        let mut text = line.text();
+                        text.push('\n');
+                        return Ok(text);
+                    }
+                    // Ctrl+D on an empty line, which ends the input on unix
+                    Key::Char('\u{4}') if line.chars.is_empty() => {
+                        outputln!();
+                        return Err(InputError::Ended);
+                    }
+                    Key::Char(c) if !c.is_control() => line.insert(c),
+                    Key::Backspace => line.backspace(),
+                    Key::Delete => line.delete(),
+                    Key::Left => line.left(),
+                    Key::Right => line.right(),
+                    Key::Home => line.home(),
+                    Key::End => line.end(),
+                    Key::Up if history_index > 0 => {
+                        if history_index == history.len() {
+                            draft = line.text();
+                        }
+                        history_index -= 1;
+                        line.replace(&history[history_index]);
+                    }
+                    Key::Down if history_index < history.len() => {
+                        history_index += 1;
+                        let text = history.get(history_index).unwrap_or(&draft);
+                        line.replace(text);
+                    }
+                    Key::Tab => {
+                        if let Some(completer) = &mut completer {
+                            complete(&mut line, message, completer);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        /// completes the line if there is only one completion, or as far as all completions
+        /// are the same, and shows all of them otherwise
+        fn complete(line: &mut Line, message: &str, completer: &mut Completer<'_>) {
+            let text = line.text();
+            let completions = completer(&text);
+
+            let prefix = match completions.split_first() {
+                Some((first, rest)) => rest.iter().fold(first.as_str(), |prefix, completion| {
+                    let len = prefix
+                        .char_indices()
+                        .zip(completion.chars())
+                        .find(|((_, a), b)| a != b)
+                        .map_or(prefix.len().min(completion.len()), |((i, _), _)| i);
+                    &prefix[..len]
+                }),
+                None => return,
+            };
+
+            if prefix.len() > text.len() || completions.len() == 1 {
+                line.replace(prefix);
+            } else {
+                line.end();
+                outputln!();
+                outputln!("{}", completions.join("  "));
+                output!("{}{}", message, text);
+            }
+        }
+
+        /// the line that is being edited, which is always shown in the terminal with the
+        /// cursor at the same position
+        #[derive(Default)]
+        struct Line {
+            chars: Vec<char>,
+            cursor: usize,
+        }
+
+        // moving the cursor left is done with `\x08` and right by printing the character
+        // again, which works in every terminal
+        impl Line {
+            fn text(&self) -> String {
+                self.chars.iter().collect()
+            }
+
+            fn insert(&mut self, c: char) {
+                self.chars.insert(self.cursor, c);
+                self.cursor += 1;
+                output!("{}", c);
+                self.redraw_rest(0);
+            }
+
+            fn backspace(&mut self) {
+                if self.cursor > 0 {
+                    self.cursor -= 1;
+                    self.chars.remove(self.cursor);
+                    output!("\x08");
+                    self.redraw_rest(1);
+                }
+            }
+
+            fn delete(&mut self) {
+                if self.cursor < self.chars.len() {
+                    self.chars.remove(self.cursor);
+                    self.redraw_rest(1);
+                }
+            }
+
+            fn left(&mut self) {
+                if self.cursor > 0 {
+                    self.cursor -= 1;
+                    output!("\x08");
+                }
+            }
+
+            fn right(&mut self) {
+                if let Some(c) = self.chars.get(self.cursor) {
+                    self.cursor += 1;
+                    output!("{}", c);
+                }
+            }
+
+            fn home(&mut self) {
+                output!("{}", "\x08".repeat(self.cursor));
+                self.cursor = 0;
+            }
+
+            fn end(&mut self) {
+                let rest = self.chars[self.cursor..].iter().collect::<String>();
+                output!("{}", rest);
+                self.cursor = self.chars.len();
+            }
+
+            fn replace(&mut self, text: &str) {
+                let old_len = self.chars.len();
+                self.home();
+                self.chars = text.chars().collect();
+                self.redraw_rest(old_len.saturating_sub(self.chars.len()));
+                self.end();
+            }
+
+            /// prints everything after the cursor again and overwrites the `removed` characters
+            /// after it with spaces, moving the cursor back to where it was
+            fn redraw_rest(&self, removed: usize) {
+                let rest = self.chars[self.cursor..].iter().collect::<String>();
+                let back = self.chars.len() - self.cursor + removed;
+                output!("{}{}{}", rest, " ".repeat(removed), "\x08".repeat(back));
+            }
+        }
+
+        #[cfg(test)]
+        mod test {
+            use super::edit;
+            use crate::testing::capture_output;
+            use crate::{InputError, Key};
+
+            fn edit_keys(history: &[&str], keys: &[Key]) -> Result<String, InputError> {
+                let history = history
+                    .iter()
+                    .map(|&line| line.to_owned())
+                    .collect::<Vec<_>>();
+                let mut keys = keys.iter().copied();
+                let mut result = None;
+                capture_output(|| {
+                    let next_key = || keys.next().ok_or(InputError::Ended);
+                    result = Some(edit(&history, "", None, next_key));
+                });
+                result.unwrap()
+            }
+
+            #[test]
+            fn edits_line() {
+                use Key::*;
+
+                let keys = [
+                    Char('a'),
+                    Char('b'),
+                    Left,
+                    Char('x'),
+                    End,
+                    Char('c'),
+                    Backspace,
+                    Home,
+                    Delete,
+                    Right,
+                    Char('y'),
+                    Enter,
+                ];
+                assert_eq!(edit_keys(&[], &keys).unwrap(), "xyb\n");
+                assert!(matches!(
+                    edit_keys(&[], &[Char('\u{4}')]),
+                    Err(InputError::Ended)
+                ));
+            }
+
+            #[test]
+            fn shows_edits() {
+                let mut output = String::new();
+                let mut line = super::Line::default();
+                output += &capture_output(|| line.insert('a'));
+                output += &capture_output(|| line.insert('b'));
+                output += &capture_output(|| line.left());
+                output += &capture_output(|| line.backspace());
+                assert_eq!(output, "ab\x08\x08b \x08\x08");
+            }
+
+            #[test]
+            fn completes() {
+                use Key::*;
+
+                let mut completer = |line: &str| {
+                    ["north", "northeast", "south"]
+                        .iter()
+                        .filter(|direction| direction.starts_with(line))
+                        .map(|&direction| direction.to_owned())
+                        .collect()
+                };
+                let mut complete = |keys: &[Key]| {
+                    let mut keys = keys.iter().copied();
+                    let mut result = None;
+                    let output = capture_output(|| {
+                        let next_key = || keys.next().ok_or(InputError::Ended);
+                        result = Some(edit(&[], "> ", Some(&mut completer), next_key));
+                    });
+                    (result.unwrap().unwrap(), output)
+                };
+
+                assert_eq!(complete(&[Char('s'), Tab, Enter]).0, "south\n");
+                assert_eq!(complete(&[Char('n'), Tab, Enter]).0, "north\n");
+                assert_eq!(complete(&[Char('x'), Tab, Enter]).0, "x\n");
+
+                let (line, output) = complete(&[Char('n'), Tab, Tab, Char('!'), Enter]);
+                assert_eq!(line, "north!\n");
+                assert!(output.contains("\nnorth  northeast\n> north!"));
+            }
+
+            #[test]
+            fn goes_through_history() {
+                use Key::*;
+
+                let history = ["first", "second"];
+                assert_eq!(
+                    edit_keys(&history, &[Up, Up, Down, Enter]).unwrap(),
+                    "second\n"
+                );
+                assert_eq!(
+                    edit_keys(&history, &[Up, Up, Up, Char('!'), Enter]).unwrap(),
+                    "first!\n"
+                );
+                assert_eq!(
+                    edit_keys(&history, &[Char('d'), Up, Down, Down, Enter]).unwrap(),
+                    "d\n"
+                );
+            }
+        }
+    }
+
+    /// removes the `\n` or `\r\n` at the end
+    fn trim_newline(line: &str) -> &str {
+        let line = line.strip_suffix('\n').unwrap_or(line);
+        line.strip_suffix('\r').unwrap_or(line)
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::{capture_output, check_path, parse_float, parse_key, parse_numbers, Key};
+        use super::{
+            parse_choice, parse_choices, parse_int_in_range, parse_yes_no, trim_newline, InputError,
+        };
+        use crate::{
+            input, input_lines, input_multiline, pause, prompt_new_password, prompt_parse,
+            prompt_parse_with_retries, prompt_timeout, read_all_stdin, read_key,
+            reset_input_source, select, set_input_source, stdin_lines, try_input, Prompt,
+            PromptError,
+        };
+        use std::io::Cursor;
+        use std::time::Duration;
+
+        #[test]
+        fn trims_newline() {
+            assert_eq!(trim_newline("hello\n"), "hello");
+            assert_eq!(trim_newline("hello\r\n"), "hello");
+            assert_eq!(trim_newline(" hello \n"), " hello ");
+            assert_eq!(trim_newline("hello"), "hello");
+            assert_eq!(trim_newline("\n\n"), "\n");
+        }
+
+        #[test]
+        fn yes_no() {
+            ["y", "Y", "yes", "YES", "Yes"]
+                .iter()
+                .for_each(|answer| assert_eq!(parse_yes_no(answer), Some(true)));
+            ["n", "N", "no", "NO", "nO"]
+                .iter()
+                .for_each(|answer| assert_eq!(parse_yes_no(answer), Some(false)));
+            ["", "ye", "nope", "maybe", "1"]
+                .iter()
+                .for_each(|answer| assert_eq!(parse_yes_no(answer), None));
+        }
+
+        #[test]
+        fn choice() {
+            assert_eq!(parse_choice("1", 3), Ok(0));
+            assert_eq!(parse_choice("3", 3), Ok(2));
+            assert!(parse_choice("0", 3).is_err());
+            assert!(parse_choice("4", 3).is_err());
+            assert!(parse_choice("-1", 3).is_err());
+            assert!(parse_choice("Quit", 3).is_err());
+        }
+
+        #[test]
+        fn choices() {
+            assert_eq!(parse_choices("1, 3", 3), Ok(vec![0, 2]));
+            assert_eq!(parse_choices("3 1,2", 3), Ok(vec![0, 1, 2]));
+            assert_eq!(parse_choices("2,2, 2", 3), Ok(vec![1]));
+            assert_eq!(parse_choices("", 3), Ok(vec![]));
+            assert!(parse_choices("1, 4", 3).is_err());
+            assert!(parse_choices("1, cheese", 3).is_err());
+        }
+
+        #[test]
+        fn int_in_range() {
+            assert_eq!(parse_int_in_range("1", &(1..=100)), Ok(1));
+            assert_eq!(parse_int_in_range("100", &(1..=100)), Ok(100));
+            assert_eq!(parse_int_in_range("-5", &(-10..=0)), Ok(-5));
+            assert!(parse_int_in_range("0", &(1..=100)).is_err());
+            assert!(parse_int_in_range("101", &(1..=100)).is_err());
+            assert!(parse_int_in_range("4.5", &(1..=100)).is_err());
+        }
+
+        #[test]
+        fn input_error_from_io() {
+            let invalid_data = std::io::Error::new(std::io::ErrorKind::InvalidData, "invalid");
+            assert!(matches!(
+                InputError::from(invalid_data),
+                InputError::InvalidUtf8
+            ));
+
+            let other = std::io::Error::new(std::io::ErrorKind::NotFound, "gone");
+            assert!(matches!(InputError::from(other), InputError::Io(_)));
+        }
+
+        #[test]
+        fn reads_from_input_source() {
+            set_input_source(Cursor::new("hello\r\nnot a number\n42\n"));
+
+            assert_eq!(input(), "hello");
+            assert_eq!(prompt_parse::<i32>(""), 42);
+            assert!(matches!(try_input(), Err(InputError::Ended)));
+            assert_eq!(input(), "");
+
+            reset_input_source();
+        }
+
+        #[test]
+        fn captures_output() {
+            set_input_source(Cursor::new("4\n2\n"));
+            let output = capture_output(|| {
+                assert_eq!(select("Menu", &["Play", "Quit"]), 1);
+            });
+            reset_input_source();
+
+            assert_eq!(
+                output,
+                "Menu\n  1) Play\n  2) Quit\nYour choice: \
+                 Please enter the number of an option, from 1 to 2.\nYour choice: "
+            );
+        }
+
+        #[test]
+        fn restores_output_after_panic() {
+            let output = capture_output(|| {
+                outputln!("before");
+                let result = std::panic::catch_unwind(|| capture_output(|| panic!("oh no")));
+                assert!(result.is_err());
+                outputln!("after");
+            });
+            assert_eq!(output, "before\nafter\n");
+        }
+
+        #[test]
+        fn keys() {
+            assert_eq!(parse_key(b"a"), (Key::Char('a'), 1));
+            assert_eq!(parse_key(b"ab"), (Key::Char('a'), 1));
+            assert_eq!(parse_key("ä".as_bytes()), (Key::Char('ä'), 2));
+            assert_eq!(parse_key("🦀".as_bytes()), (Key::Char('🦀'), 4));
+            assert_eq!(parse_key(b"\r"), (Key::Enter, 1));
+            assert_eq!(parse_key(b"\n"), (Key::Enter, 1));
+            assert_eq!(parse_key(b"\x7f"), (Key::Backspace, 1));
+            assert_eq!(parse_key(b"\x1b"), (Key::Esc, 1));
+            assert_eq!(parse_key(b"\x1b[A"), (Key::Up, 3));
+            assert_eq!(parse_key(b"\x1bOD"), (Key::Left, 3));
+            assert_eq!(parse_key(b"\x1b[3~"), (Key::Delete, 4));
+            assert_eq!(parse_key(b"\x1b[15~a"), (Key::Esc, 5));
+            assert_eq!(
+                parse_key(b"\xff"),
+                (Key::Char(char::REPLACEMENT_CHARACTER), 1)
+            );
+        }
+
+        #[test]
+        fn reads_keys_from_lines() {
+            crate::testing::set_input(["w", "", "\x1b[B"]);
+            assert_eq!(read_key(), Key::Char('w'));
+            assert_eq!(read_key(), Key::Enter);
+            assert_eq!(read_key(), Key::Down);
+            reset_input_source();
+        }
+
+        #[test]
+        fn timeout_with_input_source() {
+            crate::testing::set_input(["56"]);
+            let timeout = Duration::from_millis(10);
+            assert_eq!(prompt_timeout("", timeout), Some("56".to_owned()));
+            assert_eq!(prompt_timeout("", timeout), None);
+            reset_input_source();
+        }
+
+        #[test]
+        fn multiline() {
+            crate::testing::set_input(["roses are red", "violets are blue", "", "next"]);
+            assert_eq!(input_multiline(), "roses are red\nviolets are blue");
+            assert_eq!(input_lines(), ["next"]);
+            assert!(input_lines().is_empty());
+            reset_input_source();
+        }
+
+        #[test]
+        fn numbers() {
+            assert_eq!(parse_numbers::<i64>("1 2  3"), Ok(vec![1, 2, 3]));
+            assert_eq!(parse_numbers::<i64>("-4, 5,6"), Ok(vec![-4, 5, 6]));
+            assert_eq!(parse_numbers::<i64>(""), Ok(vec![]));
+            assert!(parse_numbers::<i64>("1 two 3").is_err());
+            assert!(parse_numbers::<i64>("1.5").is_err());
+            assert_eq!(parse_numbers::<f64>("1.5 2"), Ok(vec![1.5, 2.0]));
+        }
+
+        #[test]
+        fn prompt_builder() {
+            crate::testing::set_input(["", "-3", "abc", "7", "1", "2"]);
+
+            assert_eq!(Prompt::new("").default("18").ask::<u32>(), Some(18));
+
+            let mut attempts = Prompt::new("")
+                .validate(|input| match input.starts_with('-') {
+                    true => Err("negative"),
+                    false => Ok(()),
+                })
+                .max_attempts(2);
+            assert_eq!(attempts.ask::<i32>(), None);
+            assert_eq!(attempts.ask::<i32>(), Some(7));
+
+            let mut even = Prompt::new("").validate(|input| match input.parse::<u8>() {
+                Ok(n) if n % 2 == 0 => Ok(()),
+                _ => Err("odd"),
+            });
+            assert_eq!(even.ask::<u8>(), Some(2));
+            assert_eq!(even.ask::<u8>(), None);
+
+            reset_input_source();
+        }
+
+        #[test]
+        fn paths() {
+            let file = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml");
+            let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+            let missing = concat!(env!("CARGO_MANIFEST_DIR"), "/missing.txt");
+
+            assert_eq!(check_path(file, false), Ok(file.into()));
+            assert_eq!(check_path(dir, true), Ok(dir.into()));
+            assert!(check_path(file, true).is_err());
+            assert!(check_path(dir, false).is_err());
+            assert!(check_path(missing, false).is_err());
+        }
+
+        #[test]
+        fn new_password() {
+            crate::testing::set_input(["", "hunter2", "hunter3", "hunter2", "hunter2"]);
+            let output =
+                capture_output(|| assert_eq!(prompt_new_password("Password: "), "hunter2"));
+            reset_input_source();
+
+            assert_eq!(
+                output,
+                "Password: The password must not be empty.\n\
+                 Password: Repeat the password: The passwords are not the same. Please try again.\n\
+                 Password: Repeat the password: "
+            );
+        }
+
+        #[test]
+        fn all_input() {
+            set_input_source(Cursor::new("first\r\nsecond\n\nlast"));
+            assert_eq!(
+                stdin_lines().collect::<Vec<_>>(),
+                ["first", "second", "", "last"]
+            );
+
+            set_input_source(Cursor::new("first\r\nsecond\n\nlast"));
+            assert_eq!(read_all_stdin(), "first\r\nsecond\n\nlast");
+            assert_eq!(read_all_stdin(), "");
+            reset_input_source();
+        }
+
+        #[test]
+        fn retries() {
+            crate::testing::set_input(["a", "b", "3", "c", "d"]);
+            assert_eq!(prompt_parse_with_retries::<i32>("", 3).unwrap(), 3);
+            assert!(matches!(
+                prompt_parse_with_retries::<i32>("", 2),
+                Err(PromptError::TooManyAttempts)
+            ));
+            assert!(matches!(
+                prompt_parse_with_retries::<i32>("", 2),
+                Err(PromptError::Input(InputError::Ended))
+            ));
+            reset_input_source();
+        }
+
+        #[test]
+        fn floats() {
+            assert_eq!(parse_float("2.5"), Ok(2.5));
+            assert_eq!(parse_float("2,5"), Ok(2.5));
+            assert_eq!(parse_float("-0,5"), Ok(-0.5));
+            assert_eq!(parse_float("42"), Ok(42.0));
+            assert!(parse_float("1,000.5").is_err());
+            assert!(parse_float("1,2,3").is_err());
+            assert!(parse_float("inf").is_err());
+            assert!(parse_float("NaN").is_err());
+            assert!(parse_float("pi").is_err());
+        }
+
+        #[test]
+        fn pauses() {
+            crate::testing::set_input(["", "after"]);
+            let output = capture_output(|| {
+                pause();
+                // there is no more input after this, but it doesn't wait or panic
+                assert_eq!(input(), "after");
+                pause();
+            });
+            reset_input_source();
+
+            assert_eq!(
+                output,
+                "Press any key to continue...Press any key to continue..."
+            );
+        }
+
+        #[cfg(unix)]
+        #[test]
+        fn edits_in_editor() {
+            use super::edit_with;
+
+            let editor = |args: &[&str]| args.iter().map(|&arg| arg.to_owned()).collect();
+            let editors = vec![
+                editor(&["simple-std-editor-that-does-not-exist"]),
+                // appends to the file, which is the last argument
+                editor(&["sh", "-c", "printf ' a dragon' >> \"$1\"", "sh"]),
+            ];
+
+            assert_eq!(
+                edit_with("Once upon a time, there was", &editors),
+                "Once upon a time, there was a dragon"
+            );
+        }
+    }
+}
+
+mod random {
+    use crate::time::Date;
+    use std::collections::HashMap;
+    use std::convert::TryFrom;
+    use std::ops::{Range, RangeInclusive};
+    use std::time::Duration;
+
+    ///
+    /// Returns a random number from 0 to 1, like Javascript `Math.random`
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::random_float;
+    ///
+    /// let number = random_float();
+    ///
+    /// println!("Number between 0 and 1: {}", number);
+    ///
+    /// assert!(number < 1.0);
+    /// assert!(number >= 0.0);
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// Rust aims to be correct, that's why its major random number library is cryptographically secure,
+    /// meaning its randomness can't easily be guessed. And cryptographically secure random number generation
+    /// is a big task, that's why it has its own crate.
+    pub fn random_float() -> f64 {
+        with_global_rng(SimpleRng::next_float)
+    }
+
+    ///
+    /// Returns an integer number contained in the range. Both `start..end` and `start..=end`
+    /// ranges can be used.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::random_int_range;
+    ///
+    /// let number = random_int_range(0..100);
+    ///
+    /// println!("Number between 0 and 100: {}", number);
+    ///
+    /// assert!(number < 100);
+    /// assert!(number >= 0);
+    ///
+    /// let dice = random_int_range(1..=6);
+    ///
+    /// println!("You rolled a {}", dice);
+    ///
+    /// assert!(dice <= 6);
+    /// assert!(dice >= 1);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty, like `5..5`.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    ///
+    pub fn random_int_range(range: impl IntoIntRange) -> i32 {
+        with_global_rng(|rng| rng.next_int_range(range))
+    }
+
+    ///
+    /// Returns an `i64` contained in the range, for when [`random_int_range`] is too small
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::random_int_range_i64;
+    ///
+    /// let population = random_int_range_i64(1_000_000_000..=10_000_000_000);
+    ///
+    /// println!("The planet has {} inhabitants", population);
+    ///
+    /// assert!(population >= 1_000_000_000);
+    /// assert!(population <= 10_000_000_000);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty, like `5..5`.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn random_int_range_i64(range: impl IntoIntRange<i64>) -> i64 {
+        with_global_rng(|rng| rng.next_int_range_i64(range))
+    }
+
+    ///
+    /// Returns a `u64` contained in the range, for when [`random_int_range`] is too small
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::random_int_range_u64;
+    ///
+    /// let id = random_int_range_u64(0..=u64::MAX);
+    ///
+    /// println!("Your id is {}", id);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty, like `5..5`.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn random_int_range_u64(range: impl IntoIntRange<u64>) -> u64 {
+        with_global_rng(|rng| rng.next_int_range_u64(range))
+    }
+
+    ///
+    /// A range of integers that a random number can be picked from, implemented for
+    /// `start..end` and `start..=end` of `i32`, `i64` and `u64`.
+    ///
+    /// This exists so that [`random_int_range`] can take both kinds of ranges.
+    pub trait IntoIntRange<T = i32> {
+        /// Converts the range into a `start..=end` range, panicking if it is empty
+        fn into_int_range(self) -> RangeInclusive<T>;
+    }
+
+    macro_rules! impl_into_int_range {
+        ($($ty:ty),*) => {
+            $(
+                impl IntoIntRange<$ty> for Range<$ty> {
+                    fn into_int_range(self) -> RangeInclusive<$ty> {
+                        assert!(
+                            self.start < self.end,
+                            "cannot pick a number from the empty range {:?}",
+                            self
+                        );
+                        self.start..=(self.end - 1)
+                    }
+                }
+
+                impl IntoIntRange<$ty> for RangeInclusive<$ty> {
+                    fn into_int_range(self) -> RangeInclusive<$ty> {
+                        assert!(
+                            self.start() <= self.end(),
+                            "cannot pick a number from the empty range {:?}",
+                            self
+                        );
+                        self
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_into_int_range!(i32, i64, u64);
+
+    ///
+    /// Returns a floating point number contained in the range
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::random_float_range;
+    ///
+    /// let speed = random_float_range(0.5..2.5);
+    ///
+    /// println!("The car drives at {:.2} km/min", speed);
+    ///
+    /// assert!(speed < 2.5);
+    /// assert!(speed >= 0.5);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty, or if the start or end is infinite or not a number.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn random_float_range(range: Range<f64>) -> f64 {
+        with_global_rng(|rng| rng.next_float_range(range))
+    }
+
+    ///
+    /// Returns an endless iterator of random numbers from 0 to 1, see [`random_float`]
+    ///
+    /// The numbers come from the global generator, one at a time while iterating. They are
+    /// shared with all other random functions, so for numbers that are the same every time,
+    /// use [`SimpleRng::floats`] on a seeded generator instead.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::random_floats;
+    ///
+    /// let numbers = random_floats().take(5).collect::<Vec<_>>();
+    ///
+    /// println!("Five random numbers: {:?}", numbers);
+    ///
+    /// assert_eq!(numbers.len(), 5);
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn random_floats() -> impl Iterator<Item = f64> {
+        std::iter::repeat_with(random_float)
+    }
+
+    ///
+    /// Returns an endless iterator of random integers contained in the range, see [`random_int_range`]
+    ///
+    /// The numbers come from the global generator, one at a time while iterating. They are
+    /// shared with all other random functions, so for numbers that are the same every time,
+    /// use [`SimpleRng::ints`] on a seeded generator instead.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::random_ints;
+    ///
+    /// let numbers = random_ints(0..10).take(5).collect::<Vec<_>>();
+    ///
+    /// println!("Five random numbers: {:?}", numbers);
+    ///
+    /// assert!(numbers.iter().all(|&n| n >= 0 && n < 10));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty, like `5..5`.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn random_ints(range: impl IntoIntRange) -> impl Iterator<Item = i32> {
+        let range = range.into_int_range();
+        std::iter::repeat_with(move || random_int_range(range.clone()))
+    }
+
+    ///
+    /// Returns `true` with the given probability, which has to be between 0 and 1
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::random_bool;
+    ///
+    /// // the enemy attacks 30% of the time
+    /// if random_bool(0.3) {
+    ///     println!("The enemy attacks!");
+    /// }
+    ///
+    /// assert!(random_bool(1.0));
+    /// assert!(!random_bool(0.0));
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn random_bool(probability: f64) -> bool {
+        with_global_rng(|rng| rng.next_bool(probability))
+    }
+
+    ///
+    /// Returns `true` or `false`, both with the same chance
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::fifty_fifty;
+    ///
+    /// if fifty_fifty() {
+    ///     println!("You found a coin!");
+    /// } else {
+    ///     println!("Nothing here...");
+    /// }
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn fifty_fifty() -> bool {
+        random_bool(0.5)
+    }
+
+    ///
+    /// Shuffles the elements of a slice into a random order
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::shuffle;
+    ///
+    /// let mut cards = vec!["Ace", "King", "Queen", "Jack"];
+    /// shuffle(&mut cards);
+    ///
+    /// println!("Cards: {:?}", cards);
+    ///
+    /// assert_eq!(cards.len(), 4);
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn shuffle<T>(slice: &mut [T]) {
+        with_global_rng(|rng| rng.shuffle(slice))
+    }
+
+    ///
+    /// Returns a random element of the slice, or `None` if the slice is empty
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::choose;
+    ///
+    /// let words = ["apple", "banana", "cherry"];
+    /// let word = choose(&words).unwrap();
+    ///
+    /// println!("Your word is: {}", word);
+    ///
+    /// assert!(words.contains(word));
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn choose<T>(items: &[T]) -> Option<&T> {
+        with_global_rng(|rng| rng.choose(items))
+    }
+
+    ///
+    /// Returns a mutable reference to a random element of the slice, or `None` if the slice is empty
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::choose_mut;
+    ///
+    /// let mut enemy_health = [10, 10, 10];
+    ///
+    /// // hit a random enemy
+    /// if let Some(health) = choose_mut(&mut enemy_health) {
+    ///     *health -= 3;
+    /// }
+    ///
+    /// assert_eq!(enemy_health.iter().sum::<i32>(), 27);
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn choose_mut<T>(items: &mut [T]) -> Option<&mut T> {
+        with_global_rng(move |rng| rng.choose_mut(items))
+    }
+
+    ///
+    /// Returns a random element of an iterator, or `None` if it is empty
+    ///
+    /// The iterator is only walked through once and the elements are not collected, so this
+    /// also works for huge iterators like the lines of a big file.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::choose_from_iter;
+    ///
+    /// let words = "apple\nbanana\ncherry";
+    /// let word = choose_from_iter(words.lines()).unwrap();
+    ///
+    /// println!("Your word is: {}", word);
+    ///
+    /// assert!(words.contains(word));
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn choose_from_iter<T>(iter: impl IntoIterator<Item = T>) -> Option<T> {
+        // the iterator might use random numbers itself, so the global generator is only used for
+        // single steps while `next` isn't running
+        reservoir_sample(iter, |len| with_global_rng(|rng| rng.next_index(len)))
+    }
+
+    /// picks a random element with reservoir sampling, using `random_index(len)` to get an index
+    /// in `0..len`
+    fn reservoir_sample<T>(
+        iter: impl IntoIterator<Item = T>,
+        mut random_index: impl FnMut(usize) -> usize,
+    ) -> Option<T> {
+        let mut chosen = None;
+        // every element replaces the chosen one with a chance of 1 divided by how many elements
+        // we've seen, which gives all of them the same chance in the end
+        for (i, item) in iter.into_iter().enumerate() {
+            if random_index(i + 1) == 0 {
+                chosen = Some(item);
+            }
+        }
+        chosen
+    }
+
+    ///
+    /// Returns a random key of the map, where keys with a higher weight are picked more often
+    ///
+    /// A key with the weight `2.0` is picked twice as often as a key with the weight `1.0`, and
+    /// keys with the weight `0.0` are never picked.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::weighted_choice_map;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut next_words = HashMap::new();
+    /// next_words.insert("cat", 3.0);
+    /// next_words.insert("dog", 1.0);
+    ///
+    /// // "cat" is picked 75% of the time
+    /// let word = weighted_choice_map(&next_words);
+    ///
+    /// println!("The next word is: {}", word);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the map is empty, if a weight is negative or not a number, or if all weights
+    /// are `0.0`.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn weighted_choice_map<K, S>(weights: &HashMap<K, f64, S>) -> &K {
+        with_global_rng(|rng| rng.weighted_choice_map(weights))
+    }
+
+    ///
+    /// Returns `amount` different random elements from the slice, in random order.
+    ///
+    /// Every element is picked at most once. If `amount` is bigger than the length of the slice,
+    /// all elements are returned.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::sample;
+    ///
+    /// let numbers = (1..=49).collect::<Vec<_>>();
+    /// let lottery = sample(&numbers, 6);
+    ///
+    /// println!("The winning numbers are: {:?}", lottery);
+    ///
+    /// assert_eq!(lottery.len(), 6);
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn sample<T: Clone>(items: &[T], amount: usize) -> Vec<T> {
+        with_global_rng(|rng| rng.sample(items, amount))
+    }
+
+    ///
+    /// Returns a normally distributed random number (the "bell curve") with the given mean and
+    /// standard deviation
+    ///
+    /// About 68% of the numbers are within one standard deviation of the mean, and about 95%
+    /// within two.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::random_gaussian;
+    ///
+    /// // the height of a random person in cm
+    /// let height = random_gaussian(170.0, 10.0);
+    ///
+    /// println!("The person is {:.1}cm tall", height);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `std_dev` is negative or not a number.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn random_gaussian(mean: f64, std_dev: f64) -> f64 {
+        with_global_rng(|rng| rng.next_gaussian(mean, std_dev))
+    }
+
+    ///
+    /// The characters that [`random_string`] can choose from
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::{random_string, Charset};
+    ///
+    /// let password = random_string(12, Charset::default());
+    /// let color = random_string(6, Charset::Hex);
+    /// let dna = random_string(20, Charset::Custom("ACGT"));
+    ///
+    /// println!("password: {}, color: #{}, dna: {}", password, color, dna);
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum Charset {
+        /// `a-z`, `A-Z` and `0-9`
+        #[default]
+        Alphanumeric,
+        /// `a-z` and `A-Z`
+        Letters,
+        /// `0-9`
+        Digits,
+        /// `0-9` and `a-f`
+        Hex,
+        /// All characters of the string
+        Custom(&'static str),
+    }
+
+    impl Charset {
+        fn chars(self) -> &'static str {
+            match self {
+                Charset::Alphanumeric => {
+                    "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789"
+                }
+                Charset::Letters => "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ",
+                Charset::Digits => "0123456789",
+                Charset::Hex => "0123456789abcdef",
+                Charset::Custom(chars) => chars,
+            }
+        }
+    }
+
+    ///
+    /// Returns a string of `len` random characters from the charset
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::{random_string, Charset};
+    ///
+    /// let code = random_string(8, Charset::Alphanumeric);
+    ///
+    /// println!("Your code is: {}", code);
+    ///
+    /// assert_eq!(code.len(), 8);
+    /// assert!(code.chars().all(|c| c.is_ascii_alphanumeric()));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the charset is `Charset::Custom("")`, since there is nothing to choose from.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn random_string(len: usize, charset: Charset) -> String {
+        with_global_rng(|rng| rng.next_string(len, charset))
+    }
+
+    ///
+    /// Returns `len` random bytes
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::random_bytes;
+    ///
+    /// let fake_file = random_bytes(1024);
+    /// std::fs::write("test_data.bin", &fake_file).unwrap();
+    ///
+    /// assert_eq!(fake_file.len(), 1024);
+    /// # std::fs::remove_file("test_data.bin").unwrap();
+    /// ```
+    ///
+    /// With the `secure` feature, the bytes come from the operating system instead, which makes
+    /// them safe to use for passwords and keys. They are then not affected by
+    /// [`set_random_seed`] or `SIMPLE_STD_SEED`.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn random_bytes(len: usize) -> Vec<u8> {
+        let mut bytes = vec![0; len];
+        fill_random(&mut bytes);
+        bytes
+    }
+
+    ///
+    /// Fills the whole buffer with random bytes
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::fill_random;
+    ///
+    /// let mut buffer = [0u8; 16];
+    /// fill_random(&mut buffer);
+    ///
+    /// println!("Random data: {:?}", buffer);
+    /// ```
+    ///
+    /// With the `secure` feature, the bytes come from the operating system instead, which makes
+    /// them safe to use for passwords and keys. They are then not affected by
+    /// [`set_random_seed`] or `SIMPLE_STD_SEED`.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn fill_random(buffer: &mut [u8]) {
+        #[cfg(feature = "secure")]
+        os_random::fill(buffer);
+        #[cfg(not(feature = "secure"))]
+        with_global_rng(|rng| rng.fill_bytes(buffer));
+    }
+
+    ///
+    /// Returns a random character from the range
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::random_char;
+    ///
+    /// let grade = random_char('A'..='F');
+    ///
+    /// println!("You got an {}", grade);
+    ///
+    /// assert!(('A'..='F').contains(&grade));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty, like `'z'..='a'`.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn random_char(range: RangeInclusive<char>) -> char {
+        with_global_rng(|rng| rng.next_char(range))
+    }
+
+    ///
+    /// Returns a random lowercase letter from `a` to `z`
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::random_letter;
+    ///
+    /// let letter = random_letter();
+    ///
+    /// println!("Guess the letter! (it was {})", letter);
+    ///
+    /// assert!(letter.is_ascii_lowercase());
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn random_letter() -> char {
+        random_char('a'..='z')
+    }
+
+    ///
+    /// Returns a random character from the charset
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::{random_char_from, Charset};
+    ///
+    /// let digit = random_char_from(Charset::Digits);
+    /// let symbol = random_char_from(Charset::Custom("!?#$%&"));
+    ///
+    /// println!("Your password needs a {} and a {}", digit, symbol);
+    ///
+    /// assert!(digit.is_ascii_digit());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the charset is `Charset::Custom("")`, since there is nothing to choose from.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn random_char_from(charset: Charset) -> char {
+        with_global_rng(|rng| rng.next_char_from(charset))
+    }
+
+    ///
+    /// Returns a random UUID (version 4) formatted like `"0b7e1b4c-7a4d-4c57-9b6e-3f1b9d2a8c10"`
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::random_uuid;
+    ///
+    /// let id = random_uuid();
+    ///
+    /// println!("Created user with id {}", id);
+    ///
+    /// assert_eq!(id.len(), 36);
+    /// ```
+    ///
+    /// With the `secure` feature, the UUID is created from the random bytes of the operating
+    /// system, see [`random_bytes`].
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn random_uuid() -> String {
+        format_uuid(random_uuid_bytes())
+    }
+
+    ///
+    /// Returns the 16 bytes of a random UUID (version 4), see [`random_uuid`]
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::random_uuid_bytes;
+    ///
+    /// let id = random_uuid_bytes();
+    ///
+    /// // the version is always 4
+    /// assert_eq!(id[6] >> 4, 4);
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn random_uuid_bytes() -> [u8; 16] {
+        let mut bytes = [0; 16];
+        fill_random(&mut bytes);
+        make_uuid(bytes)
+    }
+
+    /// sets the version and variant bits of the random bytes
+    fn make_uuid(mut bytes: [u8; 16]) -> [u8; 16] {
+        // version 4 (random) and the RFC 4122 variant, as described in the RFC
+        bytes[6] = (bytes[6] & 0x0f) | 0x40;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+        bytes
+    }
+
+    fn format_uuid(bytes: [u8; 16]) -> String {
+        let hex = bytes
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>();
+
+        format!(
+            "{}-{}-{}-{}-{}",
+            &hex[0..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..32]
+        )
+    }
+
+    ///
+    /// One side of a coin, see [`coin_flip`]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Coin {
+        Heads,
+        Tails,
+    }
+
+    impl std::fmt::Display for Coin {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Coin::Heads => f.write_str("Heads"),
+                Coin::Tails => f.write_str("Tails"),
+            }
+        }
+    }
+
+    ///
+    /// Flips a coin, returning [`Coin::Heads`] or [`Coin::Tails`] with the same chance
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::{coin_flip, Coin};
+    ///
+    /// let coin = coin_flip();
+    ///
+    /// println!("The coin shows {}", coin);
+    ///
+    /// match coin {
+    ///     Coin::Heads => println!("You go first!"),
+    ///     Coin::Tails => println!("Your opponent goes first!"),
+    /// }
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn coin_flip() -> Coin {
+        with_global_rng(SimpleRng::next_coin)
+    }
+
+    ///
+    /// Seeds the global generator, so that all following random numbers are the same every time
+    /// the program runs
+    ///
+    /// This only affects the current thread, every thread has its own generator.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::{random_int_range, set_random_seed};
+    ///
+    /// set_random_seed(42);
+    /// let first = random_int_range(0..100);
+    ///
+    /// set_random_seed(42);
+    /// let second = random_int_range(0..100);
+    ///
+    /// assert_eq!(first, second);
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn set_random_seed(seed: u64) {
+        set_random_state(SimpleRng::from_seed(seed));
+    }
+
+    ///
+    /// Returns a copy of the current state of the global generator, which can be restored later
+    /// with [`set_random_state`] to get the same random numbers again
+    ///
+    /// This only affects the current thread, every thread has its own generator.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::{random_float, random_state, set_random_state};
+    ///
+    /// let state = random_state();
+    /// let numbers = [random_float(), random_float()];
+    ///
+    /// // replay the same numbers
+    /// set_random_state(state);
+    ///
+    /// assert_eq!(numbers, [random_float(), random_float()]);
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn random_state() -> SimpleRng {
+        with_global_rng(|rng| rng.clone())
+    }
+
+    ///
+    /// Replaces the state of the global generator, see [`random_state`]
+    ///
+    /// This only affects the current thread, every thread has its own generator.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::{random_float, set_random_state, SimpleRng};
+    ///
+    /// set_random_state(SimpleRng::from_seed(5));
+    ///
+    /// assert_eq!(random_float(), SimpleRng::from_seed(5).next_float());
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn set_random_state(state: SimpleRng) {
+        with_global_rng(|rng| *rng = state);
+    }
+
+    ///
+    /// A type that can be created randomly, like `Direction::random()`
+    ///
+    /// It is implemented for `bool`, the integer types (any value is possible), `f32` and `f64`
+    /// (from 0 to 1, like [`random_float`]) and [`Coin`]. With the `derive` feature, it can be
+    /// derived for enums and structs using `#[derive(Random)]`.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::{Random, SimpleRng};
+    ///
+    /// #[derive(Debug)]
+    /// enum Direction {
+    ///     Up,
+    ///     Down,
+    /// }
+    ///
+    /// impl Random for Direction {
+    ///     fn random_with(rng: &mut SimpleRng) -> Self {
+    ///         if rng.next_bool(0.5) {
+    ///             Direction::Up
+    ///         } else {
+    ///             Direction::Down
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// println!("The enemy walks {:?}", Direction::random());
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub trait Random: Sized {
+        /// Creates a random value using the generator. Implementations should only use `rng`
+        /// and not the global random functions.
+        fn random_with(rng: &mut SimpleRng) -> Self;
+
+        /// Creates a random value using the global generator
+        fn random() -> Self {
+            with_global_rng_copy(Self::random_with)
+        }
+    }
+
+    macro_rules! impl_random_int {
+        ($($ty:ty),*) => {
+            $(
+                impl Random for $ty {
+                    fn random_with(rng: &mut SimpleRng) -> Self {
+                        rng.next_u64() as $ty
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_random_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+    impl Random for bool {
+        fn random_with(rng: &mut SimpleRng) -> Self {
+            rng.next_bool(0.5)
+        }
+    }
+
+    impl Random for f64 {
+        fn random_with(rng: &mut SimpleRng) -> Self {
+            rng.next_float()
+        }
+    }
+
+    impl Random for f32 {
+        fn random_with(rng: &mut SimpleRng) -> Self {
+            // use only as many bits as fit into an f32, so it can't be rounded up to 1
+            ((rng.next_u64() >> 40) as f32) / ((1u32 << 24) as f32)
+        }
+    }
+
+    impl Random for Coin {
+        fn random_with(rng: &mut SimpleRng) -> Self {
+            rng.next_coin()
+        }
+    }
+
+    ///
+    /// Returns a random duration contained in the range
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::random_duration;
+    /// use std::time::Duration;
+    ///
+    /// let wait = random_duration(Duration::from_secs(1)..Duration::from_secs(5));
+    ///
+    /// println!("The bus arrives in {:?}", wait);
+    ///
+    /// assert!(wait < Duration::from_secs(5));
+    /// assert!(wait >= Duration::from_secs(1));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty, or longer than about 584 years.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn random_duration(range: Range<Duration>) -> Duration {
+        with_global_rng(|rng| rng.next_duration(range))
+    }
+
+    ///
+    /// Makes a duration randomly longer or shorter by up to `percent` percent
+    ///
+    /// This is useful for retrying things without everyone retrying at exactly the same time, or
+    /// to make the timing in games feel less robotic. The result is never negative, even with
+    /// more than 100 percent.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::jitter;
+    /// use std::time::Duration;
+    ///
+    /// // somewhere between 800ms and 1200ms
+    /// let delay = jitter(Duration::from_secs(1), 20.0);
+    ///
+    /// println!("Retrying in {:?}", delay);
+    ///
+    /// assert!(delay >= Duration::from_millis(800));
+    /// assert!(delay <= Duration::from_millis(1200));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `percent` is negative, infinite or not a number.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn jitter(duration: Duration, percent: f64) -> Duration {
+        with_global_rng(|rng| rng.jitter(duration, percent))
+    }
+
+    ///
+    /// Returns a random date contained in the range, where every day has the same chance
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::{random_date, Date};
+    ///
+    /// let appointment = random_date(Date::new(2024, 1, 1)..=Date::new(2024, 12, 31));
+    ///
+    /// println!("Your appointment is on {}", appointment);
+    ///
+    /// assert_eq!(appointment.year(), 2024);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub fn random_date(range: RangeInclusive<Date>) -> Date {
+        with_global_rng(|rng| rng.next_date(range))
+    }
+
+    ///
+    /// Adds the random functions for slices as methods, so that `cards.shuffle()` works
+    /// instead of `shuffle(&mut cards)`
+    ///
+    /// It is also exported from the [`prelude`](crate::prelude).
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::SliceRandomExt;
+    ///
+    /// let mut cards = vec!["Ace", "King", "Queen", "Jack"];
+    /// cards.shuffle();
+    ///
+    /// let card = cards.choose().unwrap();
+    /// let hand = cards.sample(2);
+    ///
+    /// println!("You drew {} and have {:?} in your hand", card, hand);
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    pub trait SliceRandomExt {
+        /// The type of the elements
+        type Item;
+
+        /// Shuffles the elements into a random order, see [`shuffle`]
+        fn shuffle(&mut self);
+
+        /// Returns a random element, see [`choose`]
+        fn choose(&self) -> Option<&Self::Item>;
+
+        /// Returns a mutable reference to a random element, see [`choose_mut`]
+        fn choose_mut(&mut self) -> Option<&mut Self::Item>;
+
+        /// Returns `amount` different random elements, see [`sample`]
+        fn sample(&self, amount: usize) -> Vec<Self::Item>
+        where
+            Self::Item: Clone;
+    }
+
+    impl<T> SliceRandomExt for [T] {
+        type Item = T;
+
+        fn shuffle(&mut self) {
+            shuffle(self)
+        }
+
+        fn choose(&self) -> Option<&T> {
+            choose(self)
+        }
+
+        fn choose_mut(&mut self) -> Option<&mut T> {
+            choose_mut(self)
+        }
+
+        fn sample(&self, amount: usize) -> Vec<T>
+        where
+            T: Clone,
+        {
+            sample(self, amount)
+        }
+    }
+
+    ///
+    /// A random number generator with its own state, independent of the global one used by
+    /// [`random_float`] and [`random_int_range`].
+    ///
+    /// Two generators created with the same seed always return the same numbers, which is useful
+    /// for simulations and tests that need to be reproducible.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::SimpleRng;
+    ///
+    /// let mut rng = SimpleRng::from_seed(42);
+    /// let mut same_rng = SimpleRng::from_seed(42);
+    ///
+    /// let number = rng.next_int_range(0..100);
+    ///
+    /// assert_eq!(number, same_rng.next_int_range(0..100));
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`random_float`]
+    #[derive(Debug, Clone)]
+    pub struct SimpleRng {
+        state0: u64,
+        state1: u64,
+    }
+
+    impl SimpleRng {
+        ///
+        /// Creates a new generator from a seed. The same seed always results in the same numbers.
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::SimpleRng;
+        ///
+        /// let mut rng = SimpleRng::from_seed(1234);
+        /// println!("Random number: {}", rng.next_float());
+        /// ```
+        pub fn from_seed(seed: u64) -> Self {
+            // spread the seed over the whole state with splitmix64, so that similar seeds
+            // still lead to completely different numbers and the state is never all zeros
+            let mut seed = seed;
+            let mut next = || {
+                seed = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+                let mut z = seed;
+                z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+                z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+                z ^ (z >> 31)
+            };
+
+            Self {
+                state0: next(),
+                state1: next(),
+            }
+        }
+
+        ///
+        /// Returns a random number from 0 to 1, see [`random_float`]
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::SimpleRng;
+        ///
+        /// let mut rng = SimpleRng::from_seed(0);
+        /// let number = rng.next_float();
+        ///
+        /// assert!(number < 1.0);
+        /// assert!(number >= 0.0);
+        /// ```
+        pub fn next_float(&mut self) -> f64 {
+            ((self.next_u64() >> 11) as f64) / ((1u64 << 53) as f64)
+        }
+
+        ///
+        /// Returns an integer number contained in the range, see [`random_int_range`]
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::SimpleRng;
+        ///
+        /// let mut rng = SimpleRng::from_seed(0);
+        /// let number = rng.next_int_range(0..100);
+        ///
+        /// assert!(number < 100);
+        /// assert!(number >= 0);
+        ///
+        /// let dice = rng.next_int_range(1..=6);
+        ///
+        /// assert!(dice <= 6);
+        /// assert!(dice >= 1);
+        /// ```
+        pub fn next_int_range(&mut self, range: impl IntoIntRange) -> i32 {
+            let range = range.into_int_range();
+            let (start, end) = (i64::from(*range.start()), i64::from(*range.end()));
+
+            // calculating in 64 bit means that even `i32::MIN..=i32::MAX` can't overflow
+            (start + self.next_up_to((end - start) as u64) as i64) as i32
+        }
+
+        ///
+        /// Returns an `i64` contained in the range, see [`random_int_range_i64`]
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::SimpleRng;
+        ///
+        /// let mut rng = SimpleRng::from_seed(0);
+        /// let number = rng.next_int_range_i64(-5_000_000_000..5_000_000_000);
+        ///
+        /// assert!(number < 5_000_000_000);
+        /// assert!(number >= -5_000_000_000);
+        /// ```
+        pub fn next_int_range_i64(&mut self, range: impl IntoIntRange<i64>) -> i64 {
+            let range = range.into_int_range();
+            let (start, end) = (i128::from(*range.start()), i128::from(*range.end()));
+
+            (start + i128::from(self.next_up_to((end - start) as u64))) as i64
+        }
+
+        ///
+        /// Returns a `u64` contained in the range, see [`random_int_range_u64`]
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::SimpleRng;
+        ///
+        /// let mut rng = SimpleRng::from_seed(0);
+        /// let number = rng.next_int_range_u64(10..=20);
+        ///
+        /// assert!(number <= 20);
+        /// assert!(number >= 10);
+        /// ```
+        pub fn next_int_range_u64(&mut self, range: impl IntoIntRange<u64>) -> u64 {
+            let range = range.into_int_range();
+            range.start() + self.next_up_to(range.end() - range.start())
+        }
+
+        ///
+        /// Returns a floating point number contained in the range, see [`random_float_range`]
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::SimpleRng;
+        ///
+        /// let mut rng = SimpleRng::from_seed(0);
+        /// let temperature = rng.next_float_range(-5.0..30.0);
+        ///
+        /// assert!(temperature < 30.0);
+        /// assert!(temperature >= -5.0);
+        /// ```
+        pub fn next_float_range(&mut self, range: Range<f64>) -> f64 {
+            assert!(
+                range.start.is_finite() && range.end.is_finite(),
+                "the range {:?} must not contain infinite or NaN bounds",
+                range
+            );
+            assert!(
+                range.start < range.end,
+                "cannot pick a number from the empty range {:?}",
+                range
+            );
+
+            loop {
+                let t = self.next_float();
+                // `end - start` can overflow to infinity for huge ranges, this can't
+                let number = range.start * (1.0 - t) + range.end * t;
+                // rounding can very rarely result in a number outside, just try again then
+                if range.start <= number && number < range.end {
+                    return number;
+                }
+            }
+        }
+
+        ///
+        /// Returns an endless iterator of random numbers from 0 to 1 from this generator,
+        /// see [`random_floats`]
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::SimpleRng;
+        ///
+        /// let mut rng = SimpleRng::from_seed(0);
+        /// let numbers = rng.floats().take(5).collect::<Vec<_>>();
+        ///
+        /// assert_eq!(numbers, SimpleRng::from_seed(0).floats().take(5).collect::<Vec<_>>());
+        /// ```
+        pub fn floats(&mut self) -> impl Iterator<Item = f64> + '_ {
+            std::iter::repeat_with(move || self.next_float())
+        }
+
+        ///
+        /// Returns an endless iterator of random integers contained in the range from this
+        /// generator, see [`random_ints`]
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::SimpleRng;
+        ///
+        /// let mut rng = SimpleRng::from_seed(0);
+        /// let rolls = rng.ints(1..=6).take(10).collect::<Vec<_>>();
+        ///
+        /// assert!(rolls.iter().all(|&n| n >= 1 && n <= 6));
+        /// ```
+        pub fn ints(&mut self, range: impl IntoIntRange) -> impl Iterator<Item = i32> + '_ {
+            let range = range.into_int_range();
+            std::iter::repeat_with(move || self.next_int_range(range.clone()))
+        }
+
+        ///
+        /// Returns `true` with the given probability, see [`random_bool`]
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::SimpleRng;
+        ///
+        /// let mut rng = SimpleRng::from_seed(0);
+        ///
+        /// assert!(rng.next_bool(1.0));
+        /// assert!(!rng.next_bool(0.0));
+        /// ```
+        pub fn next_bool(&mut self, probability: f64) -> bool {
+            assert!(
+                (0.0..=1.0).contains(&probability),
+                "probability must be between 0 and 1, but was {}",
+                probability
+            );
+            self.next_float() < probability
+        }
+
+        ///
+        /// Shuffles the elements of a slice into a random order, see [`shuffle`]
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::SimpleRng;
+        ///
+        /// let mut rng = SimpleRng::from_seed(0);
+        /// let mut numbers = [1, 2, 3, 4, 5];
+        /// rng.shuffle(&mut numbers);
+        ///
+        /// println!("Numbers: {:?}", numbers);
+        /// ```
+        pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+            // Fisher-Yates: swap every element with a random one from the part that isn't shuffled yet
+            for i in (1..slice.len()).rev() {
+                let j = self.next_index(i + 1);
+                slice.swap(i, j);
+            }
+        }
+
+        ///
+        /// Returns a random element of the slice, see [`choose`]
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::SimpleRng;
+        ///
+        /// let mut rng = SimpleRng::from_seed(0);
+        ///
+        /// assert!(rng.choose(&[1, 2, 3]).is_some());
+        /// assert_eq!(rng.choose::<i32>(&[]), None);
+        /// ```
+        pub fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+            if items.is_empty() {
+                None
+            } else {
+                Some(&items[self.next_index(items.len())])
+            }
+        }
+
+        ///
+        /// Returns a mutable reference to a random element of the slice, see [`choose_mut`]
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::SimpleRng;
+        ///
+        /// let mut rng = SimpleRng::from_seed(0);
+        /// let mut numbers = [0, 0, 0];
+        ///
+        /// *rng.choose_mut(&mut numbers).unwrap() = 1;
+        ///
+        /// assert_eq!(numbers.iter().sum::<i32>(), 1);
+        /// ```
+        pub fn choose_mut<'a, T>(&mut self, items: &'a mut [T]) -> Option<&'a mut T> {
+            if items.is_empty() {
+                None
+            } else {
+                let index = self.next_index(items.len());
+                Some(&mut items[index])
+            }
+        }
+
+        ///
+        /// Returns a random element of an iterator, see [`choose_from_iter`]
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::SimpleRng;
+        ///
+        /// let mut rng = SimpleRng::from_seed(0);
+        /// let even = rng.choose_from_iter((0..100).filter(|n| n % 2 == 0)).unwrap();
+        ///
+        /// assert_eq!(even % 2, 0);
+        /// ```
+        pub fn choose_from_iter<T>(&mut self, iter: impl IntoIterator<Item = T>) -> Option<T> {
+            reservoir_sample(iter, |len| self.next_index(len))
+        }
+
+        ///
+        /// Returns a random key of the map, weighted by its value, see [`weighted_choice_map`]
+        ///
+        /// Note that the order of a `HashMap` is different in every run of the program, so the
+        /// result is not reproducible even with a seeded generator.
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::SimpleRng;
+        /// use std::collections::HashMap;
+        ///
+        /// let mut rng = SimpleRng::from_seed(0);
+        /// let mut weights = HashMap::new();
+        /// weights.insert("common", 10.0);
+        /// weights.insert("never", 0.0);
+        ///
+        /// assert_eq!(*rng.weighted_choice_map(&weights), "common");
+        /// ```
+        pub fn weighted_choice_map<'a, K, S>(&mut self, weights: &'a HashMap<K, f64, S>) -> &'a K {
+            assert!(
+                weights.values().all(|&weight| weight >= 0.0),
+                "weights must not be negative or NaN"
+            );
+            let total = weights.values().sum::<f64>();
+            assert!(
+                total > 0.0 && total.is_finite(),
+                "the sum of the weights must be positive and finite, but was {}",
+                total
+            );
+
+            let target = self.next_float() * total;
+            let mut sum = 0.0;
+            let mut last = None;
+            for (key, &weight) in weights {
+                if weight == 0.0 {
+                    continue;
+                }
+                sum += weight;
+                if sum > target {
+                    return key;
+                }
+                last = Some(key);
+            }
+
+            // rounding errors can make `sum` end up slightly smaller than `target`
+            last.expect("there is a positive weight")
+        }
+
+        ///
+        /// Returns `amount` different random elements from the slice, see [`sample`]
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::SimpleRng;
+        ///
+        /// let mut rng = SimpleRng::from_seed(0);
+        ///
+        /// assert_eq!(rng.sample(&[1, 2, 3], 2).len(), 2);
+        /// assert_eq!(rng.sample(&[1, 2, 3], 10).len(), 3);
+        /// ```
+        pub fn sample<T: Clone>(&mut self, items: &[T], amount: usize) -> Vec<T> {
+            let amount = amount.min(items.len());
+
+            // shuffle only the first `amount` indices, the rest can stay where it is
+            let mut indices = (0..items.len()).collect::<Vec<_>>();
+            for i in 0..amount {
+                let j = i + self.next_index(items.len() - i);
+                indices.swap(i, j);
+            }
+
+            indices[..amount]
+                .iter()
+                .map(|&index| items[index].clone())
+                .collect()
+        }
+
+        ///
+        /// Returns a string of `len` random characters from the charset, see [`random_string`]
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::{Charset, SimpleRng};
+        ///
+        /// let mut rng = SimpleRng::from_seed(0);
+        /// let pin = rng.next_string(4, Charset::Digits);
+        ///
+        /// assert!(pin.chars().all(|c| c.is_ascii_digit()));
+        /// ```
+        pub fn next_string(&mut self, len: usize, charset: Charset) -> String {
+            let chars = charset.chars().chars().collect::<Vec<_>>();
+            assert!(!chars.is_empty(), "the charset must not be empty");
+
+            (0..len)
+                .map(|_| chars[self.next_index(chars.len())])
+                .collect()
+        }
+
+        ///
+        /// Returns a normally distributed random number, see [`random_gaussian`]
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::SimpleRng;
+        ///
+        /// let mut rng = SimpleRng::from_seed(0);
+        /// let iq = rng.next_gaussian(100.0, 15.0);
+        ///
+        /// println!("IQ: {:.0}", iq);
+        /// ```
+        pub fn next_gaussian(&mut self, mean: f64, std_dev: f64) -> f64 {
+            assert!(
+                std_dev >= 0.0,
+                "standard deviation must not be negative, but was {}",
+                std_dev
+            );
+
+            // Box-Muller transform, `1.0 - next_float()` is never 0, so the `ln` can't be infinite
+            let u1 = 1.0 - self.next_float();
+            let u2 = self.next_float();
+            let standard = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+
+            mean + standard * std_dev
+        }
+
+        ///
+        /// Returns `len` random bytes, see [`random_bytes`]
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::SimpleRng;
+        ///
+        /// let mut rng = SimpleRng::from_seed(0);
+        ///
+        /// assert_eq!(rng.next_bytes(100).len(), 100);
+        /// ```
+        pub fn next_bytes(&mut self, len: usize) -> Vec<u8> {
+            let mut bytes = vec![0; len];
+            self.fill_bytes(&mut bytes);
+            bytes
+        }
+
+        ///
+        /// Fills the whole buffer with random bytes, see [`fill_random`]
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::SimpleRng;
+        ///
+        /// let mut rng = SimpleRng::from_seed(0);
+        /// let mut buffer = [0u8; 32];
+        /// rng.fill_bytes(&mut buffer);
+        /// ```
+        pub fn fill_bytes(&mut self, buffer: &mut [u8]) {
+            for chunk in buffer.chunks_mut(8) {
+                let random = self.next_u64().to_le_bytes();
+                chunk.copy_from_slice(&random[..chunk.len()]);
+            }
+        }
+
+        ///
+        /// Returns a random character from the range, see [`random_char`]
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::SimpleRng;
+        ///
+        /// let mut rng = SimpleRng::from_seed(0);
+        ///
+        /// assert!(rng.next_char('a'..='z').is_ascii_lowercase());
+        /// ```
+        pub fn next_char(&mut self, range: RangeInclusive<char>) -> char {
+            const SURROGATES: RangeInclusive<u32> = 0xD800..=0xDFFF;
+            const SURROGATE_COUNT: u32 = 0x800;
+
+            let (start, end) = (u32::from(*range.start()), u32::from(*range.end()));
+            assert!(
+                start <= end,
+                "cannot pick a character from the empty range {:?}",
+                range
+            );
+
+            // the surrogates in the middle are not valid chars, so they are skipped
+            let skipped = if start < *SURROGATES.start() && end > *SURROGATES.end() {
+                SURROGATE_COUNT
+            } else {
+                0
+            };
+
+            let mut number = start + self.next_up_to(u64::from(end - start - skipped)) as u32;
+            if number >= *SURROGATES.start() && skipped > 0 {
+                number += skipped;
+            }
+
+            std::char::from_u32(number).expect("skipped the surrogates")
+        }
+
+        ///
+        /// Returns a random character from the charset, see [`random_char_from`]
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::{Charset, SimpleRng};
+        ///
+        /// let mut rng = SimpleRng::from_seed(0);
+        ///
+        /// assert!(rng.next_char_from(Charset::Hex).is_ascii_hexdigit());
+        /// ```
+        pub fn next_char_from(&mut self, charset: Charset) -> char {
+            let chars = charset.chars();
+            assert!(!chars.is_empty(), "the charset must not be empty");
+
+            let index = self.next_index(chars.chars().count());
+            chars.chars().nth(index).expect("index is in bounds")
+        }
+
+        ///
+        /// Returns a random UUID (version 4), see [`random_uuid`]
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::SimpleRng;
+        ///
+        /// let mut rng = SimpleRng::from_seed(0);
+        ///
+        /// assert_eq!(rng.next_uuid().len(), 36);
+        /// ```
+        pub fn next_uuid(&mut self) -> String {
+            format_uuid(self.next_uuid_bytes())
+        }
+
+        ///
+        /// Returns the 16 bytes of a random UUID (version 4), see [`random_uuid_bytes`]
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::SimpleRng;
+        ///
+        /// let mut rng = SimpleRng::from_seed(0);
+        ///
+        /// assert_eq!(rng.next_uuid_bytes().len(), 16);
+        /// ```
+        pub fn next_uuid_bytes(&mut self) -> [u8; 16] {
+            let mut bytes = [0; 16];
+            self.fill_bytes(&mut bytes);
+            make_uuid(bytes)
+        }
+
+        ///
+        /// Flips a coin, see [`coin_flip`]
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::SimpleRng;
+        ///
+        /// let mut rng = SimpleRng::from_seed(0);
+        ///
+        /// println!("The coin shows {}", rng.next_coin());
+        /// ```
+        pub fn next_coin(&mut self) -> Coin {
+            if self.next_bool(0.5) {
+                Coin::Heads
+            } else {
+                Coin::Tails
+            }
+        }
+
+        ///
+        /// Returns a random duration contained in the range, see [`random_duration`]
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::SimpleRng;
+        /// use std::time::Duration;
+        ///
+        /// let mut rng = SimpleRng::from_seed(0);
+        /// let delay = rng.next_duration(Duration::from_millis(100)..Duration::from_millis(200));
+        ///
+        /// assert!(delay >= Duration::from_millis(100));
+        /// ```
+        pub fn next_duration(&mut self, range: Range<Duration>) -> Duration {
+            assert!(
+                range.start < range.end,
+                "cannot pick a duration from the empty range {:?}",
+                range
+            );
+            let nanos = u64::try_from((range.end - range.start).as_nanos())
+                .unwrap_or_else(|_| panic!("the range {:?} is too long", range));
+
+            range.start + Duration::from_nanos(self.next_below(nanos))
+        }
+
+        ///
+        /// Makes a duration randomly longer or shorter by up to `percent` percent, see [`jitter`]
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::SimpleRng;
+        /// use std::time::Duration;
+        ///
+        /// let mut rng = SimpleRng::from_seed(0);
+        /// let delay = rng.jitter(Duration::from_secs(10), 50.0);
+        ///
+        /// assert!(delay >= Duration::from_secs(5));
+        /// assert!(delay <= Duration::from_secs(15));
+        /// ```
+        pub fn jitter(&mut self, duration: Duration, percent: f64) -> Duration {
+            assert!(
+                percent >= 0.0 && percent.is_finite(),
+                "percent must not be negative, but was {}",
+                percent
+            );
+
+            // a factor from `1 - percent` to `1 + percent`
+            let offset = (self.next_float() * 2.0 - 1.0) * percent / 100.0;
+            let factor = (1.0 + offset).max(0.0);
+            // `mul_f64` would panic if the result is too big for a `Duration`
+            Duration::try_from_secs_f64(duration.as_secs_f64() * factor).unwrap_or(Duration::MAX)
+        }
+
+        ///
+        /// Returns a random date contained in the range, see [`random_date`]
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::{Date, SimpleRng};
+        ///
+        /// let mut rng = SimpleRng::from_seed(0);
+        /// let date = rng.next_date(Date::new(2000, 1, 1)..=Date::new(2000, 1, 31));
+        ///
+        /// assert_eq!(date.month(), 1);
+        /// ```
+        pub fn next_date(&mut self, range: RangeInclusive<Date>) -> Date {
+            assert!(
+                range.start() <= range.end(),
+                "cannot pick a date from the empty range {:?}",
+                range
+            );
+            let days = self.next_int_range_i64(
+                range.start().days_since_epoch()..=range.end().days_since_epoch(),
+            );
+            Date::from_days_since_epoch(days)
+        }
+
+        /// returns a random index in `0..len`
+        fn next_index(&mut self, len: usize) -> usize {
+            self.next_below(len as u64) as usize
+        }
+
+        /// returns a random number in `0..=max`
+        fn next_up_to(&mut self, max: u64) -> u64 {
+            match max.checked_add(1) {
+                Some(bound) => self.next_below(bound),
+                // every u64 is allowed
+                None => self.next_u64(),
+            }
+        }
+
+        /// returns a random number in `0..bound`, without being biased towards small numbers
+        /// like `next_u64() % bound` would be
+        fn next_below(&mut self, bound: u64) -> u64 {
+            // Lemire's method https://arxiv.org/abs/1805.10941
+            // multiplying maps the number to `0..bound` in the upper 64 bits, and the few
+            // numbers that would appear too often are rejected by looking at the lower 64 bits
+            let mut product = u128::from(self.next_u64()) * u128::from(bound);
+            if (product as u64) < bound {
+                let threshold = bound.wrapping_neg() % bound;
+                while (product as u64) < threshold {
+                    product = u128::from(self.next_u64()) * u128::from(bound);
+                }
+            }
+            (product >> 64) as u64
+        }
+
+        /// generates a pseudo-random u64
+        fn next_u64(&mut self) -> u64 {
+            // use xorshift128+ because it's easy https://v8.dev/blog/math-random
+
+            // not a bug
+            let mut s1 = self.state0;
+            let s0 = self.state1;
+
+            self.state0 = s0;
+
+            s1 ^= s1 << 23;
+            s1 ^= s1 >> 17;
+            s1 ^= s0;
+            s1 ^= s0 >> 26;
+
+            self.state1 = s1;
+
+            s0.wrapping_add(s1)
+        }
+    }
+
+    /// random bytes from the operating system, which are safe to use for cryptography
+    #[cfg(feature = "secure")]
+    mod os_random {
+        #[cfg(any(target_os = "macos", target_os = "ios", target_os = "openbsd"))]
+        pub(super) fn fill(buffer: &mut [u8]) {
+            extern "C" {
+                fn getentropy(buf: *mut u8, buflen: usize) -> i32;
+            }
+
+            // getentropy can only fill 256 bytes at once
+            for chunk in buffer.chunks_mut(256) {
+                // SAFETY: the pointer and length describe the valid, writable chunk
+                let result = unsafe { getentropy(chunk.as_mut_ptr(), chunk.len()) };
+                if result != 0 {
+                    panic!(
+                        "failed to get random bytes from the operating system: {}",
+                        std::io::Error::last_os_error()
+                    );
+                }
+            }
+        }
+
+        #[cfg(all(
+            unix,
+            not(any(target_os = "macos", target_os = "ios", target_os = "openbsd"))
+        ))]
+        pub(super) fn fill(buffer: &mut [u8]) {
+            use std::io::Read;
+
+            std::fs::File::open("/dev/urandom")
+                .and_then(|mut file| file.read_exact(buffer))
+                .unwrap_or_else(|err| {
+                    panic!(
+                        "failed to get random bytes from the operating system: {}",
+                        err
+                    )
+                });
+        }
+
+        #[cfg(windows)]
+        pub(super) fn fill(buffer: &mut [u8]) {
+            use std::ffi::c_void;
+
+            const BCRYPT_USE_SYSTEM_PREFERRED_RNG: u32 = 0x0000_0002;
+
+            #[link(name = "bcrypt")]
+            extern "system" {
+                fn BCryptGenRandom(
+                    algorithm: *mut c_void,
+                    buffer: *mut u8,
+                    len: u32,
+                    flags: u32,
+                ) -> i32;
+            }
+
+            for chunk in buffer.chunks_mut(u32::MAX as usize) {
+                // SAFETY: the pointer and length describe the valid, writable chunk, and no
+                // algorithm handle is needed with BCRYPT_USE_SYSTEM_PREFERRED_RNG
+                let status = unsafe {
+                    BCryptGenRandom(
+                        std::ptr::null_mut(),
+                        chunk.as_mut_ptr(),
+                        chunk.len() as u32,
+                        BCRYPT_USE_SYSTEM_PREFERRED_RNG,
+                    )
+                };
+                if status < 0 {
+                    panic!(
+                        "failed to get random bytes from the operating system: NTSTATUS {:#x}",
+                        status
+                    );
+                }
+            }
+        }
+
+        #[cfg(not(any(unix, windows)))]
+        compile_error!("the `secure` feature is not supported on this platform");
+    }
+
+    /// like [`with_global_rng`], but for code from users of the crate, which might use the global
+    /// generator itself
+    pub(crate) fn with_global_rng_copy<T>(f: impl FnOnce(&mut SimpleRng) -> T) -> T {
+        let mut rng = random_state();
+        let value = f(&mut rng);
+        set_random_state(rng);
+        value
+    }
+
+    /// runs `f` with the global generator of the current thread, initializing it first if needed
+    ///
+    /// `f` must not use the global generator itself, see [`with_global_rng_copy`]
+    pub(crate) fn with_global_rng<T>(f: impl FnOnce(&mut SimpleRng) -> T) -> T {
+        use std::cell::RefCell;
+
+        thread_local! {
+            // every thread has its own generator, so threads never have to wait for each other
+            static GLOBAL_RNG: RefCell<SimpleRng> = RefCell::new(SimpleRng::from_seed(initial_seed()));
+        }
+
+        GLOBAL_RNG.with(|rng| f(&mut rng.borrow_mut()))
+    }
+
+    /// the variable that can be set to make the global generator reproducible
+    const SEED_VAR: &str = "SIMPLE_STD_SEED";
+
+    /// a more or less random seed, which is different for every thread, or the seed from
+    /// `SIMPLE_STD_SEED` if it is set
+    fn initial_seed() -> u64 {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+        use std::sync::atomic::{AtomicU64, Ordering};
+
+        // counts the threads that have used the global generator, so that every thread gets
+        // a different, but still reproducible, sequence
+        static THREADS: AtomicU64 = AtomicU64::new(0);
+
+        match std::env::var(SEED_VAR) {
+            Ok(value) => {
+                let thread = THREADS.fetch_add(1, Ordering::Relaxed);
+                parse_seed(&value).wrapping_add(thread)
+            }
+            Err(std::env::VarError::NotPresent) => {
+                let mut hasher = DefaultHasher::new();
+                std::thread::current().id().hash(&mut hasher);
+                system_time_random().hash(&mut hasher);
+                hasher.finish()
+            }
+            Err(std::env::VarError::NotUnicode(value)) => {
+                panic!("{} must be a number, but was {:?}", SEED_VAR, value)
+            }
+        }
+    }
+
+    fn parse_seed(value: &str) -> u64 {
+        value
+            .trim()
+            .parse()
+            .unwrap_or_else(|_| panic!("{} must be a number, but was {:?}", SEED_VAR, value))
+    }
+
+    fn system_time_random() -> u128 {
+        use std::time::SystemTime;
+
+        SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_micros()
+            ^ SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+    }
+
+    ///
+    /// Types that describe how likely different random numbers are, so that it's possible to
+    /// talk about distributions without having to use the full `rand` crate.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::distributions::{Bernoulli, Distribution, Exponential, Uniform};
+    ///
+    /// let dice = Uniform::new(1.0, 7.0);
+    /// let rain = Bernoulli::new(0.3);
+    /// let waiting_time = Exponential::new(0.5);
+    ///
+    /// println!("{:.2} {} {:.2}", dice.sample(), rain.sample(), waiting_time.sample());
+    /// ```
+    pub mod distributions {
+        use super::{with_global_rng_copy, SimpleRng};
+
+        ///
+        /// Something that random values can be sampled from
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::distributions::Distribution;
+        /// use simple_std::SimpleRng;
+        ///
+        /// /// the sum of two dice
+        /// struct TwoDice;
+        ///
+        /// impl Distribution for TwoDice {
+        ///     type Output = i32;
+        ///
+        ///     fn sample_with(&self, rng: &mut SimpleRng) -> i32 {
+        ///         rng.next_int_range(1..=6) + rng.next_int_range(1..=6)
+        ///     }
+        /// }
+        ///
+        /// println!("You rolled {}", TwoDice.sample());
+        /// ```
+        ///
+        /// # Why is this not in std?
+        ///
+        /// See [`random_float`](crate::random_float)
+        pub trait Distribution {
+            /// The type of the values
+            type Output;
+
+            /// Returns a random value using the generator. Implementations should only use `rng`
+            /// and not the global random functions.
+            fn sample_with(&self, rng: &mut SimpleRng) -> Self::Output;
+
+            /// Returns a random value using the global generator
+            fn sample(&self) -> Self::Output {
+                with_global_rng_copy(|rng| self.sample_with(rng))
+            }
+        }
+
+        ///
+        /// Every number between `low` and `high` has the same chance, like
+        /// [`random_float_range`](crate::random_float_range)
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::distributions::{Distribution, Uniform};
+        ///
+        /// let uniform = Uniform::new(-1.0, 1.0);
+        /// let number = uniform.sample();
+        ///
+        /// assert!(number >= -1.0 && number < 1.0);
+        /// ```
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct Uniform {
+            low: f64,
+            high: f64,
+        }
+
+        impl Uniform {
+            /// Creates a uniform distribution from `low` (inclusive) to `high` (exclusive),
+            /// panicking if `low` isn't smaller than `high` or they are not finite
+            pub fn new(low: f64, high: f64) -> Self {
+                assert!(
+                    low.is_finite() && high.is_finite() && low < high,
+                    "low must be smaller than high, but they were {} and {}",
+                    low,
+                    high
+                );
+                Uniform { low, high }
+            }
+        }
+
+        impl Distribution for Uniform {
+            type Output = f64;
+
+            fn sample_with(&self, rng: &mut SimpleRng) -> f64 {
+                rng.next_float_range(self.low..self.high)
+            }
+        }
+
+        ///
+        /// `true` with the probability `p`, otherwise `false`, like
+        /// [`random_bool`](crate::random_bool)
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::distributions::{Bernoulli, Distribution};
+        ///
+        /// let coin = Bernoulli::new(0.5);
+        ///
+        /// if coin.sample() {
+        ///     println!("Heads!");
+        /// }
+        /// ```
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct Bernoulli {
+            p: f64,
+        }
+
+        impl Bernoulli {
+            /// Creates a bernoulli distribution, panicking if `p` isn't between 0 and 1
+            pub fn new(p: f64) -> Self {
+                assert!(
+                    (0.0..=1.0).contains(&p),
+                    "probability must be between 0 and 1, but was {}",
+                    p
+                );
+                Bernoulli { p }
+            }
+        }
+
+        impl Distribution for Bernoulli {
+            type Output = bool;
+
+            fn sample_with(&self, rng: &mut SimpleRng) -> bool {
+                rng.next_bool(self.p)
+            }
+        }
+
+        ///
+        /// The time between random events that happen `lambda` times per unit of time on average,
+        /// like the time until the next customer arrives
+        ///
+        /// The mean of the values is `1 / lambda`.
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::distributions::{Distribution, Exponential};
+        ///
+        /// // two customers per minute
+        /// let customers = Exponential::new(2.0);
+        ///
+        /// println!("The next customer arrives in {:.2} minutes", customers.sample());
+        /// ```
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct Exponential {
+            lambda: f64,
+        }
+
+        impl Exponential {
+            /// Creates an exponential distribution, panicking if `lambda` isn't positive
+            pub fn new(lambda: f64) -> Self {
+                assert!(
+                    lambda > 0.0 && lambda.is_finite(),
+                    "lambda must be positive, but was {}",
+                    lambda
+                );
+                Exponential { lambda }
+            }
+        }
+
+        impl Distribution for Exponential {
+            type Output = f64;
+
+            fn sample_with(&self, rng: &mut SimpleRng) -> f64 {
+                // inverse transform sampling, `1.0 - next_float()` is never 0
+                -(1.0 - rng.next_float()).ln() / self.lambda
+            }
+        }
+
+        ///
+        /// The normal distribution (the "bell curve"), like
+        /// [`random_gaussian`](crate::random_gaussian)
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::distributions::{Distribution, Normal};
+        ///
+        /// let heights = Normal::new(170.0, 10.0);
+        ///
+        /// println!("The person is {:.1}cm tall", heights.sample());
+        /// ```
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct Normal {
+            mean: f64,
+            std_dev: f64,
+        }
+
+        impl Normal {
+            /// Creates a normal distribution, panicking if `std_dev` is negative
+            pub fn new(mean: f64, std_dev: f64) -> Self {
+                assert!(
+                    std_dev >= 0.0,
+                    "standard deviation must not be negative, but was {}",
+                    std_dev
+                );
+                Normal { mean, std_dev }
+            }
+        }
+
+        impl Distribution for Normal {
+            type Output = f64;
+
+            fn sample_with(&self, rng: &mut SimpleRng) -> f64 {
+                rng.next_gaussian(self.mean, self.std_dev)
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use crate::{
+            choose, choose_from_iter, choose_mut, coin_flip, fifty_fifty, fill_random, jitter,
+            random_bool, random_bytes, random_char, random_char_from, random_date, random_duration,
+            random_float, random_float_range, random_floats, random_gaussian, random_int_range,
+            random_int_range_i64, random_int_range_u64, random_ints, random_letter, random_state,
+            random_string, random_uuid, random_uuid_bytes, sample, set_random_seed,
+            set_random_state, shuffle, weighted_choice_map, Charset, Coin, Date, Random, SimpleRng,
+        };
+        use std::iter::repeat_with;
+        use std::time::Duration;
+
+        #[test]
+        fn not_equal() {
+            repeat_with(random_float)
+                .take(100)
+                .collect::<Vec<_>>()
+                .windows(2)
+                .for_each(|win| assert_ne!(win[0], win[1]));
+        }
+
+        #[test]
+        #[allow(clippy::manual_range_contains)]
+        fn between_0_1() {
+            assert!(repeat_with(random_float)
+                .take(100000)
+                .all(|n| n >= 0.0 && n < 1.0))
+        }
+
+        #[test]
+        fn distributed() {
+            assert!(repeat_with(random_float).take(100000).any(|n| n > 0.999));
+            assert!(repeat_with(random_float).take(100000).any(|n| n < 0.001));
+        }
+
+        #[test]
+        fn range_in_range() {
+            [0..10, 5..15, 1000..1004, (-5)..5, (-10)..(-5)]
+                .iter()
+                .for_each(|range| {
+                    assert!(repeat_with(|| random_int_range(range.clone()))
+                        .take(10000)
+                        .all(|n| n < range.end && n >= range.start));
+                })
+        }
+
+        #[test]
+        fn distributed_range() {
+            [0..10, 5..15, 1000..1004, (-5)..5, (-10)..(-5)]
+                .iter()
+                .for_each(|range| {
+                    range.clone().for_each(|expected| {
+                        assert!(repeat_with(|| random_int_range(range.clone()))
+                            .take(100000)
+                            .any(|n| n == expected));
+                    });
+                })
+        }
+
+        #[test]
+        fn seeded_reproducible() {
+            let mut rng1 = SimpleRng::from_seed(100);
+            let mut rng2 = SimpleRng::from_seed(100);
+            repeat_with(|| (rng1.next_float(), rng2.next_float()))
+                .take(1000)
+                .for_each(|(a, b)| assert_eq!(a, b));
+        }
+
+        #[test]
+        fn seeded_different_seeds() {
+            let mut rng1 = SimpleRng::from_seed(1);
+            let mut rng2 = SimpleRng::from_seed(2);
+            assert!(repeat_with(|| (rng1.next_float(), rng2.next_float()))
+                .take(100)
+                .any(|(a, b)| a != b));
+        }
+
+        #[test]
+        fn seeded_range_in_range() {
+            let mut rng = SimpleRng::from_seed(0);
+            [0..10, 5..15, (-10)..(-5)].iter().for_each(|range| {
+                assert!(repeat_with(|| rng.next_int_range(range.clone()))
+                    .take(10000)
+                    .all(|n| range.contains(&n)));
+            })
+        }
+
+        #[test]
+        fn bool_probability() {
+            assert!(repeat_with(|| random_bool(1.0)).take(1000).all(|b| b));
+            assert!(repeat_with(|| random_bool(0.0)).take(1000).all(|b| !b));
+
+            let trues = repeat_with(|| random_bool(0.25))
+                .take(100000)
+                .filter(|&b| b)
+                .count();
+            assert!(trues > 20000 && trues < 30000);
+        }
+
+        #[test]
+        fn fifty_fifty_both() {
+            assert!(repeat_with(fifty_fifty).take(1000).any(|b| b));
+            assert!(repeat_with(fifty_fifty).take(1000).any(|b| !b));
+        }
+
+        #[test]
+        #[should_panic]
+        fn bool_invalid_probability() {
+            random_bool(1.5);
+        }
+
+        #[test]
+        fn shuffle_keeps_elements() {
+            let mut numbers = (0..100).collect::<Vec<_>>();
+            shuffle(&mut numbers);
+            assert_ne!(numbers, (0..100).collect::<Vec<_>>());
+
+            numbers.sort_unstable();
+            assert_eq!(numbers, (0..100).collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn shuffle_empty_and_single() {
+            let mut empty: [i32; 0] = [];
+            shuffle(&mut empty);
+
+            let mut single = [1];
+            shuffle(&mut single);
+            assert_eq!(single, [1]);
+        }
+
+        #[test]
+        fn choose_all_elements() {
+            let items = [1, 2, 3, 4];
+            items.iter().for_each(|expected| {
+                assert!(repeat_with(|| choose(&items))
+                    .take(1000)
+                    .any(|n| n == Some(expected)));
+            });
+        }
+
+        #[test]
+        fn choose_empty() {
+            let mut empty: [i32; 0] = [];
+            assert_eq!(choose(&empty), None);
+            assert_eq!(choose_mut(&mut empty), None);
+        }
+
+        #[test]
+        fn sample_distinct() {
+            let numbers = (0..50).collect::<Vec<_>>();
+            repeat_with(|| sample(&numbers, 10))
+                .take(1000)
+                .for_each(|mut picked| {
+                    assert_eq!(picked.len(), 10);
+                    picked.sort_unstable();
+                    picked.dedup();
+                    assert_eq!(picked.len(), 10);
+                });
+        }
+
+        #[test]
+        fn sample_too_many() {
+            let mut picked = sample(&[1, 2, 3], 5);
+            picked.sort_unstable();
+            assert_eq!(picked, [1, 2, 3]);
+            assert!(sample::<i32>(&[], 3).is_empty());
+        }
+
+        #[test]
+        fn string_charsets() {
+            assert_eq!(random_string(0, Charset::Letters), "");
+            assert!(random_string(100, Charset::Letters)
+                .chars()
+                .all(|c| c.is_ascii_alphabetic()));
+            assert!(random_string(100, Charset::Hex)
+                .chars()
+                .all(|c| c.is_ascii_hexdigit()));
+            assert!(random_string(100, Charset::Custom("äö"))
+                .chars()
+                .all(|c| c == 'ä' || c == 'ö'));
+            assert_eq!(random_string(10, Charset::Custom("äö")).chars().count(), 10);
+        }
+
+        #[test]
+        #[should_panic]
+        fn string_empty_charset() {
+            random_string(5, Charset::Custom(""));
+        }
+
+        #[test]
+        fn gaussian_mean_std_dev() {
+            let numbers = repeat_with(|| random_gaussian(10.0, 2.0))
+                .take(100000)
+                .collect::<Vec<_>>();
+            let mean = numbers.iter().sum::<f64>() / numbers.len() as f64;
+            let variance =
+                numbers.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / numbers.len() as f64;
+
+            assert!((mean - 10.0).abs() < 0.1);
+            assert!((variance.sqrt() - 2.0).abs() < 0.1);
+            assert!(numbers.iter().all(|n| n.is_finite()));
+        }
+
+        #[test]
+        fn gaussian_zero_std_dev() {
+            assert_eq!(random_gaussian(5.0, 0.0), 5.0);
+        }
+
+        #[test]
+        fn inclusive_range_in_range() {
+            [0..=10, 1..=6, 5..=5, (-10)..=(-5)]
+                .iter()
+                .for_each(|range| {
+                    range.clone().for_each(|expected| {
+                        assert!(repeat_with(|| random_int_range(range.clone()))
+                            .take(100000)
+                            .any(|n| n == expected));
                     });
+                    assert!(repeat_with(|| random_int_range(range.clone()))
+                        .take(10000)
+                        .all(|n| range.contains(&n)));
+                })
+        }
+
+        #[test]
+        fn full_range() {
+            repeat_with(|| random_int_range(i32::MIN..=i32::MAX))
+                .take(1000)
+                .for_each(drop);
+            assert!(repeat_with(|| random_int_range(i32::MIN..(i32::MIN + 2)))
+                .take(1000)
+                .all(|n| n == i32::MIN || n == i32::MIN + 1));
+        }
+
+        #[test]
+        #[should_panic]
+        fn empty_range() {
+            random_int_range(5..5);
+        }
+
+        #[test]
+        fn float_range_in_range() {
+            [
+                0.0..1.0,
+                0.5..2.5,
+                -10.0..-9.99,
+                -1e300..1e300,
+                f64::MIN..f64::MAX,
+            ]
+            .iter()
+            .for_each(|range| {
+                assert!(repeat_with(|| random_float_range(range.clone()))
+                    .take(10000)
+                    .all(|n| range.contains(&n)));
+            })
+        }
+
+        #[test]
+        fn float_range_huge() {
+            // the width of the range doesn't fit into an f64
+            let numbers = repeat_with(|| random_float_range(f64::MIN..f64::MAX))
+                .take(1000)
+                .collect::<Vec<_>>();
+            assert!(numbers.iter().all(|n| n.is_finite()));
+            assert!(numbers.iter().any(|&n| n < -1e307));
+            assert!(numbers.iter().any(|&n| n > 1e307));
+        }
+
+        #[test]
+        #[should_panic]
+        fn float_range_empty() {
+            random_float_range(1.0..1.0);
+        }
+
+        #[test]
+        #[should_panic]
+        fn float_range_nan() {
+            random_float_range(0.0..f64::NAN);
+        }
+
+        #[test]
+        fn wide_ranges_in_range() {
+            assert!(repeat_with(|| random_int_range_i64(i64::MIN..=i64::MAX))
+                .take(1000)
+                .any(|n| n < 0));
+            assert!(
+                repeat_with(|| random_int_range_i64(-3_000_000_000..-2_999_999_990))
+                    .take(10000)
+                    .all(|n| (-3_000_000_000..-2_999_999_990).contains(&n))
+            );
+            assert!(repeat_with(|| random_int_range_u64(0..=u64::MAX))
+                .take(1000)
+                .any(|n| n > u64::MAX / 2));
+            assert!(
+                repeat_with(|| random_int_range_u64((u64::MAX - 3)..=u64::MAX))
+                    .take(10000)
+                    .all(|n| n >= u64::MAX - 3)
+            );
+        }
+
+        #[test]
+        fn unbiased() {
+            // with `next_u64() % bound`, the numbers below `u64::MAX / 3` would appear twice as often
+            let mut rng = SimpleRng::from_seed(5);
+            let bound = u64::MAX / 3 * 2;
+            let low = repeat_with(|| rng.next_int_range_u64(0..bound))
+                .take(100000)
+                .filter(|&n| n < bound / 2)
+                .count();
+            assert!(low > 48000 && low < 52000);
+        }
+
+        #[test]
+        fn bytes_all_values() {
+            let bytes = random_bytes(100000);
+            assert_eq!(bytes.len(), 100000);
+            (0..=255u8).for_each(|expected| assert!(bytes.contains(&expected)));
+        }
+
+        #[test]
+        fn fill_uneven_length() {
+            let mut buffer = [0u8; 13];
+            assert!(repeat_with(|| {
+                fill_random(&mut buffer);
+                buffer[12]
+            })
+            .take(100)
+            .any(|byte| byte != 0));
+        }
+
+        #[test]
+        fn uuid_format() {
+            repeat_with(random_uuid).take(100).for_each(|uuid| {
+                let parts = uuid.split('-').map(str::len).collect::<Vec<_>>();
+                assert_eq!(parts, [8, 4, 4, 4, 12]);
+                assert!(uuid.chars().all(|c| c == '-' || c.is_ascii_hexdigit()));
+                assert!(!uuid.chars().any(|c| c.is_ascii_uppercase()));
+                assert_eq!(&uuid[14..15], "4");
+                assert!(["8", "9", "a", "b"].contains(&&uuid[19..20]));
+            });
+            assert_ne!(random_uuid(), random_uuid());
+        }
+
+        #[test]
+        fn uuid_bytes_version() {
+            repeat_with(random_uuid_bytes).take(100).for_each(|bytes| {
+                assert_eq!(bytes[6] >> 4, 4);
+                assert_eq!(bytes[8] >> 6, 0b10);
+            });
+        }
+
+        #[test]
+        fn coin_both_sides() {
+            assert!(repeat_with(coin_flip).take(1000).any(|c| c == Coin::Heads));
+            assert!(repeat_with(coin_flip).take(1000).any(|c| c == Coin::Tails));
+            assert_eq!(Coin::Heads.to_string(), "Heads");
+            assert_eq!(Coin::Tails.to_string(), "Tails");
+        }
+
+        #[test]
+        fn char_in_range() {
+            assert!(repeat_with(|| random_char('a'..='e'))
+                .take(1000)
+                .all(|c| ('a'..='e').contains(&c)));
+            ('a'..='e').for_each(|expected| {
+                assert!(repeat_with(|| random_char('a'..='e'))
+                    .take(1000)
+                    .any(|c| c == expected));
+            });
+            assert!(repeat_with(random_letter)
+                .take(1000)
+                .all(|c| c.is_ascii_lowercase()));
+        }
+
+        #[test]
+        fn char_around_surrogates() {
+            let mut rng = SimpleRng::from_seed(3);
+            let low = '\u{D7FF}';
+            let high = '\u{E000}';
+            let chars = repeat_with(|| rng.next_char(low..=high))
+                .take(1000)
+                .collect::<Vec<_>>();
+            assert!(chars.iter().all(|&c| c == low || c == high));
+            assert!(chars.contains(&low) && chars.contains(&high));
+
+            repeat_with(|| rng.next_char('\0'..=char::MAX))
+                .take(1000)
+                .for_each(drop);
+        }
+
+        #[test]
+        fn char_from_charset() {
+            assert!(repeat_with(|| random_char_from(Charset::Custom("äb")))
+                .take(1000)
+                .all(|c| c == 'ä' || c == 'b'));
+        }
+
+        #[test]
+        fn iterators_in_range() {
+            assert!(random_floats().take(10000).all(|n| (0.0..1.0).contains(&n)));
+            assert!(random_ints(-3..=3)
+                .take(10000)
+                .all(|n| (-3..=3).contains(&n)));
+        }
+
+        #[test]
+        fn seeded_iterators_reproducible() {
+            let mut rng1 = SimpleRng::from_seed(9);
+            let mut rng2 = SimpleRng::from_seed(9);
+            assert_eq!(
+                rng1.ints(0..1000).take(100).collect::<Vec<_>>(),
+                rng2.ints(0..1000).take(100).collect::<Vec<_>>()
+            );
+            assert_eq!(rng1.next_float(), rng2.next_float());
+        }
+
+        #[test]
+        #[should_panic]
+        fn iterator_empty_range() {
+            let _ = random_ints(3..3);
+        }
+
+        #[test]
+        fn threads_independent() {
+            let sequences = (0..8)
+                .map(|_| std::thread::spawn(|| random_floats().take(10).collect::<Vec<_>>()))
+                .collect::<Vec<_>>()
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Vec<_>>();
+
+            sequences.iter().enumerate().for_each(|(i, a)| {
+                sequences[i + 1..].iter().for_each(|b| assert_ne!(a, b));
+            });
+        }
+
+        #[test]
+        fn seed_parsing() {
+            assert_eq!(super::parse_seed("42"), 42);
+            assert_eq!(super::parse_seed(" 18446744073709551615\n"), u64::MAX);
+        }
+
+        #[test]
+        #[should_panic(expected = "SIMPLE_STD_SEED must be a number")]
+        fn seed_invalid() {
+            super::parse_seed("hello");
+        }
+
+        #[test]
+        fn seed_replays() {
+            set_random_seed(1);
+            let first = random_ints(0..1000).take(100).collect::<Vec<_>>();
+            set_random_seed(1);
+            assert_eq!(first, random_ints(0..1000).take(100).collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn state_restores() {
+            let state = random_state();
+            let first = random_floats().take(100).collect::<Vec<_>>();
+            set_random_state(state.clone());
+            let second = random_floats().take(100).collect::<Vec<_>>();
+            assert_eq!(first, second);
+
+            let mut rng = state;
+            assert_eq!(first, rng.floats().take(100).collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn random_primitives() {
+            assert!(repeat_with(bool::random).take(1000).any(|b| b));
+            assert!(repeat_with(bool::random).take(1000).any(|b| !b));
+            assert!(repeat_with(u8::random).take(10000).any(|n| n == 255));
+            assert!(repeat_with(i64::random).take(1000).any(|n| n < 0));
+            assert!(repeat_with(f32::random)
+                .take(100000)
+                .all(|n| (0.0..1.0).contains(&n)));
+        }
+
+        #[test]
+        fn random_uses_global_state() {
+            set_random_seed(3);
+            let value = u64::random();
+            set_random_seed(3);
+            assert_eq!(value, u64::random());
+        }
+
+        #[test]
+        fn choose_from_iter_all_elements() {
+            assert_eq!(choose_from_iter(std::iter::empty::<i32>()), None);
+            assert_eq!(choose_from_iter(Some(5)), Some(5));
+            (0..5).for_each(|expected| {
+                assert!(repeat_with(|| choose_from_iter(0..5))
+                    .take(1000)
+                    .any(|n| n == Some(expected)));
+            });
+        }
+
+        #[test]
+        fn choose_from_random_iter() {
+            // the iterator uses the global generator itself
+            let chosen = choose_from_iter(random_ints(0..10).take(10)).unwrap();
+            assert!((0..10).contains(&chosen));
+        }
+
+        #[test]
+        fn duration_in_range() {
+            let range = Duration::from_millis(10)..Duration::from_millis(20);
+            assert!(repeat_with(|| random_duration(range.clone()))
+                .take(10000)
+                .all(|d| range.contains(&d)));
+            random_duration(Duration::ZERO..Duration::from_secs(100 * 365 * 24 * 60 * 60));
+        }
+
+        #[test]
+        fn date_in_range() {
+            let range = Date::new(2023, 12, 30)..=Date::new(2024, 1, 2);
+            let dates = repeat_with(|| random_date(range.clone()))
+                .take(1000)
+                .collect::<Vec<_>>();
+            assert!(dates.iter().all(|d| range.contains(d)));
+            [(2023, 12, 30), (2023, 12, 31), (2024, 1, 1), (2024, 1, 2)]
+                .iter()
+                .for_each(|&(y, m, d)| assert!(dates.contains(&Date::new(y, m, d))));
+        }
+
+        #[test]
+        fn distributions_mean() {
+            use crate::distributions::{Bernoulli, Distribution, Exponential, Normal, Uniform};
+
+            fn mean(distribution: &impl Distribution<Output = f64>) -> f64 {
+                repeat_with(|| distribution.sample())
+                    .take(100000)
+                    .sum::<f64>()
+                    / 100000.0
+            }
+
+            assert!((mean(&Uniform::new(2.0, 4.0)) - 3.0).abs() < 0.05);
+            assert!((mean(&Exponential::new(4.0)) - 0.25).abs() < 0.01);
+            assert!((mean(&Normal::new(-5.0, 1.0)) + 5.0).abs() < 0.05);
+
+            let bernoulli = Bernoulli::new(0.1);
+            let trues = repeat_with(|| bernoulli.sample())
+                .take(100000)
+                .filter(|&b| b)
+                .count();
+            assert!(trues > 9000 && trues < 11000);
+            assert!(repeat_with(|| Exponential::new(1.0).sample())
+                .take(10000)
+                .all(|n| n >= 0.0 && n.is_finite()));
+        }
+
+        #[test]
+        fn jitter_in_range() {
+            let second = Duration::from_secs(1);
+            let delays = repeat_with(|| jitter(second, 10.0))
+                .take(10000)
+                .collect::<Vec<_>>();
+            assert!(delays
+                .iter()
+                .all(|d| (Duration::from_millis(900)..=Duration::from_millis(1100)).contains(d)));
+            assert!(delays.iter().any(|d| *d < Duration::from_millis(950)));
+            assert!(delays.iter().any(|d| *d > Duration::from_millis(1050)));
+
+            assert_eq!(jitter(second, 0.0), second);
+            assert!(repeat_with(|| jitter(second, 300.0))
+                .take(10000)
+                .any(|d| d == Duration::ZERO));
+
+            let delays = repeat_with(|| jitter(Duration::MAX, 50.0))
+                .take(1000)
+                .collect::<Vec<_>>();
+            assert!(delays.contains(&Duration::MAX));
+            assert!(delays.iter().any(|d| *d < Duration::MAX / 3 * 2));
+        }
+
+        #[test]
+        fn slice_methods() {
+            use crate::prelude::*;
+
+            let mut numbers = (0..100).collect::<Vec<_>>();
+            numbers.shuffle();
+            assert_ne!(numbers, (0..100).collect::<Vec<_>>());
+            assert!(numbers.choose().is_some());
+            *numbers.choose_mut().unwrap() = 1000;
+            assert!(numbers.contains(&1000));
+            assert_eq!(numbers.sample(5).len(), 5);
+            assert_eq!([0u8; 0].choose(), None);
+        }
+
+        #[test]
+        #[cfg(feature = "secure")]
+        fn secure_bytes_ignore_seed() {
+            set_random_seed(1);
+            let first = random_bytes(32);
+            set_random_seed(1);
+            assert_ne!(first, random_bytes(32));
+
+            let bytes = random_bytes(100000);
+            (0..=255u8).for_each(|expected| assert!(bytes.contains(&expected)));
+        }
+
+        #[test]
+        fn weighted_map_proportions() {
+            let weights = [("a", 1.0), ("b", 3.0), ("never", 0.0)]
+                .iter()
+                .cloned()
+                .collect::<std::collections::HashMap<_, _>>();
+            let picks = repeat_with(|| *weighted_choice_map(&weights))
+                .take(100000)
+                .collect::<Vec<_>>();
+            let a = picks.iter().filter(|&&k| k == "a").count();
+
+            assert!(!picks.contains(&"never"));
+            assert!(a > 23000 && a < 27000);
+        }
+
+        #[test]
+        #[should_panic]
+        fn weighted_map_empty() {
+            weighted_choice_map(&std::collections::HashMap::<i32, f64>::new());
+        }
+
+        #[test]
+        #[should_panic]
+        fn weighted_map_negative() {
+            let weights = [(1, 2.0), (2, -1.0)]
+                .iter()
+                .cloned()
+                .collect::<std::collections::HashMap<_, _>>();
+            weighted_choice_map(&weights);
+        }
+    }
+}
+
+mod dice {
+    use crate::random::{with_global_rng, SimpleRng};
+    use std::fmt::{Display, Formatter};
+    use std::str::FromStr;
+
+    ///
+    /// Rolls dice written in the common dice notation, like `"2d6+1"`, and returns the total
+    ///
+    /// The notation is `<count>d<sides>`, optionally followed by `+<modifier>` or `-<modifier>`.
+    /// `"d20"` is the same as `"1d20"`. Use [`Dice`] to get the individual rolls.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::roll;
+    ///
+    /// let damage = roll("2d6+1");
+    ///
+    /// println!("The goblin takes {} damage", damage);
+    ///
+    /// assert!(damage >= 3);
+    /// assert!(damage <= 13);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the notation is invalid.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// Rolling dice is a very specific use case, it's only interesting for games.
+    pub fn roll(notation: &str) -> i64 {
+        match notation.parse::<Dice>() {
+            Ok(dice) => dice.roll().total,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    ///
+    /// Dice written in the common dice notation, see [`roll`]
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::Dice;
+    ///
+    /// let dice = "3d6-2".parse::<Dice>().expect("invalid dice");
+    /// let roll = dice.roll();
+    ///
+    /// println!("You rolled {:?}, that's {} in total", roll.rolls, roll.total);
+    ///
+    /// assert_eq!(roll.rolls.len(), 3);
+    /// assert_eq!(roll.total, roll.rolls.iter().sum::<i64>() - 2);
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`roll`]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Dice {
+        /// How many dice are rolled
+        pub count: u32,
+        /// How many sides each die has
+        pub sides: u32,
+        /// The number that is added to the sum of the rolls
+        pub modifier: i64,
+    }
+
+    /// The result of rolling [`Dice`]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct DiceRoll {
+        /// The result of every single die
+        pub rolls: Vec<i64>,
+        /// The sum of all rolls plus the modifier, which stops at [`i64::MAX`] or [`i64::MIN`]
+        /// instead of overflowing
+        pub total: i64,
+    }
+
+    /// The error returned when parsing invalid dice notation
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ParseDiceError {
+        notation: String,
+        reason: &'static str,
+    }
+
+    /// the most dice that can be rolled at once, so that `"99999999d6"` doesn't run forever
+    const MAX_COUNT: u32 = 10_000;
+
+    impl Dice {
+        ///
+        /// Rolls the dice
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::Dice;
+        ///
+        /// let d20 = "d20".parse::<Dice>().unwrap();
+        ///
+        /// println!("Attack roll: {}", d20.roll().total);
+        /// ```
+        pub fn roll(&self) -> DiceRoll {
+            with_global_rng(|rng| self.roll_with(rng))
+        }
+
+        ///
+        /// Rolls the dice using the given generator instead of the global one
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::{Dice, SimpleRng};
+        ///
+        /// let mut rng = SimpleRng::from_seed(6);
+        /// let dice = "4d6".parse::<Dice>().unwrap();
+        ///
+        /// println!("Your strength is {}", dice.roll_with(&mut rng).total);
+        /// ```
+        pub fn roll_with(&self, rng: &mut SimpleRng) -> DiceRoll {
+            let rolls = (0..self.count)
+                .map(|_| rng.next_int_range_i64(1..=i64::from(self.sides)))
+                .collect::<Vec<_>>();
+            // the modifier can be any i64, so the total could overflow
+            let total = rolls
+                .iter()
+                .fold(self.modifier, |total, &roll| total.saturating_add(roll));
+
+            DiceRoll { rolls, total }
+        }
+    }
+
+    impl FromStr for Dice {
+        type Err = ParseDiceError;
+
+        fn from_str(notation: &str) -> Result<Self, Self::Err> {
+            let error = |reason| ParseDiceError {
+                notation: notation.to_owned(),
+                reason,
+            };
+
+            let normalized = notation
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .collect::<String>()
+                .to_lowercase();
+
+            let (count, rest) = normalized
+                .split_once('d')
+                .ok_or_else(|| error("it is missing the `d`"))?;
+
+            let (sides, modifier) = match rest.find(['+', '-']) {
+                Some(index) => rest.split_at(index),
+                None => (rest, ""),
+            };
+
+            let count = if count.is_empty() {
+                1
+            } else {
+                count
+                    .parse::<u32>()
+                    .map_err(|_| error("the number of dice is not a valid number"))?
+            };
+            let sides = sides
+                .parse::<u32>()
+                .map_err(|_| error("the number of sides is not a valid number"))?;
+            // parsing as i64 already handles the `+` or `-` in front
+            let modifier = if modifier.is_empty() {
+                0
+            } else {
+                modifier
+                    .parse::<i64>()
+                    .map_err(|_| error("the modifier is not a valid number"))?
+            };
+
+            if count == 0 {
+                return Err(error("at least one die has to be rolled"));
+            }
+            if count > MAX_COUNT {
+                return Err(error("that is too many dice"));
+            }
+            if sides == 0 {
+                return Err(error("a die needs at least one side"));
+            }
+
+            Ok(Dice {
+                count,
+                sides,
+                modifier,
+            })
+        }
+    }
+
+    impl Display for Dice {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}d{}", self.count, self.sides)?;
+            match self.modifier {
+                0 => Ok(()),
+                modifier if modifier > 0 => write!(f, "+{}", modifier),
+                modifier => write!(f, "{}", modifier),
+            }
+        }
+    }
+
+    impl Display for ParseDiceError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(
+                f,
+                "invalid dice `{}`: {}, expected something like `2d6+1`",
+                self.notation, self.reason
+            )
+        }
+    }
+
+    impl std::error::Error for ParseDiceError {}
+
+    #[cfg(test)]
+    mod test {
+        use crate::{roll, Dice, SimpleRng};
+        use std::iter::repeat_with;
+
+        fn dice(count: u32, sides: u32, modifier: i64) -> Dice {
+            Dice {
+                count,
+                sides,
+                modifier,
+            }
+        }
+
+        #[test]
+        fn parse_valid() {
+            assert_eq!("2d6".parse(), Ok(dice(2, 6, 0)));
+            assert_eq!("d20".parse(), Ok(dice(1, 20, 0)));
+            assert_eq!("3D8+2".parse(), Ok(dice(3, 8, 2)));
+            assert_eq!("1d4-1".parse(), Ok(dice(1, 4, -1)));
+            assert_eq!(" 2 d 10 + 5 ".parse(), Ok(dice(2, 10, 5)));
+        }
+
+        #[test]
+        fn parse_invalid() {
+            [
+                "", "6", "2x6", "d", "2d", "0d6", "2d0", "2d6+", "2d6+-1", "2d6++1", "-2d6",
+                "2d6+a",
+            ]
+            .iter()
+            .for_each(|notation| assert!(notation.parse::<Dice>().is_err(), "{}", notation));
+        }
+
+        #[test]
+        fn display_roundtrip() {
+            ["2d6", "1d20", "3d8+2", "1d4-1"]
+                .iter()
+                .for_each(|notation| {
+                    assert_eq!(notation.parse::<Dice>().unwrap().to_string(), *notation)
+                });
+        }
+
+        #[test]
+        fn roll_in_range() {
+            assert!(repeat_with(|| roll("2d6+1"))
+                .take(10000)
+                .all(|n| (3..=13).contains(&n)));
+            (3..=13).for_each(|expected| {
+                assert!(repeat_with(|| roll("2d6+1"))
+                    .take(10000)
+                    .any(|n| n == expected))
+            });
+        }
+
+        #[test]
+        fn roll_details() {
+            let mut rng = SimpleRng::from_seed(0);
+            let result = dice(5, 4, -3).roll_with(&mut rng);
+            assert_eq!(result.rolls.len(), 5);
+            assert!(result.rolls.iter().all(|n| (1..=4).contains(n)));
+            assert_eq!(result.total, result.rolls.iter().sum::<i64>() - 3);
+        }
+
+        #[test]
+        fn roll_huge_modifier() {
+            let max = format!("2d6+{}", i64::MAX).parse::<Dice>().unwrap();
+            assert_eq!(max.roll().total, i64::MAX);
+            let min = format!("2d6{}", i64::MIN).parse::<Dice>().unwrap();
+            let roll = min.roll();
+            assert_eq!(roll.total, i64::MIN + roll.rolls.iter().sum::<i64>());
+        }
+
+        #[test]
+        #[should_panic(expected = "invalid dice")]
+        fn roll_invalid() {
+            roll("lots of dice");
+        }
+    }
+}
+
+mod color {
+    use crate::random::{Random, SimpleRng};
+    use std::fmt::{Display, Formatter};
+    use std::io::IsTerminal;
+    use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+
+    ///
+    /// A color made out of red, green and blue, each from 0 to 255
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::Color;
+    ///
+    /// let orange = Color::new(255, 165, 0);
+    ///
+    /// println!("Orange is {}", orange.to_hex());
+    ///
+    /// assert_eq!(orange, Color::ORANGE);
+    /// assert_eq!(orange.to_hex(), "#ffa500");
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// `std` doesn't know anything about graphics or colors, that's what crates for images and
+    /// terminals are for.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Color {
+        pub r: u8,
+        pub g: u8,
+        pub b: u8,
+    }
+
+    impl Color {
+        pub const BLACK: Color = Color::new(0, 0, 0);
+        pub const WHITE: Color = Color::new(255, 255, 255);
+        pub const GRAY: Color = Color::new(128, 128, 128);
+        pub const RED: Color = Color::new(255, 0, 0);
+        pub const GREEN: Color = Color::new(0, 255, 0);
+        pub const BLUE: Color = Color::new(0, 0, 255);
+        pub const YELLOW: Color = Color::new(255, 255, 0);
+        pub const CYAN: Color = Color::new(0, 255, 255);
+        pub const MAGENTA: Color = Color::new(255, 0, 255);
+        pub const ORANGE: Color = Color::new(255, 165, 0);
+        pub const PURPLE: Color = Color::new(128, 0, 128);
+        pub const PINK: Color = Color::new(255, 192, 203);
+
+        /// Creates a color from its red, green and blue parts
+        pub const fn new(r: u8, g: u8, b: u8) -> Self {
+            Color { r, g, b }
+        }
+
+        ///
+        /// Returns a random color, see [`random_color`]
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::Color;
+        ///
+        /// let color = Color::random();
+        ///
+        /// println!("Paint the wall in {}", color);
+        /// ```
+        pub fn random() -> Self {
+            <Self as Random>::random()
+        }
+
+        ///
+        /// Returns the color in the hex notation used in HTML and CSS, like `"#ff0000"` for red
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::Color;
+        ///
+        /// assert_eq!(Color::new(18, 52, 86).to_hex(), "#123456");
+        /// ```
+        pub fn to_hex(&self) -> String {
+            format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+        }
+
+        ///
+        /// Returns the red, green and blue parts of the color
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::Color;
+        ///
+        /// let (r, g, b) = Color::CYAN.to_rgb();
+        ///
+        /// assert_eq!((r, g, b), (0, 255, 255));
+        /// ```
+        pub fn to_rgb(&self) -> (u8, u8, u8) {
+            (self.r, self.g, self.b)
+        }
+    }
+
+    impl Random for Color {
+        fn random_with(rng: &mut SimpleRng) -> Self {
+            Color {
+                r: u8::random_with(rng),
+                g: u8::random_with(rng),
+                b: u8::random_with(rng),
+            }
+        }
+    }
+
+    impl From<(u8, u8, u8)> for Color {
+        fn from((r, g, b): (u8, u8, u8)) -> Self {
+            Color { r, g, b }
+        }
+    }
+
+    impl Display for Color {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            f.write_str(&self.to_hex())
+        }
+    }
+
+    ///
+    /// Returns a random color
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::random_color;
+    ///
+    /// let color = random_color();
+    ///
+    /// println!("Your favourite color is {}", color);
+    /// println!("red: {}, green: {}, blue: {}", color.r, color.g, color.b);
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`Color`]
+    pub fn random_color() -> Color {
+        Color::random()
+    }
+
+    ///
+    /// A color for text in the terminal, used by [`Colored`]
+    ///
+    /// The named colors are the ones that every terminal supports, and which can be changed
+    /// in its settings. [`Rgb`](TerminalColor::Rgb) colors are shown exactly, but only by most
+    /// terminals.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::{Color, Colored, TerminalColor};
+    ///
+    /// println!("{}", "grass".color(TerminalColor::Green));
+    /// println!("{}", "sunset".color(Color::ORANGE));
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum TerminalColor {
+        Black,
+        Red,
+        Green,
+        Yellow,
+        Blue,
+        Magenta,
+        Cyan,
+        White,
+        Gray,
+        Rgb(Color),
+    }
+
+    impl TerminalColor {
+        /// the parameter of the escape sequence that selects the color
+        pub(crate) fn code(self, background: bool) -> String {
+            let base = if background { 40 } else { 30 };
+            let offset = match self {
+                TerminalColor::Black => 0,
+                TerminalColor::Red => 1,
+                TerminalColor::Green => 2,
+                TerminalColor::Yellow => 3,
+                TerminalColor::Blue => 4,
+                TerminalColor::Magenta => 5,
+                TerminalColor::Cyan => 6,
+                TerminalColor::White => 7,
+                // the bright version of black
+                TerminalColor::Gray => 60,
+                TerminalColor::Rgb(Color { r, g, b }) => {
+                    return format!("{};2;{};{};{}", base + 8, r, g, b)
+                }
+            };
+            (base + offset).to_string()
+        }
+    }
+
+    impl From<Color> for TerminalColor {
+        fn from(color: Color) -> Self {
+            TerminalColor::Rgb(color)
+        }
+    }
+
+    macro_rules! color_methods {
+        ($($(#[$doc:meta])* $name:ident => $color:ident,)*) => {
+            /// Adds colors and styles to everything that can be printed, which are only shown
+            /// if [`colors_enabled`] returns `true`
+            ///
+            /// # Example
+            /// ```
+            /// use simple_std::Colored;
+            ///
+            /// println!("{} the file was not found", "error:".red().bold());
+            /// println!("You have {} points", 42.yellow());
+            /// ```
+            ///
+            /// # Why is this not in std?
+            ///
+            /// Colors in the terminal are set with special sequences of characters, which
+            /// aren't supported everywhere. `std` doesn't deal with terminals at all.
+            pub trait Colored: Sized {
+                $(
+                    $(#[$doc])*
+                    fn $name(self) -> Styled<Self> {
+                        Styled::new(self).$name()
+                    }
+                )*
+
+                /// Shows it in any color, like [`TerminalColor::Cyan`] or [`Color::ORANGE`]
+                fn color(self, color: impl Into<TerminalColor>) -> Styled<Self> {
+                    Styled::new(self).color(color)
+                }
+
+                /// Shows it with a background color
+                fn on_color(self, color: impl Into<TerminalColor>) -> Styled<Self> {
+                    Styled::new(self).on_color(color)
+                }
+
+                /// Shows it in bold
+                fn bold(self) -> Styled<Self> {
+                    Styled::new(self).bold()
+                }
+
+                /// Shows it fainter than normal text
+                fn dim(self) -> Styled<Self> {
+                    Styled::new(self).dim()
+                }
+
+                /// Shows it in italics, which some terminals don't support
+                fn italic(self) -> Styled<Self> {
+                    Styled::new(self).italic()
+                }
+
+                /// Shows it underlined
+                fn underline(self) -> Styled<Self> {
+                    Styled::new(self).underline()
+                }
+            }
+
+            impl<T> Styled<T> {
+                $(
+                    $(#[$doc])*
+                    pub fn $name(self) -> Self {
+                        self.color(TerminalColor::$color)
+                    }
+                )*
+            }
+        };
+    }
+
+    color_methods! {
+        /// Shows it in black
+        black => Black,
+        /// Shows it in red
+        red => Red,
+        /// Shows it in green
+        green => Green,
+        /// Shows it in yellow
+        yellow => Yellow,
+        /// Shows it in blue
+        blue => Blue,
+        /// Shows it in magenta
+        magenta => Magenta,
+        /// Shows it in cyan
+        cyan => Cyan,
+        /// Shows it in white
+        white => White,
+        /// Shows it in gray
+        gray => Gray,
+    }
+
+    impl<T: Display> Colored for T {}
+
+    /// Something with colors and styles, created with the methods of [`Colored`]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Styled<T> {
+        value: T,
+        foreground: Option<TerminalColor>,
+        background: Option<TerminalColor>,
+        bold: bool,
+        dim: bool,
+        italic: bool,
+        underline: bool,
+    }
+
+    impl<T> Styled<T> {
+        /// Creates it without any colors or styles
+        pub fn new(value: T) -> Self {
+            Styled {
+                value,
+                foreground: None,
+                background: None,
+                bold: false,
+                dim: false,
+                italic: false,
+                underline: false,
+            }
+        }
+
+        /// Shows it in any color, see [`Colored::color`]
+        pub fn color(mut self, color: impl Into<TerminalColor>) -> Self {
+            self.foreground = Some(color.into());
+            self
+        }
+
+        /// Shows it with a background color
+        pub fn on_color(mut self, color: impl Into<TerminalColor>) -> Self {
+            self.background = Some(color.into());
+            self
+        }
+
+        /// Shows it in bold
+        pub fn bold(mut self) -> Self {
+            self.bold = true;
+            self
+        }
+
+        /// Shows it fainter than normal text
+        pub fn dim(mut self) -> Self {
+            self.dim = true;
+            self
+        }
+
+        /// Shows it in italics, which some terminals don't support
+        pub fn italic(mut self) -> Self {
+            self.italic = true;
+            self
+        }
+
+        /// Shows it underlined
+        pub fn underline(mut self) -> Self {
+            self.underline = true;
+            self
+        }
+
+        /// the escape sequence that turns on all colors and styles, or an empty string if
+        /// there are none
+        fn escape_sequence(&self) -> String {
+            let styles = [
+                (self.bold, "1"),
+                (self.dim, "2"),
+                (self.italic, "3"),
+                (self.underline, "4"),
+            ];
+            let codes = styles
+                .iter()
+                .filter(|(enabled, _)| *enabled)
+                .map(|(_, code)| code.to_string())
+                .chain(self.foreground.map(|color| color.code(false)))
+                .chain(self.background.map(|color| color.code(true)))
+                .collect::<Vec<_>>();
+
+            if codes.is_empty() {
+                String::new()
+            } else {
+                format!("\x1b[{}m", codes.join(";"))
+            }
+        }
+    }
+
+    impl<T: Display> Display for Styled<T> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            let escape_sequence = self.escape_sequence();
+            if escape_sequence.is_empty() || !colors_enabled() {
+                return Display::fmt(&self.value, f);
+            }
+
+            f.write_str(&escape_sequence)?;
+            Display::fmt(&self.value, f)?;
+            f.write_str("\x1b[0m")
+        }
+    }
+
+    ///
+    /// Replaces tags like `<red>` and `</red>` with the colors and styles they stand for, or
+    /// removes them if [`colors_enabled`] returns `false`
+    ///
+    /// The tags are the names of the methods of [`Colored`]: `black`, `red`, `green`,
+    /// `yellow`, `blue`, `magenta`, `cyan`, `white`, `gray`, `bold`, `dim`, `italic` and
+    /// `underline`. They can be nested, and everything else stays like it is. This is what
+    /// [`cprintln!`](crate::cprintln) uses.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::{colorize, set_colors_enabled};
+    ///
+    /// set_colors_enabled(false);
+    /// assert_eq!(colorize("<red>Error:</red> 1 < 2"), "Error: 1 < 2");
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`Colored`]
+    pub fn colorize(text: &str) -> String {
+        colorize_with(text, colors_enabled())
+    }
+
+    fn colorize_with(text: &str, colors: bool) -> String {
+        let mut result = String::new();
+        // the tags that are open, with the codes of their escape sequences
+        let mut open = Vec::<(&str, String)>::new();
+        let mut rest = text;
+
+        while let Some(start) = rest.find('<') {
+            result.push_str(&rest[..start]);
+            rest = &rest[start..];
+
+            let tag = rest[1..].find('>').map(|end| &rest[1..end + 1]);
+            let (closing, name) = match tag {
+                Some(tag) => match tag.strip_prefix('/') {
+                    Some(name) => (true, name),
+                    None => (false, tag),
+                },
+                None => (false, ""),
+            };
+
+            let position = open.iter().rposition(|(open_name, _)| *open_name == name);
+            match (closing, tag_code(name), position) {
+                (false, Some(code), _) => {
+                    if colors {
+                        result.push_str(&format!("\x1b[{}m", code));
+                    }
+                    open.push((name, code));
+                }
+                (true, _, Some(position)) => {
+                    open.remove(position);
+                    // there is no sequence to turn off just one style, so all are turned off
+                    // and the others turned on again
+                    if colors {
+                        result.push_str("\x1b[0m");
+                        for (_, code) in &open {
+                            result.push_str(&format!("\x1b[{}m", code));
+                        }
+                    }
+                }
+                _ => {
+                    // not a tag
+                    result.push('<');
+                    rest = &rest[1..];
+                    continue;
+                }
+            }
+            rest = &rest[name.len() + 2 + usize::from(closing)..];
+        }
+
+        result.push_str(rest);
+        if colors && !open.is_empty() {
+            result.push_str("\x1b[0m");
+        }
+        result
+    }
+
+    ///
+    /// Removes all escape sequences, like the ones for colors, from the text
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::{set_colors_enabled, strip_ansi, Colored};
+    ///
+    /// set_colors_enabled(true);
+    /// let message = format!("{} the file was saved", "Success:".green());
+    ///
+    /// assert_eq!(strip_ansi(&message), "Success: the file was saved");
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`Colored`]
+    pub fn strip_ansi(text: &str) -> String {
+        let mut result = String::with_capacity(text.len());
+        let mut chars = text.chars().peekable();
+
+        while let Some(char) = chars.next() {
+            if char != '\x1b' {
+                result.push(char);
+                continue;
+            }
+            match chars.next() {
+                // `ESC [`, parameters and intermediate bytes, and a final byte like the `m` of
+                // colors
+                Some('[') => {
+                    for char in chars.by_ref() {
+                        if ('\x40'..='\x7e').contains(&char) {
+                            break;
+                        }
+                    }
+                }
+                // `ESC ]`, a command like the title of the window, and BEL or `ESC \`
+                Some(']') => {
+                    while let Some(char) = chars.next() {
+                        if char == '\x07' {
+                            break;
+                        }
+                        if char == '\x1b' && chars.peek() == Some(&'\\') {
+                            chars.next();
+                            break;
+                        }
+                    }
+                }
+                // all others are just `ESC` and one more character
+                _ => {}
+            }
+        }
+
+        result
+    }
+
+    /// the code for the escape sequence of a tag of [`colorize`]
+    fn tag_code(name: &str) -> Option<String> {
+        let color = match name {
+            "black" => TerminalColor::Black,
+            "red" => TerminalColor::Red,
+            "green" => TerminalColor::Green,
+            "yellow" => TerminalColor::Yellow,
+            "blue" => TerminalColor::Blue,
+            "magenta" => TerminalColor::Magenta,
+            "cyan" => TerminalColor::Cyan,
+            "white" => TerminalColor::White,
+            "gray" => TerminalColor::Gray,
+            "bold" => return Some("1".to_owned()),
+            "dim" => return Some("2".to_owned()),
+            "italic" => return Some("3".to_owned()),
+            "underline" => return Some("4".to_owned()),
+            _ => return None,
+        };
+        Some(color.code(false))
+    }
+
+    /// colors the text with the tags of [`colorize`] if stderr should get colors
+    ///
+    /// That is the case if it is a terminal, unless colors were turned on or off with
+    /// [`set_colors_enabled`].
+    pub(crate) fn colorize_for_stderr(text: &str) -> String {
+        let colors = if COLORS_SET.load(Ordering::Relaxed) {
+            colors_enabled()
+        } else {
+            !no_color() && std::io::stderr().is_terminal() && crate::sys::enable_escape_sequences()
+        };
+        colorize_with(text, colors)
+    }
+
+    const COLORS_UNKNOWN: u8 = 0;
+    const COLORS_DISABLED: u8 = 1;
+    const COLORS_ENABLED: u8 = 2;
+
+    static COLORS: AtomicU8 = AtomicU8::new(COLORS_UNKNOWN);
+    /// whether [`set_colors_enabled`] was called
+    static COLORS_SET: AtomicBool = AtomicBool::new(false);
+
+    /// whether the `NO_COLOR` environment variable asks for no colors
+    fn no_color() -> bool {
+        std::env::var_os("NO_COLOR").is_some_and(|var| !var.is_empty())
+    }
+
+    ///
+    /// Returns whether the colors and styles of [`Colored`] are shown
+    ///
+    /// They are shown if the output goes to a terminal and the `NO_COLOR` environment variable
+    /// isn't set, unless this was changed with [`set_colors_enabled`]. Without this, files that
+    /// the output is written to would be full of strange characters.
+    ///
+    /// On Windows, the console is switched to understanding colors the first time this is
+    /// checked. Consoles that are too old for that don't get any colors.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::colors_enabled;
+    ///
+    /// if colors_enabled() {
+    ///     println!("Look at all these colors!");
+    /// }
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`Colored`]
+    pub fn colors_enabled() -> bool {
+        match COLORS.load(Ordering::Relaxed) {
+            COLORS_ENABLED => true,
+            COLORS_DISABLED => false,
+            _ => {
+                let enabled = !no_color()
+                    && std::io::stdout().is_terminal()
+                    && crate::sys::enable_escape_sequences();
+                store_colors_enabled(enabled);
+                enabled
+            }
+        }
+    }
+
+    ///
+    /// Shows or hides the colors and styles of [`Colored`], no matter where the output goes,
+    /// see [`colors_enabled`]
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::{set_colors_enabled, Colored};
+    ///
+    /// set_colors_enabled(false);
+    /// assert_eq!("plain".red().to_string(), "plain");
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`Colored`]
+    pub fn set_colors_enabled(enabled: bool) {
+        if enabled {
+            // so that they work on Windows, if they can
+            crate::sys::enable_escape_sequences();
+        }
+        COLORS_SET.store(true, Ordering::Relaxed);
+        store_colors_enabled(enabled);
+    }
+
+    fn store_colors_enabled(enabled: bool) {
+        let colors = if enabled {
+            COLORS_ENABLED
+        } else {
+            COLORS_DISABLED
+        };
+        COLORS.store(colors, Ordering::Relaxed);
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::colorize_with;
+        use crate::strip_ansi;
+        use crate::{random_color, Color, Colored, Styled, TerminalColor};
+        use std::iter::repeat_with;
+
+        #[test]
+        fn hex() {
+            assert_eq!(Color::BLACK.to_hex(), "#000000");
+            assert_eq!(Color::WHITE.to_hex(), "#ffffff");
+            assert_eq!(Color::new(1, 2, 255).to_string(), "#0102ff");
+        }
+
+        #[test]
+        fn random_colors() {
+            let colors = repeat_with(random_color).take(1000).collect::<Vec<_>>();
+            assert!(colors.iter().any(|c| c.r > 200));
+            assert!(colors.iter().any(|c| c.g < 50));
+            assert!(colors.iter().any(|c| c.b > 100 && c.b < 150));
+            assert!(colors.windows(2).any(|win| win[0] != win[1]));
+        }
+
+        #[test]
+        fn escape_sequences() {
+            assert_eq!("a".red().escape_sequence(), "\x1b[31m");
+            assert_eq!("a".red().bold().escape_sequence(), "\x1b[1;31m");
+            assert_eq!(
+                "a".underline()
+                    .gray()
+                    .on_color(TerminalColor::Blue)
+                    .escape_sequence(),
+                "\x1b[4;90;44m"
+            );
+            assert_eq!(
+                "a".color(Color::new(1, 2, 3)).escape_sequence(),
+                "\x1b[38;2;1;2;3m"
+            );
+            assert_eq!(Styled::new("a").escape_sequence(), "");
+            assert_eq!(Styled::new("plain").to_string(), "plain");
+        }
+
+        #[test]
+        fn tags() {
+            assert_eq!(
+                colorize_with("<red>Error:</red> oh no", true),
+                "\x1b[31mError:\x1b[0m oh no"
+            );
+            assert_eq!(
+                colorize_with("<bold>a <green>b</green> c</bold>", true),
+                "\x1b[1ma \x1b[32mb\x1b[0m\x1b[1m c\x1b[0m"
+            );
+            assert_eq!(
+                colorize_with("<blue>unclosed", true),
+                "\x1b[34munclosed\x1b[0m"
+            );
+            assert_eq!(
+                colorize_with("<red>Error:</red> oh no", false),
+                "Error: oh no"
+            );
+
+            [
+                "1 < 2 > 0",
+                "<html>",
+                "</red>",
+                "<red",
+                "<",
+                "<<>>",
+                "ä<ö>ü",
+            ]
+            .iter()
+            .for_each(|&text| {
+                assert_eq!(colorize_with(text, true), text);
+                assert_eq!(colorize_with(text, false), text);
+            });
+        }
+
+        #[test]
+        fn stripping() {
+            assert_eq!(
+                strip_ansi("\x1b[1m\x1b[38;2;1;2;3mbold\x1b[0m text"),
+                "bold text"
+            );
+            assert_eq!(strip_ansi("\x1b[2J\x1b[H\x1b[?25lä"), "ä");
+            assert_eq!(strip_ansi("\x1b]0;title\x07a\x1b]0;t\x1b\\b"), "ab");
+            assert_eq!(strip_ansi("\x1bMx\x1b"), "x");
+            assert_eq!(strip_ansi("no [escapes]"), "no [escapes]");
+            assert_eq!(strip_ansi(&colorize_with("<red>1</red>", true)), "1");
+        }
+    }
+}
+
+mod display {
+    use crate::color::{colorize_for_stderr, colors_enabled, strip_ansi, TerminalColor};
+    use std::fmt::{Debug, Display};
+    use std::time::Duration;
+
+    ///
+    /// Prints the message to stderr, after a red `error:`
+    ///
+    /// This is how command line programs usually report errors, so that they are shown even if
+    /// the normal output is written to a file.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::print_error;
+    ///
+    /// let file = "scores.txt";
+    /// print_error(format!("could not find {}", file));
+    /// ```
+    ///
+    /// This prints:
+    ///
+    /// ```text
+    /// error: could not find scores.txt
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// `eprintln!` prints to stderr, and the prefix and colors are left to the program.
+    pub fn print_error(message: impl Display) {
+        eoutputln!(
+            "{} {}",
+            colorize_for_stderr("<bold><red>error:</red></bold>"),
+            message
+        );
+    }
+
+    ///
+    /// Prints the message to stderr, after a yellow `warning:`, see [`print_error`]
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::print_warning;
+    ///
+    /// print_warning("the save file is old, some items might be missing");
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`print_error`]
+    pub fn print_warning(message: impl Display) {
+        eoutputln!(
+            "{} {}",
+            colorize_for_stderr("<bold><yellow>warning:</yellow></bold>"),
+            message
+        );
+    }
+
+    ///
+    /// Prints a table with a border around it, where every column is as wide as its longest cell
+    ///
+    /// Cells that are numbers are aligned to the right, all others to the left. Rows can have
+    /// fewer cells than there are headers, the missing ones are left empty.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::print_table;
+    ///
+    /// let scores = [("Ferris", 120), ("Corro", 95)];
+    ///
+    /// print_table(
+    ///     ["Name", "Score"],
+    ///     scores.iter().map(|(name, score)| [name.to_string(), score.to_string()]),
+    /// );
+    /// ```
+    ///
+    /// This prints:
+    ///
+    /// ```text
+    /// +--------+-------+
+    /// | Name   | Score |
+    /// +--------+-------+
+    /// | Ferris |   120 |
+    /// | Corro  |    95 |
+    /// +--------+-------+
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// There are a lot of different ways to draw a table, and `std` leaves it to `format!` and
+    /// crates like `comfy-table`.
+    pub fn print_table<H, R, C>(
+        headers: impl IntoIterator<Item = H>,
+        rows: impl IntoIterator<Item = R>,
+    ) where
+        H: Display,
+        R: IntoIterator<Item = C>,
+        C: Display,
+    {
+        output!("{}", format_table(headers, rows));
+    }
+
+    fn format_table<H, R, C>(
+        headers: impl IntoIterator<Item = H>,
+        rows: impl IntoIterator<Item = R>,
+    ) -> String
+    where
+        H: Display,
+        R: IntoIterator<Item = C>,
+        C: Display,
+    {
+        let headers = headers
+            .into_iter()
+            .map(|cell| cell.to_string())
+            .collect::<Vec<_>>();
+        let rows = rows
+            .into_iter()
+            .map(|row| row.into_iter().map(|cell| cell.to_string()).collect())
+            .collect::<Vec<Vec<_>>>();
+
+        let columns = rows.iter().map(Vec::len).fold(headers.len(), usize::max);
+        let mut widths = vec![0; columns];
+        for row in std::iter::once(&headers).chain(&rows) {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(visible_len(cell));
+            }
+        }
+
+        let border = widths
+            .iter()
+            .map(|width| format!("+{}", "-".repeat(width + 2)))
+            .collect::<String>()
+            + "+\n";
+        let format_row = |row: &[String], align_numbers: bool| {
+            let mut line = String::new();
+            for (i, width) in widths.iter().enumerate() {
+                let cell = row.get(i).map_or("", String::as_str);
+                let padding = " ".repeat(width - visible_len(cell));
+                if align_numbers && cell.trim().parse::<f64>().is_ok() {
+                    line += &format!("| {}{} ", padding, cell);
+                } else {
+                    line += &format!("| {}{} ", cell, padding);
+                }
+            }
+            line + "|\n"
+        };
+
+        let mut table = border.clone();
+        if !headers.is_empty() {
+            table += &format_row(&headers, false);
+            table += &border;
+        }
+        if !rows.is_empty() {
+            for row in &rows {
+                table += &format_row(row, true);
+            }
+            table += &border;
+        }
+        table
+    }
+
+    ///
+    /// Prints the text one character after the other, waiting for `delay` after each of them,
+    /// like it is typed on a typewriter
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::print_slowly;
+    /// use std::time::Duration;
+    ///
+    /// print_slowly("You wake up in a dark cave...\n", Duration::from_millis(50));
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// It's easy to do with a loop, `std::thread::sleep` and flushing stdout, but the flushing
+    /// is easy to forget, which makes all the characters show up at once.
+    pub fn print_slowly(text: &str, delay: Duration) {
+        for char in text.chars() {
+            output!("{}", char);
+            std::thread::sleep(delay);
+        }
+    }
+
+    ///
+    /// Prints the text inside of a box, with lines of the box around every line of the text
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::print_boxed;
+    ///
+    /// print_boxed("1) New game\n2) Load game\n3) Quit");
+    /// ```
+    ///
+    /// This prints:
+    ///
+    /// ```text
+    /// ┌──────────────┐
+    /// │ 1) New game  │
+    /// │ 2) Load game │
+    /// │ 3) Quit      │
+    /// └──────────────┘
+    /// ```
+    ///
+    /// If the terminal can't show the lines, because it doesn't use UTF-8, it is drawn with `+`,
+    /// `-` and `|` instead.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`print_table`]
+    pub fn print_boxed(text: &str) {
+        let style = if unicode_supported() {
+            &THIN_LINES
+        } else {
+            &ASCII_LINES
+        };
+        output!("{}", draw_box(text, style, 1, false));
+    }
+
+    ///
+    /// Prints the title in the middle of a big box, like the title screen of a game
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::print_banner;
+    ///
+    /// print_banner("The Cave of Doom");
+    /// ```
+    ///
+    /// This prints:
+    ///
+    /// ```text
+    /// ╔══════════════════════╗
+    /// ║                      ║
+    /// ║   The Cave of Doom   ║
+    /// ║                      ║
+    /// ╚══════════════════════╝
+    /// ```
+    ///
+    /// If the terminal can't show the lines, because it doesn't use UTF-8, it is drawn with `*`
+    /// instead. The title can have multiple lines too.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`print_table`]
+    pub fn print_banner(title: &str) {
+        let style = if unicode_supported() {
+            &DOUBLE_LINES
+        } else {
+            &ASCII_STARS
+        };
+        output!("{}", draw_box(title, style, 3, true));
+    }
+
+    ///
+    /// Breaks the lines of the text so that none is longer than `width` characters, without
+    /// breaking words apart
+    ///
+    /// Only words that are longer than a whole line are broken. The line breaks that are
+    /// already in the text stay, so paragraphs stay paragraphs.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::wrap_text;
+    ///
+    /// let text = wrap_text("The old door creaks as you push it open.", 15);
+    /// assert_eq!(text, "The old door\ncreaks as you\npush it open.");
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// Doing it right for every language is complicated and left to crates like `textwrap`.
+    pub fn wrap_text(text: &str, width: usize) -> String {
+        let width = width.max(1);
+        let mut lines = Vec::new();
+
+        for paragraph in text.lines() {
+            let mut line = String::new();
+            let mut line_len = 0;
+            for word in paragraph.split_whitespace() {
+                let mut word = word;
+                let mut word_len = word.chars().count();
+                if line_len > 0 && line_len + 1 + word_len > width {
+                    lines.push(std::mem::take(&mut line));
+                    line_len = 0;
+                }
+                // words that don't fit on a line of their own are split
+                while word_len > width {
+                    let split = word
+                        .char_indices()
+                        .nth(width)
+                        .map_or(word.len(), |(i, _)| i);
+                    lines.push(word[..split].to_owned());
+                    word = &word[split..];
+                    word_len -= width;
+                }
+                if word_len == 0 {
+                    continue;
+                }
+                if line_len > 0 {
+                    line.push(' ');
+                    line_len += 1;
+                }
+                line.push_str(word);
+                line_len += word_len;
+            }
+            lines.push(line);
+        }
+
+        lines.join("\n")
+    }
+
+    ///
+    /// Prints the text like `println!`, but with the lines broken so that they fit into the
+    /// terminal, see [`wrap_text`]
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::print_wrapped;
+    ///
+    /// print_wrapped(
+    ///     "You are standing at the entrance of a cave. A cold wind blows from the darkness \
+    ///     inside, and somewhere deep below, water is dripping.",
+    /// );
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`wrap_text`]
+    pub fn print_wrapped(text: &str) {
+        outputln!("{}", wrap_text(text, crate::terminal::line_width()));
+    }
+
+    ///
+    /// Prints a bar for every value, with its label in front of it, where the longest bar is
+    /// as wide as the terminal allows
+    ///
+    /// The labels and values are paired up in order, values below zero get no bar.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::{print_bar_chart, roll};
+    ///
+    /// let mut counts = [0u32; 6];
+    /// for _ in 0..100 {
+    ///     counts[roll("1d6") as usize - 1] += 1;
+    /// }
+    ///
+    /// print_bar_chart(["one", "two", "three", "four", "five", "six"], counts);
+    /// ```
+    ///
+    /// This prints something like:
+    ///
+    /// ```text
+    /// one   ████████████████████████████████████ 18
+    /// two   ██████████████████████████ 13
+    /// three ████████████████████████████ 14
+    /// four  ██████████████████████████████████ 17
+    /// five  ████████████████████████████████████████████ 22
+    /// six   ████████████████████████████████ 16
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`print_table`]
+    pub fn print_bar_chart<L, V>(
+        labels: impl IntoIterator<Item = L>,
+        values: impl IntoIterator<Item = V>,
+    ) where
+        L: Display,
+        V: Into<f64>,
+    {
+        let bar = if unicode_supported() { '█' } else { '#' };
+        let width = crate::terminal::line_width().saturating_sub(1);
+        output!("{}", format_bar_chart(labels, values, width, bar));
+    }
+
+    fn format_bar_chart<L, V>(
+        labels: impl IntoIterator<Item = L>,
+        values: impl IntoIterator<Item = V>,
+        width: usize,
+        bar: char,
+    ) -> String
+    where
+        L: Display,
+        V: Into<f64>,
+    {
+        let rows = labels
+            .into_iter()
+            .zip(values)
+            .map(|(label, value)| {
+                let value = value.into();
+                (label.to_string(), value, value.to_string())
+            })
+            .collect::<Vec<_>>();
+
+        let label_width = rows
+            .iter()
+            .map(|(label, ..)| visible_len(label))
+            .max()
+            .unwrap_or(0);
+        let value_width = rows
+            .iter()
+            .map(|(.., value)| value.len())
+            .max()
+            .unwrap_or(0);
+        // at least a little bit of a bar, even if the terminal is tiny
+        let max_bar = width.saturating_sub(label_width + value_width + 2).max(10);
+        let max_value = rows.iter().map(|&(_, value, _)| value).fold(0.0, f64::max);
+
+        let mut chart = String::new();
+        for (label, value, value_text) in &rows {
+            let len = if max_value > 0.0 && *value > 0.0 {
+                (value / max_value * max_bar as f64).round() as usize
+            } else {
+                0
+            };
+            let padding = " ".repeat(label_width - visible_len(label));
+            let bar = bar.to_string().repeat(len);
+            chart += &format!("{}{} {} {}\n", label, padding, bar, value_text);
+        }
+        chart
+    }
+
+    ///
+    /// Prints the value like `{:#?}`, with every field on its own line, and strings and
+    /// numbers in color if [`colors_enabled`] allows it
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::show;
+    ///
+    /// #[derive(Debug)]
+    /// struct Player {
+    ///     name: String,
+    ///     position: (i32, i32),
+    ///     items: Vec<&'static str>,
+    /// }
+    ///
+    /// let player = Player {
+    ///     name: "Ferris".to_owned(),
+    ///     position: (3, -1),
+    ///     items: vec!["sword", "apple"],
+    /// };
+    ///
+    /// show(&player);
+    /// ```
+    ///
+    /// This prints:
+    ///
+    /// ```text
+    /// Player {
+    ///     name: "Ferris",
+    ///     position: (
+    ///         3,
+    ///         -1,
+    ///     ),
+    ///     items: [
+    ///         "sword",
+    ///         "apple",
+    ///     ],
+    /// }
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// `std` has `dbg!` and `{:#?}` for this, which are enough for most people.
+    pub fn show(value: &impl Debug) {
+        outputln!(
+            "{}",
+            highlight_debug(&format!("{:#?}", value), colors_enabled())
+        );
+    }
+
+    /// colors the strings, characters and numbers in the output of `Debug`
+    fn highlight_debug(text: &str, colors: bool) -> String {
+        if !colors {
+            return text.to_owned();
+        }
+        let paint = |token: &str, color: TerminalColor| {
+            format!("\x1b[{}m{}\x1b[0m", color.code(false), token)
+        };
+
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text;
+        // whether the previous character could be part of a name, like the `1` in `field1`
+        let mut in_name = false;
+
+        while let Some(char) = rest.chars().next() {
+            let starts_number = char.is_ascii_digit()
+                || char == '-' && rest[1..].starts_with(|next: char| next.is_ascii_digit());
+
+            let len = if char == '"' || char == '\'' {
+                quoted_len(rest, char)
+            } else if starts_number && !in_name {
+                number_len(rest)
+            } else {
+                in_name = char.is_alphanumeric() || char == '_';
+                result.push(char);
+                rest = &rest[char.len_utf8()..];
+                continue;
+            };
+
+            let color = if starts_number {
+                TerminalColor::Yellow
+            } else {
+                TerminalColor::Green
+            };
+            result.push_str(&paint(&rest[..len], color));
+            rest = &rest[len..];
+            in_name = false;
+        }
+
+        result
+    }
+
+    /// the length of the string or character at the start of the text, including the quotes
+    fn quoted_len(text: &str, quote: char) -> usize {
+        let mut escaped = false;
+        for (i, char) in text.char_indices().skip(1) {
+            match char {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                _ if char == quote => return i + 1,
+                _ => {}
+            }
+        }
+        text.len()
+    }
+
+    /// the length of the number at the start of the text, like `-12`, `0.5` or `1e-7`
+    fn number_len(text: &str) -> usize {
+        let mut previous = ' ';
+        for (i, char) in text.char_indices().skip(1) {
+            let exponent_sign = (char == '-' || char == '+') && previous == 'e';
+            if !(char.is_ascii_alphanumeric() || char == '.' || char == '_' || exponent_sign) {
+                return i;
+            }
+            previous = char;
+        }
+        text.len()
+    }
+
+    ///
+    /// Prints the bytes as hexadecimal numbers, 16 in every line, with the position of the
+    /// first one in front and the bytes as text behind them
+    ///
+    /// Bytes that aren't printable ASCII characters are shown as `.` in the text.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::hexdump;
+    ///
+    /// hexdump(b"Hello, World!\n\x00\x01\x02 and more");
+    /// ```
+    ///
+    /// This prints:
+    ///
+    /// ```text
+    /// 00000000  48 65 6c 6c 6f 2c 20 57  6f 72 6c 64 21 0a 00 01  |Hello, World!...|
+    /// 00000010  02 20 61 6e 64 20 6d 6f  72 65                    |. and more|
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// It's only useful for looking at binary data, and there are tools like `hexdump` or
+    /// `xxd` for that.
+    pub fn hexdump(bytes: &[u8]) {
+        output!("{}", format_hexdump(bytes));
+    }
+
+    fn format_hexdump(bytes: &[u8]) -> String {
+        let mut dump = String::new();
+
+        for (i, line) in bytes.chunks(16).enumerate() {
+            let mut hex = String::new();
+            for (j, byte) in line.iter().enumerate() {
+                // an extra space after the first half of the line
+                if j == 8 {
+                    hex.push(' ');
+                }
+                hex += &format!("{:02x} ", byte);
+            }
+            let text = line
+                .iter()
+                .map(|&byte| {
+                    if byte.is_ascii_graphic() || byte == b' ' {
+                        char::from(byte)
+                    } else {
+                        '.'
+                    }
                 })
+                .collect::<String>();
+
+            // the hex part of a full line is 16 * 3 + 1 characters long
+            dump += &format!("{:08x}  {:<49} |{}|\n", i * 16, hex, text);
+        }
+
+        dump
+    }
+
+    ///
+    /// Formats a number of bytes so that it's easy to read, like `1.4 MB`
+    ///
+    /// The units are powers of 1000, like on most websites and file managers.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::format_bytes;
+    ///
+    /// assert_eq!(format_bytes(512), "512 B");
+    /// assert_eq!(format_bytes(1_400_000), "1.4 MB");
+    /// assert_eq!(format_bytes(64_000_000_000), "64 GB");
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// Programs disagree on whether a kilobyte has 1000 or 1024 bytes, and on how to round.
+    pub fn format_bytes(bytes: u64) -> String {
+        const UNITS: [&str; 6] = ["kB", "MB", "GB", "TB", "PB", "EB"];
+
+        if bytes < 1000 {
+            return format!("{} B", bytes);
+        }
+        let mut value = bytes as f64;
+        for unit in UNITS.iter() {
+            value /= 1000.0;
+            // one decimal for small values, but not if rounding makes it the next unit
+            if value < 9.95 {
+                return format!("{:.1} {}", value, unit);
+            }
+            if value < 999.5 {
+                return format!("{:.0} {}", value, unit);
+            }
+        }
+        unreachable!("u64::MAX is less than 1000 EB")
+    }
+
+    /// how many characters of the text are shown, without the ones of escape sequences for
+    /// colors
+    fn visible_len(text: &str) -> usize {
+        strip_ansi(text).chars().count()
+    }
+
+    /// the characters a box is drawn with
+    struct BoxStyle {
+        top_left: char,
+        top_right: char,
+        bottom_left: char,
+        bottom_right: char,
+        horizontal: char,
+        vertical: char,
+    }
+
+    const THIN_LINES: BoxStyle = BoxStyle {
+        top_left: '┌',
+        top_right: '┐',
+        bottom_left: '└',
+        bottom_right: '┘',
+        horizontal: '─',
+        vertical: '│',
+    };
+
+    const DOUBLE_LINES: BoxStyle = BoxStyle {
+        top_left: '╔',
+        top_right: '╗',
+        bottom_left: '╚',
+        bottom_right: '╝',
+        horizontal: '═',
+        vertical: '║',
+    };
+
+    const ASCII_LINES: BoxStyle = BoxStyle {
+        top_left: '+',
+        top_right: '+',
+        bottom_left: '+',
+        bottom_right: '+',
+        horizontal: '-',
+        vertical: '|',
+    };
+
+    const ASCII_STARS: BoxStyle = BoxStyle {
+        top_left: '*',
+        top_right: '*',
+        bottom_left: '*',
+        bottom_right: '*',
+        horizontal: '*',
+        vertical: '*',
+    };
+
+    /// draws a box around the text, with `padding` spaces to the left and right of the text
+    ///
+    /// Banners have an empty line above and below the text, and centered text.
+    fn draw_box(text: &str, style: &BoxStyle, padding: usize, banner: bool) -> String {
+        let mut lines = text.lines().collect::<Vec<_>>();
+        if lines.is_empty() {
+            lines.push("");
+        }
+        if banner {
+            lines.insert(0, "");
+            lines.push("");
+        }
+
+        let width = lines
+            .iter()
+            .map(|line| visible_len(line))
+            .max()
+            .unwrap_or(0);
+        let horizontal = style.horizontal.to_string().repeat(width + 2 * padding);
+        let padding = " ".repeat(padding);
+
+        let mut result = format!("{}{}{}\n", style.top_left, horizontal, style.top_right);
+        for line in lines {
+            let space = width - visible_len(line);
+            let (left, right) = if banner {
+                (space / 2, space - space / 2)
+            } else {
+                (0, space)
+            };
+            result += &format!(
+                "{}{}{}{}{}{}{}\n",
+                style.vertical,
+                padding,
+                " ".repeat(left),
+                line,
+                " ".repeat(right),
+                padding,
+                style.vertical
+            );
+        }
+        result += &format!(
+            "{}{}{}\n",
+            style.bottom_left, horizontal, style.bottom_right
+        );
+        result
+    }
+
+    /// whether the terminal can probably show characters like `│`
+    fn unicode_supported() -> bool {
+        // the console on Windows gets the text as UTF-16, so it can always show them
+        if cfg!(windows) {
+            return true;
+        }
+        if std::env::var_os("TERM").is_some_and(|term| term == "dumb") {
+            return false;
+        }
+        // the first of these that is set decides how characters are encoded
+        ["LC_ALL", "LC_CTYPE", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|value| !value.is_empty())
+            .map_or(true, |locale| {
+                let locale = locale.to_lowercase();
+                locale.contains("utf-8") || locale.contains("utf8")
+            })
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::{draw_box, format_bar_chart, format_hexdump, format_table, highlight_debug};
+        use super::{ASCII_LINES, ASCII_STARS, DOUBLE_LINES, THIN_LINES};
+        use crate::testing::capture_output;
+        use crate::{
+            format_bytes, print_error, print_slowly, print_warning, strip_ansi, wrap_text,
+        };
+        use std::time::Duration;
+
+        #[test]
+        fn tables() {
+            let rows = vec![vec!["Ferris", "120"], vec!["Corro", "9.5"]];
+            assert_eq!(
+                format_table(["Name", "Score"], rows),
+                "\
++--------+-------+
+| Name   | Score |
++--------+-------+
+| Ferris |   120 |
+| Corro  |   9.5 |
++--------+-------+
+"
+            );
+
+            assert_eq!(
+                format_table(["ä", "b"], [vec!["1"], vec!["x", "y", "zzz"]]),
+                "\
++---+---+-----+
+| ä | b |     |
++---+---+-----+
+| 1 |   |     |
+| x | y | zzz |
++---+---+-----+
+"
+            );
+
+            assert_eq!(
+                format_table(["Empty"], Vec::<Vec<&str>>::new()),
+                "+-------+\n| Empty |\n+-------+\n"
+            );
+
+            let table = format_table(["Status"], [["\x1b[32mok\x1b[0m"]]);
+            assert_eq!(
+                strip_ansi(&table),
+                "+--------+\n| Status |\n+--------+\n| ok     |\n+--------+\n"
+            );
+        }
+
+        #[test]
+        fn diagnostics() {
+            let output = capture_output(|| {
+                print_error("oh no");
+                print_warning(42);
+            });
+            assert_eq!(strip_ansi(&output), "error: oh no\nwarning: 42\n");
+        }
+
+        #[test]
+        fn slowly() {
+            let output = capture_output(|| print_slowly("Hällo\n", Duration::from_millis(1)));
+            assert_eq!(output, "Hällo\n");
+        }
+
+        #[test]
+        fn wrapping() {
+            assert_eq!(wrap_text("a b c d e", 3), "a b\nc d\ne");
+            assert_eq!(
+                wrap_text("  spaces   are  ignored ", 100),
+                "spaces are ignored"
+            );
+            assert_eq!(wrap_text("one\n\ntwo three", 5), "one\n\ntwo\nthree");
+            assert_eq!(wrap_text("ab abcdefgh a", 3), "ab\nabc\ndef\ngh\na");
+            assert_eq!(wrap_text("äöü äöüäöü", 3), "äöü\näöü\näöü");
+            assert_eq!(wrap_text("abc", 0), "a\nb\nc");
+            assert_eq!(wrap_text("", 10), "");
+        }
+
+        #[test]
+        fn bar_charts() {
+            assert_eq!(
+                format_bar_chart(["a", "bcd", "e", "f"], [2, 4, -1, 1], 20, '#'),
+                "\
+a   ####### 2
+bcd ############# 4
+e    -1
+f   ### 1
+"
+            );
+            assert_eq!(
+                format_bar_chart(["half", "full", "zero"], [0.5, 1.0, 0.0], 29, '#'),
+                "\
+half ########## 0.5
+full #################### 1
+zero  0
+"
+            );
+            assert_eq!(
+                format_bar_chart(["a", "b"], [1.0], 30, '#').lines().count(),
+                1
+            );
+            assert_eq!(
+                format_bar_chart(Vec::<&str>::new(), Vec::<f64>::new(), 30, '#'),
+                ""
+            );
+        }
+
+        #[test]
+        fn debug_highlighting() {
+            let text = format!(
+                "{:#?}",
+                (
+                    1.5e-7,
+                    "a \"b\" c",
+                    'x',
+                    '\'',
+                    -3,
+                    vec![Some("x2")],
+                    "",
+                    (0.25, 1)
+                )
+            );
+            assert_eq!(highlight_debug(&text, false), text);
+
+            let highlighted = highlight_debug(&text, true);
+            assert_eq!(strip_ansi(&highlighted), text);
+            for token in [
+                "1.5e-7",
+                r#""a \"b\" c""#,
+                "'x'",
+                r"'\''",
+                "-3",
+                r#""x2""#,
+                r#""""#,
+                "0.25",
+                "1",
+            ] {
+                let color = if token.starts_with(['"', '\'']) {
+                    32
+                } else {
+                    33
+                };
+                let painted = format!("\x1b[{}m{}\x1b[0m,\n", color, token);
+                assert!(
+                    highlighted.contains(&painted),
+                    "{} in {}",
+                    token,
+                    highlighted
+                );
+            }
+            assert!(highlighted.contains("Some(\n"));
+
+            assert_eq!(
+                highlight_debug("Point { x1: 2 }", true),
+                "Point { x1: \x1b[33m2\x1b[0m }"
+            );
+            assert_eq!(
+                highlight_debug("\"unclosed", true),
+                "\x1b[32m\"unclosed\x1b[0m"
+            );
+        }
+
+        #[test]
+        fn hexdumps() {
+            assert_eq!(
+                format_hexdump(b"Hello, World!\n\x00\x01\x02 and more\xff"),
+                "\
+00000000  48 65 6c 6c 6f 2c 20 57  6f 72 6c 64 21 0a 00 01  |Hello, World!...|
+00000010  02 20 61 6e 64 20 6d 6f  72 65 ff                 |. and more.|
+"
+            );
+            assert_eq!(
+                format_hexdump(&[0x41; 8]),
+                "00000000  41 41 41 41 41 41 41 41                           |AAAAAAAA|\n"
+            );
+            assert_eq!(format_hexdump(&[]), "");
+            assert!(format_hexdump(&[0; 33])
+                .lines()
+                .all(|line| line.find('|') == Some(60)));
+        }
+
+        #[test]
+        fn boxes() {
+            assert_eq!(
+                draw_box("Hello\nWörld!", &THIN_LINES, 1, false),
+                "\
+┌────────┐
+│ Hello  │
+│ Wörld! │
+└────────┘
+"
+            );
+            assert_eq!(draw_box("", &ASCII_LINES, 1, false), "+--+\n|  |\n+--+\n");
+            assert_eq!(
+                draw_box("Title\nis long", &DOUBLE_LINES, 3, true),
+                "\
+╔═════════════╗
+║             ║
+║    Title    ║
+║   is long   ║
+║             ║
+╚═════════════╝
+"
+            );
+            assert_eq!(
+                draw_box("ab", &ASCII_STARS, 3, true),
+                "\
+**********
+*        *
+*   ab   *
+*        *
+**********
+"
+            );
+        }
+
+        #[test]
+        fn bytes() {
+            assert_eq!(format_bytes(0), "0 B");
+            assert_eq!(format_bytes(999), "999 B");
+            assert_eq!(format_bytes(1000), "1.0 kB");
+            assert_eq!(format_bytes(9_949), "9.9 kB");
+            assert_eq!(format_bytes(9_950), "10 kB");
+            assert_eq!(format_bytes(999_499), "999 kB");
+            assert_eq!(format_bytes(999_500), "1.0 MB");
+            assert_eq!(format_bytes(1_400_000), "1.4 MB");
+            assert_eq!(format_bytes(u64::MAX), "18 EB");
+        }
+    }
+}
+
+mod progress {
+    use crate::time::format_duration;
+    use std::io::IsTerminal;
+    use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+    use std::thread::JoinHandle;
+    use std::time::{Duration, Instant};
+
+    /// how many characters the bar of a [`ProgressBar`] is wide, if the terminal is wide enough
+    const BAR_WIDTH: usize = 30;
+    const MIN_BAR_WIDTH: usize = 5;
+    /// how often the line is redrawn at most if only the ETA changed
+    const REDRAW_INTERVAL: Duration = Duration::from_millis(200);
+
+    ///
+    /// A bar that shows how much of a long task is done, with the percentage and how long the
+    /// rest will take
+    ///
+    /// It is drawn on one line that is updated in place, so nothing else should be printed
+    /// until it is finished.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::ProgressBar;
+    ///
+    /// let mut bar = ProgressBar::new(1000);
+    /// for _ in 0..1000 {
+    ///     // do some work
+    ///     bar.inc();
+    /// }
+    /// bar.finish();
+    /// ```
+    ///
+    /// This looks like this while it is running:
+    ///
+    /// ```text
+    /// [=================>            ]  60% (600/1000) ETA 2s
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// Drawing things in the terminal is not something that every program needs, so it is left
+    /// to crates like `indicatif`.
+    #[derive(Debug)]
+    pub struct ProgressBar {
+        total: u64,
+        position: u64,
+        started: Instant,
+        last_draw: Option<(Instant, u64)>,
+        last_len: usize,
+        finished: bool,
+    }
+
+    impl ProgressBar {
+        ///
+        /// Creates a progress bar for a task with `total` steps and draws it
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::ProgressBar;
+        ///
+        /// let files = ["a.txt", "b.txt", "c.txt"];
+        /// let mut bar = ProgressBar::new(files.len() as u64);
+        /// ```
+        pub fn new(total: u64) -> Self {
+            let mut bar = ProgressBar {
+                total,
+                position: 0,
+                started: Instant::now(),
+                last_draw: None,
+                last_len: 0,
+                finished: false,
+            };
+            bar.draw();
+            bar
+        }
+
+        /// Adds one finished step
+        pub fn inc(&mut self) {
+            self.set(self.position.saturating_add(1));
+        }
+
+        /// Sets how many steps are finished, at most the total
+        pub fn set(&mut self, position: u64) {
+            self.position = position.min(self.total);
+            self.draw();
+        }
+
+        /// Returns how many steps are finished
+        pub fn position(&self) -> u64 {
+            self.position
+        }
+
+        /// Returns how many steps there are
+        pub fn total(&self) -> u64 {
+            self.total
+        }
+
+        ///
+        /// Fills the bar and moves to the next line, so that other things can be printed again
+        ///
+        /// If the bar is dropped without this, it moves to the next line too, but stays how it
+        /// was.
+        pub fn finish(mut self) {
+            self.position = self.total;
+            self.finished = true;
+            self.last_draw = None;
+            self.draw();
+            outputln!();
+        }
+
+        /// redraws the line, unless that was done very recently and the percentage is the same
+        fn draw(&mut self) {
+            let now = Instant::now();
+            let percent = percent(self.position, self.total);
+            if let Some((time, last_percent)) = self.last_draw {
+                if last_percent == percent && now.duration_since(time) < REDRAW_INTERVAL {
+                    return;
+                }
+            }
+            self.last_draw = Some((now, percent));
+
+            let line = render(
+                self.position,
+                self.total,
+                self.started.elapsed(),
+                self.finished,
+                // the last column is left free, as some terminals go to the next line after it
+                crate::terminal::line_width().saturating_sub(1),
+            );
+            let len = line.chars().count();
+            // spaces to overwrite the end of a longer previous line
+            let padding = self.last_len.saturating_sub(len);
+            self.last_len = len;
+            output!("\r{}{}", line, " ".repeat(padding));
+        }
+    }
+
+    impl Drop for ProgressBar {
+        fn drop(&mut self) {
+            if !self.finished {
+                outputln!();
+            }
+        }
+    }
+
+    fn percent(position: u64, total: u64) -> u64 {
+        if total == 0 {
+            100
+        } else {
+            (u128::from(position) * 100 / u128::from(total)) as u64
+        }
+    }
+
+    /// the line of a progress bar, without the `\r`, with a bar that fits into `width`
+    fn render(
+        position: u64,
+        total: u64,
+        elapsed: Duration,
+        finished: bool,
+        width: usize,
+    ) -> String {
+        let time = if finished {
+            format!("in {}", format_duration(elapsed))
+        } else if position == 0 {
+            "ETA ?".to_owned()
+        } else {
+            // assume that all the remaining steps take as long as the ones so far
+            let remaining = elapsed.as_secs_f64() * (total - position) as f64 / position as f64;
+            // it can be too big for a `Duration` with a huge total
+            match Duration::try_from_secs_f64(remaining) {
+                Ok(remaining) => format!("ETA {}", format_duration(remaining)),
+                Err(_) => "ETA ?".to_owned(),
+            }
+        };
+        let info = format!(
+            "{:>3}% ({}/{}) {}",
+            percent(position, total),
+            position,
+            total,
+            time
+        );
+
+        // the brackets and the space between the bar and the info need 3 characters
+        let bar_width = width
+            .saturating_sub(info.chars().count() + 3)
+            .clamp(MIN_BAR_WIDTH, BAR_WIDTH);
+        let filled = if total == 0 {
+            bar_width
+        } else {
+            (u128::from(position) * bar_width as u128 / u128::from(total)) as usize
+        };
+        let bar = if filled == bar_width {
+            "=".repeat(bar_width)
+        } else {
+            format!(
+                "{}>{}",
+                "=".repeat(filled),
+                " ".repeat(bar_width - filled - 1)
+            )
+        };
+
+        format!("[{}] {}", bar, info)
+    }
+
+    ///
+    /// A line that shows what is happening at the moment, and is replaced every time it is
+    /// updated instead of printing a new line
+    ///
+    /// The line is removed when the status is dropped. Nothing else should be printed while it
+    /// is shown, as that would end up on the same line.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::Status;
+    ///
+    /// let mut status = Status::new();
+    /// for i in 1..=1000 {
+    ///     status.update(format!("processing item {}", i));
+    /// }
+    /// drop(status);
+    ///
+    /// println!("All items processed");
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`ProgressBar`]
+    #[derive(Debug, Default)]
+    pub struct Status {
+        len: usize,
+    }
+
+    impl Status {
+        /// Creates a status that isn't shown until [`Status::update`] is called
+        pub fn new() -> Self {
+            Status { len: 0 }
+        }
+
+        ///
+        /// Replaces the line with the text
+        ///
+        /// Text that doesn't fit into the line of the terminal is cut off, and only the first line
+        /// of it is shown.
+        pub fn update(&mut self, text: impl std::fmt::Display) {
+            let text = text.to_string();
+            let max_len = crate::terminal::line_width().saturating_sub(1);
+            let line = text
+                .lines()
+                .next()
+                .unwrap_or("")
+                .chars()
+                .take(max_len)
+                .collect::<String>();
+
+            let len = line.chars().count();
+            output!("\r{}{}", line, self.clear_rest(len));
+            self.len = len;
+        }
+
+        /// what is needed to remove the rest of the previous line after `len` characters
+        fn clear_rest(&self, len: usize) -> String {
+            if crate::io::capturing_output() || !crate::sys::enable_escape_sequences() {
+                // the old console on Windows doesn't know the escape sequence, and captured
+                // output shouldn't depend on the terminal
+                let padding = self.len.saturating_sub(len);
+                format!("{}{}", " ".repeat(padding), "\x08".repeat(padding))
+            } else {
+                "\x1b[K".to_owned()
+            }
+        }
+    }
+
+    impl Drop for Status {
+        fn drop(&mut self) {
+            if self.len > 0 {
+                output!("\r{}", self.clear_rest(0));
+            }
+        }
+    }
+
+    const SPINNER_FRAMES: [char; 4] = ['|', '/', '-', '\\'];
+    const SPINNER_INTERVAL: Duration = Duration::from_millis(100);
+
+    ///
+    /// An animation that shows that something is happening, for tasks where it isn't known how
+    /// long they take, see [`ProgressBar`] for the others
+    ///
+    /// The animation runs on its own thread, so nothing else should be printed until it is
+    /// stopped. If the output doesn't go to a terminal, there is no animation and only the
+    /// message and the result are printed.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::Spinner;
+    ///
+    /// let spinner = Spinner::start("Loading...");
+    /// std::thread::sleep(std::time::Duration::from_millis(300));
+    /// spinner.stop_with("done");
+    /// ```
+    ///
+    /// This shows a turning `|` in front of `Loading...` and then stays as:
+    ///
+    /// ```text
+    /// Loading... done
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`ProgressBar`]
+    #[derive(Debug)]
+    pub struct Spinner {
+        message: String,
+        animation: Option<(Sender<()>, JoinHandle<()>)>,
+        stopped: bool,
+    }
+
+    impl Spinner {
+        ///
+        /// Starts the animation in front of the message
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::Spinner;
+        ///
+        /// let spinner = Spinner::start("Downloading the list of words");
+        /// ```
+        pub fn start(message: &str) -> Self {
+            let message = message.to_owned();
+            let animated = !crate::io::capturing_output() && std::io::stdout().is_terminal();
+
+            let animation = if animated {
+                let (stop, stopped) = mpsc::channel();
+                let thread_message = message.clone();
+                let thread = std::thread::spawn(move || {
+                    for frame in SPINNER_FRAMES.iter().cycle() {
+                        output!("\r{} {}", frame, thread_message);
+                        match stopped.recv_timeout(SPINNER_INTERVAL) {
+                            Err(RecvTimeoutError::Timeout) => {}
+                            _ => return,
+                        }
+                    }
+                });
+                Some((stop, thread))
+            } else {
+                output!("{}", message);
+                None
+            };
+
+            Spinner {
+                message,
+                animation,
+                stopped: false,
+            }
+        }
+
+        /// Stops the animation and replaces it with the message, followed by `result`
+        pub fn stop_with(mut self, result: &str) {
+            let line = self.stop();
+            outputln!("{} {}", line, result);
+        }
+
+        /// stops the thread if there is one, returning what is needed to replace its line with
+        /// the message
+        fn stop(&mut self) -> String {
+            self.stopped = true;
+            match self.animation.take() {
+                Some((stop, thread)) => {
+                    let _ = stop.send(());
+                    let _ = thread.join();
+                    // the frame and its space in front of the message are overwritten as well
+                    let blank = " ".repeat(self.message.chars().count() + 2);
+                    format!("\r{}\r{}", blank, self.message)
+                }
+                // the message was already printed
+                None => String::new(),
+            }
+        }
+    }
+
+    impl Drop for Spinner {
+        fn drop(&mut self) {
+            if !self.stopped {
+                let line = self.stop();
+                outputln!("{}", line);
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::render;
+        use crate::testing::capture_output;
+        use crate::{ProgressBar, Spinner, Status};
+        use std::time::Duration;
+
+        #[test]
+        fn rendering() {
+            let secs = Duration::from_secs;
+            assert_eq!(
+                render(0, 10, secs(0), false, 80),
+                "[>                             ]   0% (0/10) ETA ?"
+            );
+            assert_eq!(
+                render(3, 10, secs(6), false, 80),
+                "[=========>                    ]  30% (3/10) ETA 14s"
+            );
+            assert_eq!(
+                render(10, 10, secs(75), true, 80),
+                "[==============================] 100% (10/10) in 1m 15s"
+            );
+            assert_eq!(
+                render(0, 0, secs(0), true, 80),
+                "[==============================] 100% (0/0) in 0ns"
+            );
+            assert_eq!(
+                render(1, 2, secs(1), false, 32),
+                "[======>     ]  50% (1/2) ETA 1s"
+            );
+            assert_eq!(render(1, 2, secs(1), false, 0), "[==>  ]  50% (1/2) ETA 1s");
+            assert!(render(1, u64::MAX, secs(1_000_000), false, 80).ends_with(" ETA ?"));
+        }
+
+        #[test]
+        fn drawing() {
+            let output = capture_output(|| {
+                let mut bar = ProgressBar::new(4);
+                bar.inc();
+                bar.set(100);
+                assert_eq!(bar.position(), 4);
+                bar.finish();
+            });
+            assert!(output.starts_with("\r[>"));
+            assert!(output.contains("\r[=======>                      ]  25% (1/4) ETA"));
+            assert!(output.contains(" 100% (4/4) in "));
+            assert!(output.ends_with('\n'));
+
+            let output = capture_output(|| {
+                ProgressBar::new(4);
+            });
+            assert!(output.ends_with('\n'));
+        }
+
+        #[test]
+        fn status_line() {
+            let output = capture_output(|| {
+                let mut status = Status::new();
+                status.update("processing item 9");
+                status.update(format_args!("processing item {}", 10));
+                status.update("done\nignored");
+            });
+            assert_eq!(
+                output,
+                "\rprocessing item 9\rprocessing item 10\rdone              \
+                 \x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\r    \x08\x08\x08\x08"
+            );
+
+            assert_eq!(capture_output(|| drop(Status::new())), "");
+        }
+
+        #[test]
+        fn spinner_without_terminal() {
+            let output = capture_output(|| Spinner::start("Loading...").stop_with("done"));
+            assert_eq!(output, "Loading... done\n");
+
+            let output = capture_output(|| {
+                Spinner::start("Thinking...");
+            });
+            assert_eq!(output, "Thinking...\n");
+        }
+    }
+}
+
+mod fs {
+    use std::collections::HashMap;
+    use std::fmt::{Display, Formatter, Write};
+    use std::io::{ErrorKind, Write as _};
+    use std::path::{Path, PathBuf};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+    use std::thread::JoinHandle;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    ///
+    /// Reads the whole file as text
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::read_file;
+    ///
+    /// let story = read_file("story.txt");
+    /// println!("The story has {} words", story.split_whitespace().count());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the file can't be read, with a message that says which file it is and why,
+    /// see [`try_read_file`].
+    ///
+    /// # Why is this not in std?
+    ///
+    /// It is, as `std::fs::read_to_string`, but its errors don't say which file couldn't be
+    /// read, which makes them hard to understand.
+    pub fn read_file(path: impl AsRef<Path>) -> String {
+        try_read_file(path).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    ///
+    /// Reads the whole file as text like [`read_file`], but returns an error instead of
+    /// panicking
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::try_read_file;
+    ///
+    /// match try_read_file("highscore.txt") {
+    ///     Ok(highscore) => println!("The highscore is {}", highscore.trim()),
+    ///     Err(err) => println!("There is no highscore yet ({})", err),
+    /// }
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`read_file`]
+    pub fn try_read_file(path: impl AsRef<Path>) -> Result<String, FileError> {
+        let path = path.as_ref();
+        std::fs::read_to_string(path).map_err(|err| FileError::new("read", path, err))
+    }
+
+    ///
+    /// Reads the file as text and returns its lines, without the line breaks at their end
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::{choose, read_lines};
+    ///
+    /// let words = read_lines("words.txt");
+    /// let word = choose(&words).expect("the word list is empty");
+    /// println!("Guess the word with {} letters", word.len());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the file can't be read, see [`read_file`].
+    ///
+    /// # Why is this not in std?
+    ///
+    /// `std::fs::read_to_string(path)?.lines()` does the same, but the lines borrow the
+    /// string that they are part of, so it can't be dropped while they are used.
+    pub fn read_lines(path: impl AsRef<Path>) -> Vec<String> {
+        try_read_lines(path).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    ///
+    /// Reads the lines of the file like [`read_lines`], but returns an error instead of
+    /// panicking
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::try_read_lines;
+    ///
+    /// let todos = try_read_lines("todo.txt").unwrap_or_default();
+    /// for todo in todos {
+    ///     println!("- {}", todo);
+    /// }
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`read_lines`]
+    pub fn try_read_lines(path: impl AsRef<Path>) -> Result<Vec<String>, FileError> {
+        let text = try_read_file(path)?;
+        Ok(text.lines().map(str::to_owned).collect())
+    }
+
+    ///
+    /// Reads the whole file as bytes, for files that aren't text like images
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::{hexdump, read_bytes};
+    ///
+    /// let image = read_bytes("ferris.png");
+    /// // the first bytes say which kind of file it is
+    /// hexdump(&image[..16]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the file can't be read, see [`read_file`].
+    ///
+    /// # Why is this not in std?
+    ///
+    /// It is, as `std::fs::read`, see [`read_file`]
+    pub fn read_bytes(path: impl AsRef<Path>) -> Vec<u8> {
+        try_read_bytes(path).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    ///
+    /// Reads the whole file as bytes like [`read_bytes`], but returns an error instead of
+    /// panicking
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::try_read_bytes;
+    ///
+    /// match try_read_bytes("save.bin") {
+    ///     Ok(save) => println!("Loaded {} bytes", save.len()),
+    ///     Err(err) => println!("Starting a new game ({})", err),
+    /// }
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`read_file`]
+    pub fn try_read_bytes(path: impl AsRef<Path>) -> Result<Vec<u8>, FileError> {
+        let path = path.as_ref();
+        std::fs::read(path).map_err(|err| FileError::new("read", path, err))
+    }
+
+    ///
+    /// Writes the bytes to the file, replacing what was in it
+    ///
+    /// The file is created if it doesn't exist yet.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::{random_bytes, write_bytes};
+    ///
+    /// write_bytes("key.bin", &random_bytes(32));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the file can't be written, with a message that says which file it is and why.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// It is, as `std::fs::write`, see [`read_file`]
+    pub fn write_bytes(path: impl AsRef<Path>, bytes: &[u8]) {
+        let path = path.as_ref();
+        if let Err(err) = std::fs::write(path, bytes) {
+            panic!("{}", FileError::new("write", path, err));
+        }
+    }
+
+    ///
+    /// Returns whether there is a file at the path
+    ///
+    /// Directories are not files, see [`dir_exists`] for them.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::{file_exists, read_file};
+    ///
+    /// if file_exists("save.txt") {
+    ///     let save = read_file("save.txt");
+    /// } else {
+    ///     println!("Starting a new game");
+    /// }
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// It is, as `Path::is_file`, but it's hard to find for such a simple thing.
+    pub fn file_exists(path: impl AsRef<Path>) -> bool {
+        path.as_ref().is_file()
+    }
+
+    ///
+    /// Returns whether there is a directory at the path
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::dir_exists;
+    ///
+    /// if !dir_exists("saves") {
+    ///     println!("There are no saved games yet");
+    /// }
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// It is, as `Path::is_dir`, see [`file_exists`]
+    pub fn dir_exists(path: impl AsRef<Path>) -> bool {
+        path.as_ref().is_dir()
+    }
+
+    ///
+    /// Creates the directory if it doesn't exist yet, together with all the directories it is
+    /// in that don't exist either
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::ensure_dir;
+    ///
+    /// ensure_dir("saves/2024");
+    /// std::fs::write("saves/2024/game.txt", "level 3").unwrap();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the directory can't be created, for example because there already is a file
+    /// with that name.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// It is, as `std::fs::create_dir_all`, but its errors don't say which directory couldn't
+    /// be created, see [`read_file`].
+    pub fn ensure_dir(path: impl AsRef<Path>) {
+        let path = path.as_ref();
+        if let Err(err) = std::fs::create_dir_all(path) {
+            panic!("{}", FileError::new("create the directory", path, err));
+        }
+    }
+
+    ///
+    /// Returns how many bytes the file has
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::{file_size, format_bytes};
+    ///
+    /// println!("The save file has {}", format_bytes(file_size("save.json")));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the file doesn't exist or can't be accessed, see [`read_file`].
+    ///
+    /// # Why is this not in std?
+    ///
+    /// It is, as `std::fs::metadata(path)?.len()`, but that's hard to find.
+    pub fn file_size(path: impl AsRef<Path>) -> u64 {
+        let path = path.as_ref();
+        match std::fs::metadata(path) {
+            Ok(metadata) => metadata.len(),
+            Err(err) => panic!("{}", FileError::new("get the size of", path, err)),
+        }
+    }
+
+    ///
+    /// Returns the names of the files and directories in the directory, sorted alphabetically
+    ///
+    /// See [`DirListing`] for only listing files or directories, or also the ones in the
+    /// directories inside of it.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::list_dir;
+    ///
+    /// for name in list_dir("saves") {
+    ///     println!("Found the saved game {}", name);
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the directory can't be read, for example because it doesn't exist.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// `std::fs::read_dir` can fail for the directory and for every single entry, and the
+    /// names aren't necessarily valid UTF-8, so using it needs a lot of error handling.
+    pub fn list_dir(path: impl AsRef<Path>) -> Vec<String> {
+        DirListing::new(path).list()
+    }
+
+    ///
+    /// Lists the contents of a directory like [`list_dir`], with more options
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::DirListing;
+    ///
+    /// let files = DirListing::new("src").files_only().recursive().list();
+    /// // something like ["lib.rs", "game/board.rs", "game/mod.rs"]
+    /// println!("{:?}", files);
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`list_dir`]
+    #[derive(Debug, Clone)]
+    pub struct DirListing {
+        path: PathBuf,
+        files: bool,
+        dirs: bool,
+        recursive: bool,
+    }
+
+    impl DirListing {
+        /// Creates a listing of everything directly inside the directory
+        pub fn new(path: impl AsRef<Path>) -> Self {
+            DirListing {
+                path: path.as_ref().to_owned(),
+                files: true,
+                dirs: true,
+                recursive: false,
+            }
+        }
+
+        /// Only lists files, no directories
+        pub fn files_only(mut self) -> Self {
+            self.files = true;
+            self.dirs = false;
+            self
+        }
+
+        /// Only lists directories, no files
+        pub fn dirs_only(mut self) -> Self {
+            self.files = false;
+            self.dirs = true;
+            self
+        }
+
+        /// Also lists everything in the directories inside of it, with names like `dir/file.txt`
+        pub fn recursive(mut self) -> Self {
+            self.recursive = true;
+            self
+        }
+
+        ///
+        /// Returns the names, sorted alphabetically
+        ///
+        /// # Panics
+        ///
+        /// Panics if a directory can't be read, see [`DirListing::try_list`].
+        pub fn list(&self) -> Vec<String> {
+            self.try_list().unwrap_or_else(|err| panic!("{}", err))
+        }
+
+        /// Returns the names, or an error if a directory can't be read
+        pub fn try_list(&self) -> Result<Vec<String>, FileError> {
+            let mut names = Vec::new();
+            self.add_entries(&self.path, Path::new(""), &mut names)?;
+            names.sort();
+            Ok(names)
+        }
+
+        /// adds the names of the entries of `dir`, which is called `prefix` in the listing
+        fn add_entries(
+            &self,
+            dir: &Path,
+            prefix: &Path,
+            names: &mut Vec<String>,
+        ) -> Result<(), FileError> {
+            let error = |err| FileError::new("list the directory", dir, err);
+
+            for entry in std::fs::read_dir(dir).map_err(error)? {
+                let entry = entry.map_err(error)?;
+                let name = prefix.join(entry.file_name());
+                // symbolic links are listed like what they point to, but not followed to avoid
+                // going around in circles
+                let is_dir = entry.path().is_dir();
+                let is_link = entry.file_type().map_err(error)?.is_symlink();
+
+                if (is_dir && self.dirs) || (!is_dir && self.files) {
+                    names.push(name.to_string_lossy().into_owned());
+                }
+                if is_dir && !is_link && self.recursive {
+                    self.add_entries(&entry.path(), &name, names)?;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    ///
+    /// Returns the paths of all files in the directory and the directories inside of it that
+    /// match the pattern, sorted alphabetically
+    ///
+    /// In the pattern, `*` stands for any number of characters and `?` for exactly one
+    /// character. If the pattern contains a `/`, it is compared to the path inside of the
+    /// directory, otherwise only to the file name, so `*.txt` finds all text files and
+    /// `levels/*.txt` only the ones in `levels`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::{find_files, read_lines};
+    ///
+    /// for path in find_files(".", "*.txt") {
+    ///     println!("{} has {} lines", path, read_lines(&path).len());
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a directory can't be read, see [`list_dir`].
+    ///
+    /// # Why is this not in std?
+    ///
+    /// Patterns like these are mostly used by shells, `std` only has [`std::fs::read_dir`] to
+    /// go through directories.
+    pub fn find_files(dir: impl AsRef<Path>, pattern: &str) -> Vec<String> {
+        let dir = dir.as_ref();
+        let pattern = pattern.chars().collect::<Vec<_>>();
+        let whole_path = pattern.contains(&'/');
+
+        DirListing::new(dir)
+            .files_only()
+            .recursive()
+            .list()
+            .into_iter()
+            .filter(|name| {
+                // the names contain `\` on Windows
+                let name = name.replace(std::path::MAIN_SEPARATOR, "/");
+                let name = if whole_path {
+                    &name
+                } else {
+                    name.rsplit('/').next().unwrap_or_default()
+                };
+                matches_pattern(&pattern, &name.chars().collect::<Vec<_>>())
+            })
+            .map(|name| dir.join(name).to_string_lossy().into_owned())
+            .collect()
+    }
+
+    /// whether the text matches the pattern of [`find_files`], where `*` doesn't match `/`
+    fn matches_pattern(pattern: &[char], text: &[char]) -> bool {
+        match pattern.split_first() {
+            None => text.is_empty(),
+            Some(('*', rest)) => {
+                // try every length of the text that the star could stand for
+                let max = text.iter().position(|&c| c == '/').unwrap_or(text.len());
+                (0..=max).any(|len| matches_pattern(rest, &text[len..]))
+            }
+            Some((&expected, rest)) => match text.split_first() {
+                Some((&c, text)) if c == expected || (expected == '?' && c != '/') => {
+                    matches_pattern(rest, text)
+                }
+                _ => false,
+            },
+        }
+    }
+
+    ///
+    /// Copies the directory with everything in it to `to`, and returns how many files were
+    /// copied
+    ///
+    /// Files that are already at `to` are overwritten. Symbolic links are copied as the files
+    /// they point to, links to directories are left out.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::copy_dir;
+    ///
+    /// let count = copy_dir("saves", "backup/saves");
+    /// println!("Copied {} files", count);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if something can't be read or written, or if `to` is inside of the directory,
+    /// which would copy the copy again forever.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// `std` only has `std::fs::copy` for single files, as there are many ways to copy a
+    /// directory, for example what to do with links or files that are already there.
+    pub fn copy_dir(from: impl AsRef<Path>, to: impl AsRef<Path>) -> usize {
+        let (from, to) = (from.as_ref(), to.as_ref());
+
+        // everything is checked before `to` is created, so nothing is left behind if it fails
+        let canonical_from = from
+            .canonicalize()
+            .unwrap_or_else(|err| panic!("{}", FileError::new("copy", from, err)));
+        if canonical_target(to).starts_with(&canonical_from) {
+            panic!(
+                "could not copy `{}` to `{}`: it is inside of the directory",
+                from.display(),
+                to.display()
+            );
+        }
+
+        ensure_dir(to);
+        copy_entries(from, to).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// the canonical path of `path`, which doesn't have to exist yet, by canonicalizing the
+    /// closest parent that exists
+    fn canonical_target(path: &Path) -> PathBuf {
+        let absolute = std::env::current_dir()
+            .map(|dir| dir.join(path))
+            .unwrap_or_else(|_| path.to_owned());
+        absolute
+            .ancestors()
+            .find_map(|ancestor| {
+                let canonical = ancestor.canonicalize().ok()?;
+                let rest = absolute.strip_prefix(ancestor).ok()?;
+                Some(canonical.join(rest))
+            })
+            .unwrap_or(absolute)
+    }
+
+    fn copy_entries(from: &Path, to: &Path) -> Result<usize, FileError> {
+        let mut count = 0;
+        let list_error = |err| FileError::new("list the directory", from, err);
+
+        for entry in std::fs::read_dir(from).map_err(list_error)? {
+            let entry = entry.map_err(list_error)?;
+            let (source, target) = (entry.path(), to.join(entry.file_name()));
+
+            if entry.file_type().map_err(list_error)?.is_dir() {
+                std::fs::create_dir_all(&target)
+                    .map_err(|err| FileError::new("create the directory", &target, err))?;
+                count += copy_entries(&source, &target)?;
+            } else if !source.is_dir() {
+                std::fs::copy(&source, &target)
+                    .map_err(|err| FileError::new("copy", &source, err))?;
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    ///
+    /// Deletes the directory with everything in it, and returns the paths of everything that
+    /// was deleted
+    ///
+    /// If `dry_run` is `true`, nothing is deleted, but the paths that would be are returned, so
+    /// that they can be shown before asking whether they should really be deleted. It refuses
+    /// to delete directories where that is most likely a mistake, like the home directory, or
+    /// one that the program is running in.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::{confirm, remove_dir_all_safe};
+    ///
+    /// for path in remove_dir_all_safe("old-saves", true) {
+    ///     println!("{}", path);
+    /// }
+    /// if confirm("Delete all of these?") {
+    ///     remove_dir_all_safe("old-saves", false);
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the directory can't be read or deleted, or if it is one of the directories
+    /// that it refuses to delete.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// It is, as `std::fs::remove_dir_all`, which deletes anything without asking. Deciding
+    /// which directories are too important to delete is up to the program.
+    pub fn remove_dir_all_safe(path: impl AsRef<Path>, dry_run: bool) -> Vec<String> {
+        let path = path.as_ref();
+        let canonical = path
+            .canonicalize()
+            .unwrap_or_else(|err| panic!("{}", FileError::new("delete", path, err)));
+
+        let home = try_home_dir().and_then(|home| home.canonicalize().ok());
+        let current = std::env::current_dir().and_then(|dir| dir.canonicalize());
+        let reason = if canonical.parent().is_none() {
+            Some("it is the root of the file system")
+        } else if home.as_ref() == Some(&canonical) {
+            Some("it is the home directory")
+        } else if current.is_ok_and(|current| current.starts_with(&canonical)) {
+            Some("the program is running in it")
+        } else if !canonical.is_dir() {
+            Some("it is not a directory")
+        } else {
+            None
+        };
+        if let Some(reason) = reason {
+            panic!("refusing to delete `{}`: {}", path.display(), reason);
+        }
+
+        let mut paths = DirListing::new(path)
+            .recursive()
+            .list()
+            .into_iter()
+            .map(|name| path.join(name).to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        paths.push(path.to_string_lossy().into_owned());
+
+        if !dry_run {
+            if let Err(err) = std::fs::remove_dir_all(path) {
+                panic!("{}", FileError::new("delete", path, err));
+            }
+        }
+        paths
+    }
+
+    /// the environment variable that contains the home directory
+    const HOME_VAR: &str = if cfg!(windows) { "USERPROFILE" } else { "HOME" };
+
+    ///
+    /// Returns the home directory of the user, like `/home/ferris` or `C:\Users\Ferris`
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::home_dir;
+    ///
+    /// let notes = home_dir().join("notes.txt");
+    /// println!("Your notes are saved in {}", notes.display());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the home directory isn't known, because the `HOME` environment variable, or
+    /// `USERPROFILE` on Windows, isn't set.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// It is, as `std::env::home_dir`, which was deprecated for a long time because it returned
+    /// wrong directories on Windows, and still returns an `Option`.
+    pub fn home_dir() -> PathBuf {
+        try_home_dir().unwrap_or_else(|| {
+            panic!(
+                "could not find the home directory, because `{}` is not set",
+                HOME_VAR
+            )
+        })
+    }
+
+    fn try_home_dir() -> Option<PathBuf> {
+        dir_from_env(HOME_VAR)
+    }
+
+    /// the directory in the environment variable, if it is set to an absolute path
+    fn dir_from_env(var: &str) -> Option<PathBuf> {
+        let dir = PathBuf::from(std::env::var_os(var)?);
+        Some(dir).filter(|dir| dir.is_absolute())
+    }
+
+    ///
+    /// Returns the directory where programs store their settings, in which a program should
+    /// create its own directory
+    ///
+    /// It is `%APPDATA%` on Windows, `~/Library/Application Support` on macOS and
+    /// `~/.config` (or `$XDG_CONFIG_HOME`) on Linux and other systems.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::{config_dir, ensure_dir, write_config};
+    ///
+    /// let dir = config_dir().join("snake");
+    /// ensure_dir(&dir);
+    /// write_config(dir.join("settings.cfg"), [("speed", "fast")]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the home directory isn't known, see [`home_dir`].
+    ///
+    /// # Why is this not in std?
+    ///
+    /// The directories are different on every system and change sometimes, which is left to
+    /// crates like `dirs`.
+    pub fn config_dir() -> PathBuf {
+        if cfg!(windows) {
+            dir_from_env("APPDATA").unwrap_or_else(|| home_dir().join("AppData").join("Roaming"))
+        } else if cfg!(target_os = "macos") {
+            home_dir().join("Library").join("Application Support")
+        } else {
+            dir_from_env("XDG_CONFIG_HOME").unwrap_or_else(|| home_dir().join(".config"))
+        }
+    }
+
+    ///
+    /// Returns the directory where programs store their data, like saved games, in which a
+    /// program should create its own directory
+    ///
+    /// It is `%APPDATA%` on Windows, `~/Library/Application Support` on macOS and
+    /// `~/.local/share` (or `$XDG_DATA_HOME`) on Linux and other systems.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::{data_dir, ensure_dir};
+    ///
+    /// let saves = data_dir().join("snake").join("saves");
+    /// ensure_dir(&saves);
+    /// std::fs::write(saves.join("1.txt"), "level 3").unwrap();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the home directory isn't known, see [`home_dir`].
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`config_dir`]
+    pub fn data_dir() -> PathBuf {
+        if cfg!(windows) {
+            dir_from_env("APPDATA").unwrap_or_else(|| home_dir().join("AppData").join("Roaming"))
+        } else if cfg!(target_os = "macos") {
+            home_dir().join("Library").join("Application Support")
+        } else {
+            dir_from_env("XDG_DATA_HOME").unwrap_or_else(|| home_dir().join(".local").join("share"))
+        }
+    }
+
+    ///
+    /// Reads a CSV file, where every line is a row and the cells are separated by commas
+    ///
+    /// Cells can be in double quotes, to contain commas, line breaks or double quotes, which
+    /// are written twice inside of them (`"say ""hi"""`). Empty lines are skipped.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::read_csv;
+    ///
+    /// // name,points
+    /// // Ferris,120
+    /// // "Corro, the Unsafe",95
+    /// let rows = read_csv("scores.csv");
+    /// for row in &rows[1..] {
+    ///     println!("{} has {} points", row[0], row[1]);
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the file can't be read, see [`read_file`].
+    ///
+    /// # Why is this not in std?
+    ///
+    /// There are many slightly different kinds of CSV, and crates like `csv` handle all of them.
+    pub fn read_csv(path: impl AsRef<Path>) -> Vec<Vec<String>> {
+        parse_csv(&read_file(path))
+    }
+
+    fn parse_csv(text: &str) -> Vec<Vec<String>> {
+        let mut rows = Vec::new();
+        let mut row = Vec::new();
+        let mut cell = String::new();
+        let mut chars = text.chars().peekable();
+        // whether something was read for the current row, so that empty lines are skipped
+        let mut row_started = false;
+
+        while let Some(char) = chars.next() {
+            match char {
+                '"' => {
+                    row_started = true;
+                    while let Some(char) = chars.next() {
+                        match char {
+                            '"' if chars.peek() == Some(&'"') => {
+                                chars.next();
+                                cell.push('"');
+                            }
+                            '"' => break,
+                            _ => cell.push(char),
+                        }
+                    }
+                }
+                ',' => {
+                    row_started = true;
+                    row.push(std::mem::take(&mut cell));
+                }
+                '\n' | '\r' => {
+                    if char == '\r' && chars.peek() == Some(&'\n') {
+                        chars.next();
+                    }
+                    if row_started {
+                        row.push(std::mem::take(&mut cell));
+                        rows.push(std::mem::take(&mut row));
+                        row_started = false;
+                    }
+                }
+                _ => {
+                    row_started = true;
+                    cell.push(char);
+                }
+            }
+        }
+        if row_started {
+            row.push(cell);
+            rows.push(row);
+        }
+
+        rows
+    }
+
+    ///
+    /// Writes the rows to a CSV file, see [`read_csv`]
+    ///
+    /// Cells that contain commas, line breaks or double quotes are put into double quotes.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::write_csv;
+    ///
+    /// let scores = [("Ferris", 120), ("Corro, the Unsafe", 95)];
+    ///
+    /// write_csv(
+    ///     "scores.csv",
+    ///     scores.iter().map(|(name, points)| [name.to_string(), points.to_string()]),
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the file can't be written.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`read_csv`]
+    pub fn write_csv<R, C>(path: impl AsRef<Path>, rows: impl IntoIterator<Item = R>)
+    where
+        R: IntoIterator<Item = C>,
+        C: Display,
+    {
+        let path = path.as_ref();
+        if let Err(err) = std::fs::write(path, format_csv(rows)) {
+            panic!("{}", FileError::new("write", path, err));
+        }
+    }
+
+    fn format_csv<R, C>(rows: impl IntoIterator<Item = R>) -> String
+    where
+        R: IntoIterator<Item = C>,
+        C: Display,
+    {
+        let mut text = String::new();
+        for row in rows {
+            for (i, cell) in row.into_iter().enumerate() {
+                if i > 0 {
+                    text.push(',');
+                }
+                let cell = cell.to_string();
+                if cell.contains(&[',', '"', '\n', '\r'][..]) {
+                    let _ = write!(text, "\"{}\"", cell.replace('"', "\"\""));
+                } else {
+                    text.push_str(&cell);
+                }
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    ///
+    /// Reads a config file with a `key = value` setting on every line
+    ///
+    /// The spaces around keys and values are removed, unless the value is in double quotes.
+    /// Empty lines, lines starting with `#` or `;` as comments, and lines without `=` are
+    /// skipped. If a key is there twice, the last value is used.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::read_config;
+    ///
+    /// // # the settings of the game
+    /// // player name = Ferris
+    /// // difficulty = hard
+    /// let config = read_config("settings.txt");
+    ///
+    /// let difficulty = config.get("difficulty").map_or("normal", String::as_str);
+    /// println!("Playing on {}", difficulty);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the file can't be read, see [`read_file`].
+    ///
+    /// # Why is this not in std?
+    ///
+    /// There are lots of formats for config files, and crates like `toml` or `serde_json` for
+    /// them.
+    pub fn read_config(path: impl AsRef<Path>) -> HashMap<String, String> {
+        parse_config(&read_file(path))
+    }
+
+    fn parse_config(text: &str) -> HashMap<String, String> {
+        text.lines()
+            .map(str::trim)
+            .filter(|line| !line.starts_with('#') && !line.starts_with(';'))
+            .filter_map(|line| line.split_once('='))
+            .map(|(key, value)| {
+                let value = value.trim();
+                let value = match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+                    Some(quoted) => quoted,
+                    None => value,
+                };
+                (key.trim().to_owned(), value.to_owned())
+            })
+            .collect()
+    }
+
+    ///
+    /// Writes the settings to a config file with a `key = value` line for each of them, sorted
+    /// by the keys, see [`read_config`]
+    ///
+    /// Values that start or end with spaces are put into double quotes, so that they stay like
+    /// they are.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::{read_config, write_config};
+    ///
+    /// let mut config = read_config("settings.txt");
+    /// config.insert("difficulty".to_owned(), "easy".to_owned());
+    /// write_config("settings.txt", &config);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the file can't be written, or if a key or value contains a line break, or a
+    /// key contains `=`, as they couldn't be read again.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`read_config`]
+    pub fn write_config<K, V>(path: impl AsRef<Path>, settings: impl IntoIterator<Item = (K, V)>)
+    where
+        K: Display,
+        V: Display,
+    {
+        let path = path.as_ref();
+        if let Err(err) = std::fs::write(path, format_config(settings)) {
+            panic!("{}", FileError::new("write", path, err));
+        }
+    }
+
+    fn format_config<K, V>(settings: impl IntoIterator<Item = (K, V)>) -> String
+    where
+        K: Display,
+        V: Display,
+    {
+        let mut settings = settings
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect::<Vec<_>>();
+        settings.sort();
+
+        let mut text = String::new();
+        for (key, value) in settings {
+            let key = key.trim();
+            if key.contains(&['\n', '\r', '='][..]) || value.contains(&['\n', '\r'][..]) {
+                panic!(
+                    "the setting `{:?} = {:?}` can't be written to a config file",
+                    key, value
+                );
+            }
+            let needs_quotes = value.trim() != value || value.starts_with('"');
+            if needs_quotes {
+                let _ = writeln!(text, "{} = \"{}\"", key, value);
+            } else if value.is_empty() {
+                let _ = writeln!(text, "{} =", key);
+            } else {
+                let _ = writeln!(text, "{} = {}", key, value);
+            }
+        }
+        text
+    }
+
+    /// how often a watched file is checked for changes
+    const WATCH_INTERVAL: Duration = Duration::from_millis(100);
+
+    /// A change to a file that is watched with [`watch_file`] or [`watch_file_events`]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum FileEvent {
+        /// The file didn't exist and was created
+        Created,
+        /// The contents of the file changed
+        Modified,
+        /// The file was deleted
+        Removed,
+    }
+
+    ///
+    /// Calls `callback` every time the file is created, changed or deleted, until the returned
+    /// [`FileWatcher`] is dropped
+    ///
+    /// The file is checked for changes ten times per second in the background, so the callback
+    /// is called from another thread.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::{read_lines, watch_file, FileEvent};
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let words = Arc::new(Mutex::new(read_lines("words.txt")));
+    /// let watched_words = Arc::clone(&words);
+    /// let _watcher = watch_file("words.txt", move |event| {
+    ///     if event != FileEvent::Removed {
+    ///         *watched_words.lock().unwrap() = read_lines("words.txt");
+    ///         println!("Reloaded the words");
+    ///     }
+    /// });
+    ///
+    /// loop {
+    ///     // play with the words, which are always up to date
+    /// #   break;
+    /// }
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// Every operating system has its own way to get notified about changes to files, which
+    /// crates like `notify` hide behind one API. Checking again and again is simpler, but slower.
+    pub fn watch_file(
+        path: impl AsRef<Path>,
+        mut callback: impl FnMut(FileEvent) + Send + 'static,
+    ) -> FileWatcher {
+        let (stop, stopped) = mpsc::channel();
+        let thread = spawn_watcher(path.as_ref(), WATCH_INTERVAL, move |event| {
+            if let Some(event) = event {
+                callback(event);
+            }
+            // a closed channel means that the watcher was dropped without a message
+            !matches!(stopped.try_recv(), Err(TryRecvError::Empty))
+        });
+        FileWatcher {
+            stop: Some(stop),
+            thread: Some(thread),
+        }
+    }
+
+    ///
+    /// Watches the file like [`watch_file`], but collects the changes in the returned
+    /// [`FileEvents`] instead of calling a function
+    ///
+    /// The file is watched until the [`FileEvents`] are dropped.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::{read_config, watch_file_events};
+    ///
+    /// let mut config = read_config("game.cfg");
+    /// let changes = watch_file_events("game.cfg");
+    ///
+    /// loop {
+    ///     if changes.try_next().is_some() {
+    ///         config = read_config("game.cfg");
+    ///     }
+    ///     // run the game with the config
+    /// #   break;
+    /// }
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`watch_file`]
+    pub fn watch_file_events(path: impl AsRef<Path>) -> FileEvents {
+        let (sender, events) = mpsc::channel();
+        // the receiver can't be dropped before the watcher, so sending always works
+        let watcher = watch_file(path, move |event| {
+            let _ = sender.send(event);
+        });
+        FileEvents {
+            events,
+            _watcher: watcher,
+        }
+    }
+
+    /// checks the file every `interval` on a new thread and calls `handle` with what changed,
+    /// until it returns `true`
+    fn spawn_watcher(
+        path: &Path,
+        interval: Duration,
+        mut handle: impl FnMut(Option<FileEvent>) -> bool + Send + 'static,
+    ) -> JoinHandle<()> {
+        /// the modification time and size, `None` if there is no file
+        fn state(path: &Path) -> Option<(Option<SystemTime>, u64)> {
+            let metadata = std::fs::metadata(path).ok().filter(|meta| meta.is_file())?;
+            Some((metadata.modified().ok(), metadata.len()))
+        }
+
+        let path = path.to_owned();
+        let mut last = state(&path);
+        std::thread::spawn(move || loop {
+            std::thread::sleep(interval);
+            let current = state(&path);
+            let event = match (last, current) {
+                (None, Some(_)) => Some(FileEvent::Created),
+                (Some(_), None) => Some(FileEvent::Removed),
+                (Some(last), Some(current)) if last != current => Some(FileEvent::Modified),
+                _ => None,
+            };
+            last = current;
+            if handle(event) {
+                return;
+            }
+        })
+    }
+
+    /// Stops watching the file when it is dropped, see [`watch_file`]
+    #[derive(Debug)]
+    pub struct FileWatcher {
+        stop: Option<Sender<()>>,
+        thread: Option<JoinHandle<()>>,
+    }
+
+    /// The changes to a file, see [`watch_file_events`]
+    ///
+    /// Iterating over it waits for the next change.
+    #[derive(Debug)]
+    pub struct FileEvents {
+        events: Receiver<FileEvent>,
+        _watcher: FileWatcher,
+    }
+
+    impl FileEvents {
+        /// Returns the oldest change that wasn't returned yet, or `None` if there is none
+        pub fn try_next(&self) -> Option<FileEvent> {
+            self.events.try_recv().ok()
+        }
+
+        /// Waits for the next change like [`Iterator::next`], but only up to `timeout`
+        pub fn next_timeout(&self, timeout: Duration) -> Option<FileEvent> {
+            self.events.recv_timeout(timeout).ok()
+        }
+    }
+
+    impl Iterator for FileEvents {
+        type Item = FileEvent;
+
+        fn next(&mut self) -> Option<FileEvent> {
+            self.events.recv().ok()
+        }
+    }
+
+    impl Drop for FileWatcher {
+        fn drop(&mut self) {
+            drop(self.stop.take());
+            if let Some(thread) = self.thread.take() {
+                // the callback can't be called anymore after the watcher is gone
+                if thread.thread().id() != std::thread::current().id() {
+                    let _ = thread.join();
+                }
+            }
+        }
+    }
+
+    ///
+    /// Writes the contents to the file so that it has either the old or the new contents, even
+    /// if the program crashes or the computer turns off while writing
+    ///
+    /// The contents are written to a new file next to it first, which then replaces the file.
+    /// It can be text or bytes.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::write_file_atomic;
+    ///
+    /// let level = 4;
+    /// // the save is never half written
+    /// write_file_atomic("save.txt", format!("level = {}", level));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the file can't be written, with a message that says which file it is and why.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// `std::fs::write` and `std::fs::rename` can be combined to do it, but knowing that this
+    /// is necessary and where to put the new file is hard.
+    pub fn write_file_atomic(path: impl AsRef<Path>, contents: impl AsRef<[u8]>) {
+        let path = path.as_ref();
+        // renaming only works on the same file system, so the new file is put next to the old
+        let dir = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+        let temp = create_temp(dir, ".tmp", create_file);
+
+        let result = std::fs::OpenOptions::new()
+            .write(true)
+            .open(&temp)
+            .and_then(|mut file| {
+                file.write_all(contents.as_ref())?;
+                // the contents have to be on the disk before the file replaces the old one
+                file.sync_all()
+            })
+            .and_then(|()| std::fs::rename(&temp, path));
+        if let Err(err) = result {
+            let _ = std::fs::remove_file(&temp);
+            panic!("{}", FileError::new("write", path, err));
+        }
+    }
+
+    ///
+    /// Writes messages to a log file, one line for each with the time in front of it
+    ///
+    /// The messages are added to the end of the file, and each line is written to the file
+    /// right away, so nothing is lost if the program crashes. The time is the local time of
+    /// the computer, like the one from [`now_string`](crate::now_string).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::Logger;
+    ///
+    /// let logger = Logger::to_file("game.log");
+    /// logger.log("The game started");
+    /// logger.log(format_args!("Player {} joined", "Ferris"));
+    /// // game.log now ends with lines like
+    /// // [2024-05-01 13:37:09] The game started
+    /// // [2024-05-01 13:37:09] Player Ferris joined
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// Logging is done very differently by every program, and crates like `log` allow them to
+    /// choose how.
+    #[derive(Debug)]
+    pub struct Logger {
+        path: PathBuf,
+        file: std::fs::File,
+    }
+
+    impl Logger {
+        ///
+        /// Opens the log file, which is created if it doesn't exist yet
+        ///
+        /// # Panics
+        ///
+        /// Panics if the file can't be opened.
+        pub fn to_file(path: impl AsRef<Path>) -> Self {
+            let path = path.as_ref();
+            let file = std::fs::OpenOptions::new()
+                .append(true)
+                .create(true)
+                .open(path)
+                .unwrap_or_else(|err| panic!("{}", FileError::new("open", path, err)));
+            Logger {
+                path: path.to_owned(),
+                file,
+            }
+        }
+
+        ///
+        /// Writes the message with the current time to the end of the file
+        ///
+        /// # Panics
+        ///
+        /// Panics if the file can't be written.
+        pub fn log(&self, message: impl Display) {
+            let line = format!("[{}] {}\n", crate::time::now_string(), message);
+            // the whole line is written at once, so lines from different threads don't mix
+            let result = (&self.file)
+                .write_all(line.as_bytes())
+                .and_then(|()| (&self.file).flush());
+            if let Err(err) = result {
+                panic!("{}", FileError::new("write", &self.path, err));
+            }
+        }
+    }
+
+    ///
+    /// Creates an empty file with a name that no other file has in the temporary directory of
+    /// the system, which is deleted again when the returned [`TempFile`] is dropped
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::{read_file, temp_file};
+    ///
+    /// let file = temp_file();
+    /// std::fs::write(&file, "scratch space").unwrap();
+    /// assert_eq!(read_file(&file), "scratch space");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the file can't be created.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// `std` only has `std::env::temp_dir` for the directory, and leaves picking a name and
+    /// cleaning up to crates like `tempfile`.
+    pub fn temp_file() -> TempFile {
+        temp_file_with_extension("")
+    }
+
+    /// like [`temp_file`], but the name ends with `extension`, like `.txt`
+    pub(crate) fn temp_file_with_extension(extension: &str) -> TempFile {
+        TempFile {
+            path: create_temp(&std::env::temp_dir(), extension, create_file),
+        }
+    }
+
+    ///
+    /// Creates an empty directory with a name that nothing else has in the temporary directory
+    /// of the system, which is deleted with everything in it when the returned [`TempDir`] is
+    /// dropped
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::{list_dir, temp_dir};
+    ///
+    /// let dir = temp_dir();
+    /// std::fs::write(dir.path().join("level1.txt"), "#####").unwrap();
+    /// assert_eq!(list_dir(&dir), ["level1.txt"]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the directory can't be created.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`temp_file`]
+    pub fn temp_dir() -> TempDir {
+        TempDir {
+            path: create_temp(&std::env::temp_dir(), "", |path| std::fs::create_dir(path)),
+        }
+    }
+
+    /// creates a new empty file, failing if it exists already
+    fn create_file(path: &Path) -> std::io::Result<()> {
+        std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)
+            .map(drop)
+    }
+
+    /// creates something in `dir` with `create`, trying new names until one isn't taken yet
+    fn create_temp(
+        dir: &Path,
+        extension: &str,
+        create: impl Fn(&Path) -> std::io::Result<()>,
+    ) -> PathBuf {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+        loop {
+            // the time makes it unlikely to get the same name as an older process with the same id
+            let nanos = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |time| time.subsec_nanos());
+            let name = format!(
+                "simple-std-{}-{}-{}{}",
+                std::process::id(),
+                COUNT.fetch_add(1, Ordering::Relaxed),
+                nanos,
+                extension
+            );
+            let path = dir.join(name);
+            match create(&path) {
+                Ok(()) => return path,
+                Err(err) if err.kind() == ErrorKind::AlreadyExists => continue,
+                Err(err) => panic!("{}", FileError::new("create", &path, err)),
+            }
+        }
+    }
+
+    /// A file that is deleted when this is dropped, see [`temp_file`]
+    #[derive(Debug)]
+    pub struct TempFile {
+        path: PathBuf,
+    }
+
+    impl TempFile {
+        /// Returns the path of the file
+        pub fn path(&self) -> &Path {
+            &self.path
+        }
+    }
+
+    impl AsRef<Path> for TempFile {
+        fn as_ref(&self) -> &Path {
+            &self.path
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.path);
+        }
+    }
+
+    /// A directory that is deleted with everything in it when this is dropped, see [`temp_dir`]
+    #[derive(Debug)]
+    pub struct TempDir {
+        path: PathBuf,
+    }
+
+    impl TempDir {
+        /// Returns the path of the directory
+        pub fn path(&self) -> &Path {
+            &self.path
+        }
+    }
+
+    impl AsRef<Path> for TempDir {
+        fn as_ref(&self) -> &Path {
+            &self.path
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.path);
+        }
+    }
+
+    /// The error returned when something can't be done with a file, which says which file it
+    /// is
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::try_read_file;
+    ///
+    /// let err = try_read_file("does-not-exist.txt").unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "could not read `does-not-exist.txt`: it does not exist"
+    /// );
+    /// ```
+    #[derive(Debug)]
+    pub struct FileError {
+        action: &'static str,
+        path: PathBuf,
+        error: std::io::Error,
+    }
+
+    impl FileError {
+        /// `action` is what couldn't be done, like "read" or "create"
+        pub(crate) fn new(action: &'static str, path: &Path, error: std::io::Error) -> Self {
+            FileError {
+                action,
+                path: path.to_owned(),
+                error,
+            }
+        }
+
+        /// Returns the path of the file that the error is about
+        pub fn path(&self) -> &Path {
+            &self.path
+        }
+
+        /// Returns what kind of error it is, like [`ErrorKind::NotFound`] if the file doesn't
+        /// exist
+        pub fn kind(&self) -> ErrorKind {
+            self.error.kind()
+        }
+    }
+
+    impl Display for FileError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "could not {} `{}`: ", self.action, self.path.display())?;
+            match self.error.kind() {
+                ErrorKind::NotFound => f.write_str("it does not exist"),
+                ErrorKind::PermissionDenied => f.write_str("permission denied"),
+                ErrorKind::AlreadyExists => f.write_str("something with that name already exists"),
+                // `read_to_string` uses this kind for invalid UTF-8
+                ErrorKind::InvalidData if self.action == "read" => {
+                    f.write_str("it is not valid UTF-8 text")
+                }
+                _ => write!(f, "{}", self.error),
+            }
+        }
+    }
+
+    impl std::error::Error for FileError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(&self.error)
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::{format_config, format_csv, parse_config, parse_csv};
+        use super::{matches_pattern, spawn_watcher};
+        use crate::remove_dir_all_safe;
+        use crate::DirListing;
+        use crate::Logger;
+        use crate::{config_dir, data_dir, home_dir};
+        use crate::{copy_dir, dir_exists, ensure_dir, file_exists, find_files, list_dir};
+        use crate::{file_size, read_bytes, try_read_bytes, write_bytes, write_file_atomic};
+        use crate::{read_config, read_csv, temp_dir, temp_file, write_config, write_csv};
+        use crate::{read_file, read_lines, try_read_file, try_read_lines};
+        use crate::{watch_file, watch_file_events, FileEvent};
+        use std::collections::HashMap;
+        use std::io::ErrorKind;
+        use std::path::{Path, PathBuf};
+        use std::sync::mpsc;
+        use std::time::Duration;
+
+        /// a path in the temporary directory that no other test uses
+        fn test_path(name: &str) -> PathBuf {
+            std::env::temp_dir().join(format!("simple-std-test-{}-{}", std::process::id(), name))
+        }
+
+        #[test]
+        fn reading() {
+            let path = test_path("reading.txt");
+            std::fs::write(&path, "hello\nworld\n").unwrap();
+            assert_eq!(read_file(&path), "hello\nworld\n");
+
+            std::fs::write(&path, b"\xff\xfe").unwrap();
+            let err = try_read_file(&path).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!(
+                    "could not read `{}`: it is not valid UTF-8 text",
+                    path.display()
+                )
+            );
+            std::fs::remove_file(&path).unwrap();
+
+            let err = try_read_file(&path).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::NotFound);
+            assert_eq!(err.path(), path);
+            assert!(std::error::Error::source(&err).is_some());
+
+            let result = std::panic::catch_unwind(|| read_file(Path::new("does/not/exist.txt")));
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn logging() {
+            let file = temp_file();
+            std::fs::write(&file, "old line\n").unwrap();
+            let logger = Logger::to_file(&file);
+            logger.log("first");
+            logger.log(format_args!("{} + {}", 1, 2));
+
+            let lines = read_lines(&file);
+            assert_eq!(lines.len(), 3);
+            assert_eq!(lines[0], "old line");
+            for (line, message) in lines[1..].iter().zip(["first", "1 + 2"].iter()) {
+                // [2024-05-01 13:37:09] message
+                assert_eq!(line.len(), 22 + message.len(), "{}", line);
+                assert!(
+                    line.starts_with("[20") && line.ends_with(message),
+                    "{}",
+                    line
+                );
+                assert_eq!(&line[20..22], "] ");
+            }
+        }
+
+        #[test]
+        fn atomic_writing() {
+            let dir = temp_dir();
+            let path = dir.path().join("save.txt");
+            write_file_atomic(&path, "level 1");
+            write_file_atomic(&path, b"level 2");
+            assert_eq!(read_file(&path), "level 2");
+            // the new file was renamed, so nothing is left behind
+            assert_eq!(list_dir(&dir), ["save.txt"]);
+
+            ensure_dir(dir.path().join("dir"));
+            let result = std::panic::catch_unwind(|| write_file_atomic(dir.path().join("dir"), ""));
+            assert!(result.is_err());
+            assert_eq!(list_dir(&dir), ["dir", "save.txt"]);
+        }
+
+        #[test]
+        fn bytes() {
+            let path = test_path("bytes.bin");
+            write_bytes(&path, b"\xff\x00binary\n");
+            assert_eq!(read_bytes(&path), b"\xff\x00binary\n");
+            assert_eq!(file_size(&path), 9);
+            write_bytes(&path, &[]);
+            assert_eq!(read_bytes(&path), []);
+            std::fs::remove_file(&path).unwrap();
+
+            let err = try_read_bytes(&path).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("could not read `{}`: it does not exist", path.display())
+            );
+            let result = std::panic::catch_unwind(|| write_bytes("does/not/exist.bin", b""));
+            assert!(result.is_err());
+            assert!(std::panic::catch_unwind(|| file_size(&path)).is_err());
+        }
+
+        #[test]
+        fn lines() {
+            let path = test_path("lines.txt");
+            std::fs::write(&path, "apple\r\n  banana \n\ncherry").unwrap();
+            assert_eq!(read_lines(&path), ["apple", "  banana ", "", "cherry"]);
+            std::fs::remove_file(&path).unwrap();
+
+            assert_eq!(
+                try_read_lines(&path).unwrap_err().kind(),
+                ErrorKind::NotFound
+            );
+        }
+
+        #[test]
+        fn existence() {
+            let dir = test_path("existence");
+            let nested = dir.join("a").join("b");
+            assert!(!dir_exists(&dir));
+
+            ensure_dir(&nested);
+            ensure_dir(&nested);
+            assert!(dir_exists(&nested));
+            assert!(!file_exists(&nested));
+
+            let file = dir.join("file.txt");
+            std::fs::write(&file, "").unwrap();
+            assert!(file_exists(&file));
+            assert!(!dir_exists(&file));
+            assert!(std::panic::catch_unwind(|| ensure_dir(&file)).is_err());
+
+            std::fs::remove_dir_all(&dir).unwrap();
+            assert!(!file_exists(&file));
+        }
+
+        #[test]
+        fn listing() {
+            let dir = test_path("listing");
+            ensure_dir(dir.join("b").join("c"));
+            std::fs::write(dir.join("a.txt"), "").unwrap();
+            std::fs::write(dir.join("b").join("d.txt"), "").unwrap();
+
+            let sep = std::path::MAIN_SEPARATOR;
+            let nested = |names: &[&str]| names.join(&sep.to_string());
+
+            assert_eq!(list_dir(&dir), ["a.txt", "b"]);
+            assert_eq!(DirListing::new(&dir).files_only().list(), ["a.txt"]);
+            assert_eq!(DirListing::new(&dir).dirs_only().list(), ["b"]);
+            assert_eq!(
+                DirListing::new(&dir).recursive().list(),
+                [
+                    "a.txt".to_owned(),
+                    "b".to_owned(),
+                    nested(&["b", "c"]),
+                    nested(&["b", "d.txt"])
+                ]
+            );
+            assert_eq!(
+                DirListing::new(&dir).recursive().files_only().list(),
+                ["a.txt".to_owned(), nested(&["b", "d.txt"])]
+            );
+            assert!(list_dir(dir.join("b").join("c")).is_empty());
+
+            std::fs::remove_dir_all(&dir).unwrap();
+            let err = DirListing::new(&dir).try_list().unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::NotFound);
+        }
+
+        #[test]
+        fn copying_and_removing() {
+            let dir = test_path("copying");
+            let (from, to) = (dir.join("from"), dir.join("to"));
+            ensure_dir(from.join("empty"));
+            ensure_dir(from.join("sub"));
+            std::fs::write(from.join("a.txt"), "a").unwrap();
+            std::fs::write(from.join("sub").join("b.txt"), "b").unwrap();
+
+            assert_eq!(copy_dir(&from, &to), 2);
+            assert_eq!(
+                DirListing::new(&to).recursive().list(),
+                DirListing::new(&from).recursive().list()
+            );
+            assert_eq!(read_file(to.join("sub").join("b.txt")), "b");
+            assert!(std::panic::catch_unwind(|| copy_dir(&from, from.join("inside"))).is_err());
+            assert!(!dir_exists(from.join("inside")));
+            let nested = from.join("inside").join("deeper");
+            assert!(std::panic::catch_unwind(|| copy_dir(&from, &nested)).is_err());
+            assert!(!dir_exists(from.join("inside")));
+            let missing = dir.join("missing");
+            assert!(std::panic::catch_unwind(|| copy_dir(&missing, dir.join("copy"))).is_err());
+            assert!(!dir_exists(dir.join("copy")));
+
+            let would_delete = remove_dir_all_safe(&to, true);
+            assert_eq!(would_delete.len(), 5);
+            assert_eq!(would_delete.last().unwrap(), &to.to_string_lossy());
+            assert!(dir_exists(&to));
+            assert_eq!(remove_dir_all_safe(&to, false), would_delete);
+            assert!(!dir_exists(&to));
+
+            assert!(std::panic::catch_unwind(|| remove_dir_all_safe(".", true)).is_err());
+            assert!(std::panic::catch_unwind(|| remove_dir_all_safe(&to, true)).is_err());
+            std::fs::remove_dir_all(&dir).unwrap();
+        }
+
+        #[test]
+        fn csv() {
+            assert_eq!(
+                parse_csv(
+                    "name,points\r\nFerris,120\n\n\"Corro, the \"\"Unsafe\"\"\",\"9\n5\"\n,\n"
+                ),
+                [
+                    vec!["name", "points"],
+                    vec!["Ferris", "120"],
+                    vec!["Corro, the \"Unsafe\"", "9\n5"],
+                    vec!["", ""],
+                ]
+            );
+            assert_eq!(parse_csv("a,\"\",b"), [["a", "", "b"]]);
+            assert_eq!(parse_csv("\"unclosed,x\ny"), [["unclosed,x\ny"]]);
+            assert!(parse_csv("").is_empty());
+
+            let rows = [
+                vec!["a", "b,c"],
+                vec!["say \"hi\"", ""],
+                vec!["line\nbreak"],
+            ];
+            let text = format_csv(rows.iter().map(|row| row.iter()));
+            assert_eq!(text, "a,\"b,c\"\n\"say \"\"hi\"\"\",\n\"line\nbreak\"\n");
+            assert_eq!(parse_csv(&text)[0], rows[0]);
+            assert_eq!(parse_csv(&text)[2], rows[2]);
+
+            let file = temp_file();
+            write_csv(&file, [[1, 2], [3, 4]]);
+            assert_eq!(read_csv(&file), [["1", "2"], ["3", "4"]]);
+        }
+
+        #[test]
+        fn config() {
+            let text = "\
+# comment
+; also a comment
+  player name =  Ferris
+difficulty=hard
+empty =
+spaces = \"  a  \"
+no equals sign
+url = https://example.com/?a=b
+difficulty = easy
+";
+            let config = parse_config(text);
+            let expected = [
+                ("player name", "Ferris"),
+                ("difficulty", "easy"),
+                ("empty", ""),
+                ("spaces", "  a  "),
+                ("url", "https://example.com/?a=b"),
+            ];
+            let expected = expected
+                .iter()
+                .map(|&(key, value)| (key.to_owned(), value.to_owned()))
+                .collect::<HashMap<_, _>>();
+            assert_eq!(config, expected);
+
+            let text = format_config(&config);
+            assert_eq!(
+                text,
+                "\
+difficulty = easy
+empty =
+player name = Ferris
+spaces = \"  a  \"
+url = https://example.com/?a=b
+"
+            );
+            assert_eq!(parse_config(&text), config);
+            assert_eq!(parse_config(&format_config([("a", "\"q\"")]))["a"], "\"q\"");
+
+            let file = temp_file();
+            write_config(&file, [("volume", 7)]);
+            assert_eq!(read_config(&file)["volume"], "7");
+            assert!(std::panic::catch_unwind(|| format_config([("a=b", "c")])).is_err());
+            assert!(std::panic::catch_unwind(|| format_config([("a", "b\nc")])).is_err());
+        }
+
+        #[test]
+        fn temporary() {
+            let (file, other_file) = (temp_file(), temp_file());
+            assert_ne!(file.path(), other_file.path());
+            assert!(file_exists(&file));
+            assert_eq!(read_file(&file), "");
+            let path = file.path().to_owned();
+            drop(file);
+            assert!(!file_exists(path));
+
+            let dir = temp_dir();
+            assert!(list_dir(&dir).is_empty());
+            ensure_dir(dir.path().join("sub"));
+            std::fs::write(dir.path().join("sub").join("a.txt"), "a").unwrap();
+            let path = dir.path().to_owned();
+            drop(dir);
+            assert!(!dir_exists(path));
+        }
+
+        #[test]
+        fn watching() {
+            let path = test_path("watching.txt");
+            let _ = std::fs::remove_file(&path);
+            let (sender, events) = mpsc::channel();
+            spawn_watcher(&path, Duration::from_millis(5), move |event| {
+                event.is_some_and(|event| sender.send(event).is_err())
+            });
+            let next = || events.recv_timeout(Duration::from_secs(5)).unwrap();
+
+            std::fs::write(&path, "one").unwrap();
+            assert_eq!(next(), FileEvent::Created);
+            std::fs::write(&path, "two lines\n").unwrap();
+            assert_eq!(next(), FileEvent::Modified);
+            std::fs::remove_file(&path).unwrap();
+            assert_eq!(next(), FileEvent::Removed);
+            drop(events);
+
+            let path = test_path("watching-callback.txt");
+            std::fs::write(&path, "").unwrap();
+            let (sender, events) = mpsc::channel();
+            let watcher = watch_file(&path, move |event| sender.send(event).unwrap());
+            std::fs::write(&path, "changed").unwrap();
+            assert_eq!(
+                events.recv_timeout(Duration::from_secs(5)),
+                Ok(FileEvent::Modified)
+            );
+            // the callback is dropped with the watcher, which closes the channel
+            drop(watcher);
+            assert!(events.recv().is_err());
+
+            let events = watch_file_events(&path);
+            assert_eq!(events.try_next(), None);
+            std::fs::remove_file(&path).unwrap();
+            let event = events.next_timeout(Duration::from_secs(5));
+            assert_eq!(event, Some(FileEvent::Removed));
+        }
+
+        #[test]
+        fn patterns() {
+            let matches = |pattern: &str, text: &str| {
+                let chars = |s: &str| s.chars().collect::<Vec<_>>();
+                matches_pattern(&chars(pattern), &chars(text))
+            };
+            assert!(matches("*.txt", "notes.txt"));
+            assert!(matches("*.txt", ".txt"));
+            assert!(!matches("*.txt", "notes.txt.bak"));
+            assert!(matches("level?.txt", "level1.txt"));
+            assert!(!matches("level?.txt", "level10.txt"));
+            assert!(matches("*a*b*", "xxaxxbxx"));
+            assert!(matches("exact", "exact"));
+            assert!(!matches("exact", "exactly"));
+            assert!(matches("levels/*.txt", "levels/1.txt"));
+            assert!(!matches("*.txt", "levels/1.txt"));
+            assert!(!matches("levels?1.txt", "levels/1.txt"));
+        }
+
+        #[test]
+        fn finding() {
+            let dir = temp_dir();
+            ensure_dir(dir.path().join("levels").join("secret"));
+            for name in ["b.txt", "a.md", "levels/1.txt", "levels/secret/2.txt"].iter() {
+                std::fs::write(dir.path().join(name), "").unwrap();
+            }
+            let path = |name: &str| {
+                let path = name
+                    .split('/')
+                    .fold(dir.path().to_owned(), |path, part| path.join(part));
+                path.to_string_lossy().into_owned()
+            };
+
+            assert_eq!(
+                find_files(&dir, "*.txt"),
+                [
+                    path("b.txt"),
+                    path("levels/1.txt"),
+                    path("levels/secret/2.txt")
+                ]
+            );
+            assert_eq!(find_files(&dir, "levels/*.txt"), [path("levels/1.txt")]);
+            assert_eq!(find_files(&dir, "?.md"), [path("a.md")]);
+            assert!(find_files(&dir, "levels").is_empty());
+        }
+
+        #[test]
+        fn standard_dirs() {
+            let home = home_dir();
+            assert!(home.is_absolute());
+            assert!(config_dir().is_absolute());
+            assert!(data_dir().is_absolute());
+
+            if cfg!(target_os = "linux") {
+                if std::env::var_os("XDG_CONFIG_HOME").is_none() {
+                    assert_eq!(config_dir(), home.join(".config"));
+                }
+                if std::env::var_os("XDG_DATA_HOME").is_none() {
+                    assert_eq!(data_dir(), home.join(".local").join("share"));
+                }
+            }
+        }
+    }
+}
+
+mod net {
+    use crate::fs::FileError;
+    use std::fmt::{Display, Formatter};
+    use std::fs::File;
+    use std::io::{BufRead, BufReader, ErrorKind, Read, Write};
+    use std::net::TcpStream;
+    use std::path::Path;
+    use std::time::Duration;
+
+    /// how many redirects are followed before giving up
+    const MAX_REDIRECTS: usize = 10;
+
+    /// how long the server can take to send something before the download fails
+    const TIMEOUT: Duration = Duration::from_secs(30);
+
+    ///
+    /// Downloads the file at the URL and saves it to `path`, returning how many bytes it has
+    ///
+    /// `http://` URLs are downloaded directly. `https://` URLs need encryption, which the `tls`
+    /// feature adds with the OpenSSL library of the system on unix. Without it, or on other
+    /// systems, the `curl` program is used for them, which is installed on most systems.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::{download_file, read_lines};
+    ///
+    /// let bytes = download_file(
+    ///     "https://raw.githubusercontent.com/dwyl/english-words/master/words.txt",
+    ///     "words.txt",
+    /// );
+    /// println!("Downloaded {} bytes", bytes);
+    /// let words = read_lines("words.txt");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the file can't be downloaded or saved, see [`try_download_file`].
+    ///
+    /// # Why is this not in std?
+    ///
+    /// HTTP and encryption are big and change over time, which is why they are left to crates
+    /// like `reqwest`, which also need an async runtime like `tokio` for most of their features.
+    pub fn download_file(url: &str, path: impl AsRef<Path>) -> u64 {
+        try_download_file(url, path).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    ///
+    /// Downloads the file like [`download_file`], but returns an error instead of panicking
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::try_download_file;
+    ///
+    /// match try_download_file("http://example.com/", "example.html") {
+    ///     Ok(bytes) => println!("Downloaded {} bytes", bytes),
+    ///     Err(err) if err.status() == Some(404) => println!("The page doesn't exist"),
+    ///     Err(err) => println!("{}", err),
+    /// }
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`download_file`]
+    pub fn try_download_file(url: &str, path: impl AsRef<Path>) -> Result<u64, DownloadError> {
+        let path = path.as_ref();
+        let mut current = url.to_owned();
+
+        for _ in 0..=MAX_REDIRECTS {
+            #[cfg(not(all(feature = "tls", unix)))]
+            let result = if current.starts_with("https://") {
+                download_with_curl(&current, path)
+            } else {
+                download_http(&current, path)
+            };
+            #[cfg(all(feature = "tls", unix))]
+            let result = download_http(&current, path);
+            match result {
+                Ok(Download::Done(bytes)) => return Ok(bytes),
+                Ok(Download::Redirect(location)) => current = location,
+                Err(reason) => {
+                    return Err(DownloadError {
+                        url: url.to_owned(),
+                        reason,
+                    })
+                }
+            }
+        }
+
+        Err(DownloadError {
+            url: url.to_owned(),
+            reason: Reason::TooManyRedirects,
+        })
+    }
+
+    enum Download {
+        Done(u64),
+        Redirect(String),
+    }
+
+    /// the parts of an `http://` or `https://` URL
+    struct Url<'a> {
+        https: bool,
+        host: &'a str,
+        port: u16,
+        path: String,
+    }
+
+    fn parse_url(url: &str) -> Option<Url<'_>> {
+        let (https, rest) = match url.strip_prefix("https://") {
+            Some(rest) => (true, rest),
+            None => (false, url.strip_prefix("http://")?),
+        };
+        let (authority, path) = match rest.find(['/', '?', '#']) {
+            Some(i) => (&rest[..i], &rest[i..]),
+            None => (rest, "/"),
+        };
+        // the fragment is only for the browser
+        let path = path.split('#').next().unwrap_or_default();
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (host, port.parse().ok()?),
+            None => (authority, if https { 443 } else { 80 }),
+        };
+        if host.is_empty() {
+            return None;
+        }
+        Some(Url {
+            https,
+            host,
+            port,
+            path: if path.starts_with('/') {
+                path.to_owned()
+            } else {
+                format!("/{}", path)
+            },
+        })
+    }
+
+    /// the URL that a `Location` header points to, which can be relative to the `origin`, like
+    /// `http://example.com`, and the `path` of the current URL
+    fn resolve_location(origin: &str, path: &str, location: &str) -> String {
+        if location.contains("://") {
+            return location.to_owned();
+        }
+        if location.starts_with("//") {
+            let scheme = origin.split("//").next().unwrap_or_default();
+            return format!("{}{}", scheme, location);
+        }
+        let path = path.split('?').next().unwrap_or_default();
+        let path = if location.starts_with('/') {
+            location.to_owned()
+        } else if location.starts_with('?') {
+            format!("{}{}", path, location)
+        } else {
+            // `moved.txt` replaces the last part of `/files/old.txt`
+            let directory = path.rfind('/').map_or("/", |i| &path[..=i]);
+            format!("{}{}", directory, location)
+        };
+        format!("{}{}", origin, path)
+    }
+
+    fn download_http(url: &str, path: &Path) -> Result<Download, Reason> {
+        let parsed = parse_url(url).ok_or(Reason::InvalidUrl)?;
+        let stream = TcpStream::connect((parsed.host, parsed.port)).map_err(Reason::Connection)?;
+        stream
+            .set_read_timeout(Some(TIMEOUT))
+            .map_err(Reason::Connection)?;
+
+        if parsed.https {
+            #[cfg(all(feature = "tls", unix))]
+            return download_from(tls::connect(stream, parsed.host)?, &parsed, path);
+            // only the `tls` feature on unix gives `https://` URLs to this function
+            #[cfg(not(all(feature = "tls", unix)))]
+            return Err(Reason::InvalidUrl);
+        }
+        download_from(stream, &parsed, path)
+    }
+
+    /// sends the request for the URL over the connection and saves the answer to `path`
+    fn download_from(
+        mut stream: impl Read + Write,
+        parsed: &Url<'_>,
+        path: &Path,
+    ) -> Result<Download, Reason> {
+        let (scheme, default_port) = if parsed.https {
+            ("https", 443)
+        } else {
+            ("http", 80)
+        };
+        let host = if parsed.port == default_port {
+            parsed.host.to_owned()
+        } else {
+            format!("{}:{}", parsed.host, parsed.port)
+        };
+        write!(
+            stream,
+            "GET {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: simple-std\r\nAccept: */*\r\nConnection: close\r\n\r\n",
+            parsed.path, host
+        )
+        .map_err(Reason::Connection)?;
+
+        let mut response = BufReader::new(stream);
+        let status_line = read_line(&mut response)?;
+        let status = status_line
+            .split(' ')
+            .nth(1)
+            .and_then(|status| status.parse::<u16>().ok())
+            .ok_or_else(|| Reason::Connection(ErrorKind::InvalidData.into()))?;
+
+        let mut headers = Vec::new();
+        loop {
+            let line = read_line(&mut response)?;
+            if line.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                headers.push((name.trim().to_ascii_lowercase(), value.trim().to_owned()));
+            }
+        }
+        let header = |name: &str| {
+            headers
+                .iter()
+                .find(|(header, _)| header == name)
+                .map(|(_, value)| value.as_str())
+        };
+
+        match status {
+            200..=299 => {}
+            301 | 302 | 303 | 307 | 308 => {
+                let location = header("location")
+                    .ok_or_else(|| Reason::Connection(ErrorKind::InvalidData.into()))?;
+                let origin = format!("{}://{}", scheme, host);
+                return Ok(Download::Redirect(resolve_location(
+                    &origin,
+                    &parsed.path,
+                    location,
+                )));
+            }
+            _ => {
+                let text = status_line.split_once(' ').map_or("", |(_, text)| text);
+                return Err(Reason::Status(status, text.to_owned()));
+            }
+        }
+
+        let mut file =
+            File::create(path).map_err(|err| Reason::File(FileError::new("create", path, err)))?;
+        let mut written = 0;
+        let chunked = header("transfer-encoding")
+            .is_some_and(|encoding| encoding.eq_ignore_ascii_case("chunked"));
+
+        if chunked {
+            loop {
+                let line = read_line(&mut response)?;
+                let size = line.split(';').next().unwrap_or_default().trim();
+                let size = u64::from_str_radix(size, 16)
+                    .map_err(|_| Reason::Connection(ErrorKind::InvalidData.into()))?;
+                if size == 0 {
+                    break;
+                }
+                written += copy(&mut response, &mut file, path, Some(size))?;
+                read_line(&mut response)?;
+            }
+        } else {
+            let length = header("content-length").and_then(|length| length.parse().ok());
+            written = copy(&mut response, &mut file, path, length)?;
+        }
+
+        Ok(Download::Done(written))
+    }
+
+    /// reads a line of the response without the line break
+    fn read_line(response: &mut impl BufRead) -> Result<String, Reason> {
+        let mut line = String::new();
+        match response.read_line(&mut line) {
+            Ok(0) => Err(Reason::Connection(ErrorKind::UnexpectedEof.into())),
+            Ok(_) => Ok(line.trim_end_matches(&['\r', '\n'][..]).to_owned()),
+            Err(err) => Err(Reason::Connection(err)),
+        }
+    }
+
+    /// copies `length` bytes, or everything until the connection is closed if it's `None`
+    fn copy(
+        from: &mut impl Read,
+        to: &mut File,
+        path: &Path,
+        length: Option<u64>,
+    ) -> Result<u64, Reason> {
+        let mut buffer = [0; 8192];
+        let mut copied = 0;
+        while length.map_or(true, |length| copied < length) {
+            let max = length.map_or(buffer.len() as u64, |length| {
+                (length - copied).min(buffer.len() as u64)
+            });
+            let read = match from.read(&mut buffer[..max as usize]) {
+                Ok(0) if length.is_some() => {
+                    return Err(Reason::Connection(ErrorKind::UnexpectedEof.into()))
+                }
+                Ok(0) => break,
+                Ok(read) => read,
+                Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+                Err(err) => return Err(Reason::Connection(err)),
+            };
+            to.write_all(&buffer[..read])
+                .map_err(|err| Reason::File(FileError::new("write", path, err)))?;
+            copied += read as u64;
+        }
+        Ok(copied)
+    }
+
+    #[cfg(not(all(feature = "tls", unix)))]
+    fn download_with_curl(url: &str, path: &Path) -> Result<Download, Reason> {
+        let output = std::process::Command::new("curl")
+            .args([
+                "--fail",
+                "--location",
+                "--silent",
+                "--show-error",
+                "--output",
+            ])
+            .arg(path)
+            .arg(url)
+            .output()
+            .map_err(|_| Reason::NoCurl)?;
+
+        if !output.status.success() {
+            let message = String::from_utf8_lossy(&output.stderr);
+            let message = message.trim().trim_start_matches("curl: ");
+            return Err(Reason::Curl(message.to_owned()));
+        }
+        let metadata = std::fs::metadata(path)
+            .map_err(|err| Reason::File(FileError::new("read", path, err)))?;
+        Ok(Download::Done(metadata.len()))
+    }
+
+    /// The error returned when [`try_download_file`] fails, which says which URL it was and why
+    #[derive(Debug)]
+    pub struct DownloadError {
+        url: String,
+        reason: Reason,
+    }
+
+    #[derive(Debug)]
+    enum Reason {
+        InvalidUrl,
+        Connection(std::io::Error),
+        /// the status code and the text after it, like "404 Not Found"
+        Status(u16, String),
+        TooManyRedirects,
+        File(FileError),
+        #[cfg(not(all(feature = "tls", unix)))]
+        NoCurl,
+        #[cfg(not(all(feature = "tls", unix)))]
+        Curl(String),
+        /// the message from OpenSSL
+        #[cfg(all(feature = "tls", unix))]
+        Tls(String),
+    }
+
+    impl DownloadError {
+        /// Returns the URL that couldn't be downloaded
+        pub fn url(&self) -> &str {
+            &self.url
+        }
+
+        /// Returns the HTTP status code if the server answered with an error, like 404 if the
+        /// file doesn't exist
+        pub fn status(&self) -> Option<u16> {
+            match self.reason {
+                Reason::Status(status, _) => Some(status),
+                _ => None,
+            }
+        }
+    }
+
+    impl Display for DownloadError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "could not download `{}`: ", self.url)?;
+            match &self.reason {
+                Reason::InvalidUrl => f.write_str("it is not an http:// or https:// URL"),
+                Reason::Connection(err) => match err.kind() {
+                    ErrorKind::UnexpectedEof => f.write_str("the connection was closed too early"),
+                    ErrorKind::InvalidData => f.write_str("the server sent an invalid answer"),
+                    ErrorKind::TimedOut | ErrorKind::WouldBlock => {
+                        f.write_str("the server took too long to answer")
+                    }
+                    _ => write!(f, "{}", err),
+                },
+                Reason::Status(_, text) => write!(f, "the server answered with `{}`", text),
+                Reason::TooManyRedirects => f.write_str("it redirects too often"),
+                Reason::File(err) => write!(f, "{}", err),
+                #[cfg(not(all(feature = "tls", unix)))]
+                Reason::NoCurl => {
+                    f.write_str("https:// URLs need the `curl` program to be installed")
+                }
+                #[cfg(not(all(feature = "tls", unix)))]
+                Reason::Curl(message) => f.write_str(message),
+                #[cfg(all(feature = "tls", unix))]
+                Reason::Tls(message) => write!(f, "the secure connection failed: {}", message),
+            }
+        }
+    }
+
+    impl std::error::Error for DownloadError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            match &self.reason {
+                Reason::Connection(err) => Some(err),
+                Reason::File(err) => Some(err),
+                _ => None,
+            }
+        }
+    }
+
+    /// `https://` connections with the OpenSSL library of the system
+    #[cfg(all(feature = "tls", unix))]
+    mod tls {
+        use super::Reason;
+        use std::ffi::{c_void, CStr, CString};
+        use std::io::{ErrorKind, Read, Write};
+        use std::net::TcpStream;
+        use std::os::raw::{c_char, c_int, c_long, c_ulong};
+        use std::os::unix::io::AsRawFd;
+
+        const SSL_VERIFY_PEER: c_int = 1;
+        const SSL_CTRL_SET_TLSEXT_HOSTNAME: c_int = 55;
+        const TLSEXT_NAMETYPE_HOST_NAME: c_long = 0;
+        const SSL_ERROR_SYSCALL: c_int = 5;
+        const SSL_ERROR_ZERO_RETURN: c_int = 6;
+        /// `SSL_R_UNEXPECTED_EOF_WHILE_READING` of OpenSSL 3
+        const UNEXPECTED_EOF: c_ulong = 294;
+
+        #[link(name = "ssl")]
+        #[link(name = "crypto")]
+        extern "C" {
+            fn TLS_client_method() -> *const c_void;
+            fn SSL_CTX_new(method: *const c_void) -> *mut c_void;
+            fn SSL_CTX_free(context: *mut c_void);
+            fn SSL_CTX_set_default_verify_paths(context: *mut c_void) -> c_int;
+            fn SSL_CTX_set_verify(context: *mut c_void, mode: c_int, callback: *const c_void);
+            fn SSL_new(context: *mut c_void) -> *mut c_void;
+            fn SSL_free(ssl: *mut c_void);
+            fn SSL_set_fd(ssl: *mut c_void, fd: c_int) -> c_int;
+            fn SSL_ctrl(
+                ssl: *mut c_void,
+                command: c_int,
+                larg: c_long,
+                parg: *mut c_void,
+            ) -> c_long;
+            fn SSL_set1_host(ssl: *mut c_void, host: *const c_char) -> c_int;
+            fn SSL_connect(ssl: *mut c_void) -> c_int;
+            fn SSL_read(ssl: *mut c_void, buffer: *mut c_void, len: c_int) -> c_int;
+            fn SSL_write(ssl: *mut c_void, buffer: *const c_void, len: c_int) -> c_int;
+            fn SSL_shutdown(ssl: *mut c_void) -> c_int;
+            fn SSL_get_error(ssl: *const c_void, result: c_int) -> c_int;
+            fn ERR_get_error() -> c_ulong;
+            fn ERR_error_string_n(error: c_ulong, buffer: *mut c_char, len: usize);
+        }
+
+        /// an encrypted connection over a `TcpStream`
+        pub(super) struct TlsStream {
+            context: *mut c_void,
+            ssl: *mut c_void,
+            // the connection has to stay open as long as OpenSSL uses its file descriptor
+            tcp: TcpStream,
+        }
+
+        /// starts an encrypted connection, which checks that the certificate of the server is
+        /// valid for `host` and trusted by the system
+        pub(super) fn connect(tcp: TcpStream, host: &str) -> Result<TlsStream, Reason> {
+            let host = CString::new(host).map_err(|_| Reason::InvalidUrl)?;
+            // SAFETY: every pointer is checked before it is used, and freed by the drop of
+            // `TlsStream`, which owns them as soon as they are created
+            unsafe {
+                let context = SSL_CTX_new(TLS_client_method());
+                if context.is_null() {
+                    return Err(last_error());
+                }
+                let mut stream = TlsStream {
+                    context,
+                    ssl: std::ptr::null_mut(),
+                    tcp,
+                };
+                // the settings of the context are copied when the connection is created, so
+                // they have to be set before
+                SSL_CTX_set_verify(context, SSL_VERIFY_PEER, std::ptr::null());
+                if SSL_CTX_set_default_verify_paths(context) != 1 {
+                    return Err(last_error());
+                }
+                let ssl = SSL_new(context);
+                if ssl.is_null() {
+                    return Err(last_error());
+                }
+                stream.ssl = ssl;
+                let ready = SSL_set_fd(ssl, stream.tcp.as_raw_fd()) == 1
+                    // the server needs the name to know which certificate to send
+                    && SSL_ctrl(
+                        ssl,
+                        SSL_CTRL_SET_TLSEXT_HOSTNAME,
+                        TLSEXT_NAMETYPE_HOST_NAME,
+                        host.as_ptr() as *mut c_void,
+                    ) == 1
+                    && SSL_set1_host(ssl, host.as_ptr()) == 1
+                    && SSL_connect(ssl) == 1;
+                if ready {
+                    Ok(stream)
+                } else {
+                    Err(last_error())
+                }
+            }
+        }
+
+        /// the oldest error of OpenSSL on this thread
+        fn last_error() -> Reason {
+            // SAFETY: it only reads the error queue of this thread
+            match unsafe { ERR_get_error() } {
+                0 => Reason::Tls("the handshake was not completed".to_owned()),
+                error => Reason::Tls(error_message(error)),
+            }
+        }
+
+        /// the text that OpenSSL has for the error
+        fn error_message(error: c_ulong) -> String {
+            let mut buffer = [0 as c_char; 256];
+            // SAFETY: the length is the size of the buffer, and the text is always terminated
+            let message = unsafe {
+                ERR_error_string_n(error, buffer.as_mut_ptr(), buffer.len());
+                CStr::from_ptr(buffer.as_ptr())
+            };
+            message.to_string_lossy().into_owned()
+        }
+
+        /// turns the result of reading or writing into an io result
+        fn result(ssl: *mut c_void, result: c_int) -> std::io::Result<usize> {
+            if result > 0 {
+                return Ok(result as usize);
+            }
+            // SAFETY: the ssl pointer is valid and the result is from the last call with it
+            let (kind, error) = unsafe { (SSL_get_error(ssl, result), ERR_get_error()) };
+            match kind {
+                SSL_ERROR_ZERO_RETURN => Ok(0),
+                // many servers close the connection without saying so first, and whether
+                // everything arrived is known from the length anyway
+                SSL_ERROR_SYSCALL if error == 0 => {
+                    let err = std::io::Error::last_os_error();
+                    match err.raw_os_error() {
+                        Some(0) | None => Ok(0),
+                        Some(_) => Err(err),
+                    }
+                }
+                _ if error & 0x7F_FFFF == UNEXPECTED_EOF => Ok(0),
+                _ => Err(std::io::Error::new(ErrorKind::Other, error_message(error))),
+            }
+        }
+
+        impl Read for TlsStream {
+            fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+                let len = buffer.len().min(c_int::MAX as usize) as c_int;
+                // SAFETY: the pointer and length describe the valid, writable buffer
+                let read = unsafe { SSL_read(self.ssl, buffer.as_mut_ptr().cast(), len) };
+                result(self.ssl, read)
+            }
+        }
+
+        impl Write for TlsStream {
+            fn write(&mut self, buffer: &[u8]) -> std::io::Result<usize> {
+                let len = buffer.len().min(c_int::MAX as usize) as c_int;
+                // SAFETY: the pointer and length describe the valid buffer
+                let written = unsafe { SSL_write(self.ssl, buffer.as_ptr().cast(), len) };
+                result(self.ssl, written)
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        impl Drop for TlsStream {
+            fn drop(&mut self) {
+                // SAFETY: the pointers are valid or null, and never used again
+                unsafe {
+                    if !self.ssl.is_null() {
+                        SSL_shutdown(self.ssl);
+                        SSL_free(self.ssl);
+                    }
+                    SSL_CTX_free(self.context);
+                }
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::{parse_url, resolve_location};
+        use crate::{read_file, temp_file, try_download_file};
+        use std::io::{BufRead, BufReader, Write};
+        use std::net::TcpListener;
+
+        /// serves the responses to one connection each, returning the URL of the server
+        fn serve(responses: Vec<&'static str>) -> String {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            std::thread::spawn(move || {
+                for response in responses {
+                    let (stream, _) = listener.accept().unwrap();
+                    let mut request = BufReader::new(&stream);
+                    let mut line = String::new();
+                    while request.read_line(&mut line).unwrap() > 2 {
+                        line.clear();
+                    }
+                    (&stream).write_all(response.as_bytes()).unwrap();
+                }
+            });
+            url
+        }
+
+        #[test]
+        fn urls() {
+            let url = parse_url("http://example.com:8080/words.txt?lang=en#top").unwrap();
+            assert_eq!(
+                (url.host, url.port, url.path.as_str()),
+                ("example.com", 8080, "/words.txt?lang=en")
+            );
+            let url = parse_url("http://example.com").unwrap();
+            assert_eq!(
+                (url.host, url.port, url.path.as_str()),
+                ("example.com", 80, "/")
+            );
+            let url = parse_url("http://example.com?a").unwrap();
+            assert_eq!(url.path, "/?a");
+            let url = parse_url("https://example.com/a").unwrap();
+            assert_eq!((url.https, url.port), (true, 443));
+            assert!(parse_url("ftp://example.com").is_none());
+            assert!(parse_url("http://:80/").is_none());
+            assert!(parse_url("http://example.com:port/").is_none());
+        }
+
+        #[test]
+        #[cfg(all(feature = "tls", unix))]
+        fn tls_errors() {
+            // a server that doesn't understand TLS
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("https://{}/", listener.local_addr().unwrap());
+            std::thread::spawn(move || {
+                let (stream, _) = listener.accept().unwrap();
+                let _ = (&stream).write_all(b"HTTP/1.1 200 OK\r\n\r\nhello");
+            });
+
+            let err = try_download_file(&url, temp_file()).unwrap_err();
+            assert!(err.to_string().contains(": the secure connection failed: "));
+        }
+
+        #[test]
+        fn locations() {
+            let resolve =
+                |location| resolve_location("http://a.com:81", "/files/old.txt?v=1", location);
+            assert_eq!(resolve("https://b.com/x"), "https://b.com/x");
+            assert_eq!(resolve("//b.com/x"), "http://b.com/x");
+            assert_eq!(resolve("/moved"), "http://a.com:81/moved");
+            assert_eq!(resolve("moved.txt"), "http://a.com:81/files/moved.txt");
+            assert_eq!(resolve("?v=2"), "http://a.com:81/files/old.txt?v=2");
+            assert_eq!(
+                resolve_location("https://a.com", "/", "moved.txt"),
+                "https://a.com/moved.txt"
+            );
+        }
+
+        #[test]
+        fn downloading() {
+            let file = temp_file();
+            let url = serve(vec![
+                "HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\nhello",
+                "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nchun\r\n3;x=y\r\nked\r\n0\r\n\r\n",
+                "HTTP/1.0 200 OK\r\n\r\nuntil the end",
+                "HTTP/1.1 302 Found\r\nLocation: /files/moved\r\n\r\n",
+                "HTTP/1.1 302 Found\r\nLocation: moved.txt\r\n\r\n",
+                "HTTP/1.1 200 OK\r\ncontent-length: 5\r\n\r\nmoved",
+            ]);
+
+            assert_eq!(try_download_file(&url, &file).unwrap(), 5);
+            assert_eq!(read_file(&file), "hello");
+            assert_eq!(try_download_file(&url, &file).unwrap(), 7);
+            assert_eq!(read_file(&file), "chunked");
+            assert_eq!(try_download_file(&url, &file).unwrap(), 13);
+            assert_eq!(read_file(&file), "until the end");
+            assert_eq!(try_download_file(&url, &file).unwrap(), 5);
+            assert_eq!(read_file(&file), "moved");
+        }
+
+        #[test]
+        fn errors() {
+            let file = temp_file();
+            let url = serve(vec![
+                "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n",
+                "HTTP/1.1 200 OK\r\nContent-Length: 10\r\n\r\nshort",
+                "HTTP/1.1 301 Moved Permanently\r\nLocation: ftp://example.com\r\n\r\n",
+            ]);
+
+            let err = try_download_file(&url, &file).unwrap_err();
+            assert_eq!(err.status(), Some(404));
+            assert_eq!(
+                err.to_string(),
+                format!(
+                    "could not download `{}`: the server answered with `404 Not Found`",
+                    url
+                )
+            );
+            let err = try_download_file(&url, &file).unwrap_err();
+            assert!(err
+                .to_string()
+                .ends_with("the connection was closed too early"));
+            let err = try_download_file(&url, &file).unwrap_err();
+            assert_eq!(err.url(), url);
+            assert!(err
+                .to_string()
+                .ends_with("it is not an http:// or https:// URL"));
+        }
+    }
+}
+
+mod json {
+    use crate::fs::read_file;
+    use std::collections::BTreeMap;
+    use std::fmt::{Display, Formatter, Write};
+    use std::ops::Index;
+    use std::path::Path;
+    use std::str::FromStr;
+
+    ///
+    /// A JSON value, which can be anything that JSON can describe
+    ///
+    /// It can be parsed from a string with [`str::parse`], or read from a file with
+    /// [`read_json`], and printed as JSON with [`Display`]. Indexing it with a key or position
+    /// that doesn't exist returns [`Json::Null`] instead of panicking, so nested values can be
+    /// accessed without checking every step.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::Json;
+    ///
+    /// let json = r#"{"name": "Ferris", "age": 8, "friends": ["Corro"]}"#
+    ///     .parse::<Json>()
+    ///     .expect("invalid JSON");
+    ///
+    /// assert_eq!(json["name"].as_str(), Some("Ferris"));
+    /// assert_eq!(json["age"].as_f64(), Some(8.0));
+    /// assert_eq!(json["friends"][0].as_str(), Some("Corro"));
+    /// assert!(json["enemies"][0].is_null());
+    ///
+    /// println!("{}", json);
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// There are many data formats, and `std` doesn't pick one of them. Crates like
+    /// `serde_json` can read JSON directly into structs.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Json {
+        Null,
+        Bool(bool),
+        Number(f64),
+        String(String),
+        Array(Vec<Json>),
+        Object(BTreeMap<String, Json>),
+    }
+
+    /// returned when indexing with something that doesn't exist
+    static NULL: Json = Json::Null;
+
+    impl Json {
+        /// Returns the value of the key if this is an object that has it
+        pub fn get(&self, key: &str) -> Option<&Json> {
+            self.as_object()?.get(key)
+        }
+
+        /// Returns whether this is [`Json::Null`]
+        pub fn is_null(&self) -> bool {
+            *self == Json::Null
+        }
+
+        /// Returns the bool if this is one
+        pub fn as_bool(&self) -> Option<bool> {
+            match self {
+                Json::Bool(bool) => Some(*bool),
+                _ => None,
+            }
+        }
+
+        /// Returns the number if this is one
+        pub fn as_f64(&self) -> Option<f64> {
+            match self {
+                Json::Number(number) => Some(*number),
+                _ => None,
+            }
+        }
+
+        /// Returns the number if this is one without a fractional part
+        pub fn as_i64(&self) -> Option<i64> {
+            let number = self.as_f64()?;
+            let fits = number.fract() == 0.0 && number.abs() < 2f64.powi(63);
+            if fits {
+                Some(number as i64)
+            } else {
+                None
+            }
+        }
+
+        /// Returns the string if this is one
+        pub fn as_str(&self) -> Option<&str> {
+            match self {
+                Json::String(string) => Some(string),
+                _ => None,
+            }
+        }
+
+        /// Returns the values if this is an array
+        pub fn as_array(&self) -> Option<&Vec<Json>> {
+            match self {
+                Json::Array(array) => Some(array),
+                _ => None,
+            }
+        }
+
+        /// Returns the keys and values if this is an object
+        pub fn as_object(&self) -> Option<&BTreeMap<String, Json>> {
+            match self {
+                Json::Object(object) => Some(object),
+                _ => None,
+            }
+        }
+
+        /// writes the value, with every value of arrays and objects on its own line if it is
+        /// `pretty`, indented for being `depth` arrays and objects deep
+        fn write(&self, f: &mut Formatter<'_>, pretty: bool, depth: usize) -> std::fmt::Result {
+            let newline = |f: &mut Formatter<'_>, depth: usize| {
+                if pretty {
+                    write!(f, "\n{:1$}", "", 2 * depth)
+                } else {
+                    Ok(())
+                }
+            };
+
+            match self {
+                Json::Null => f.write_str("null"),
+                Json::Bool(bool) => write!(f, "{}", bool),
+                Json::Number(number) if number.is_finite() => write!(f, "{}", number),
+                // JSON has no infinity or NaN
+                Json::Number(_) => f.write_str("null"),
+                Json::String(string) => write_string(f, string),
+                Json::Array(array) if array.is_empty() => f.write_str("[]"),
+                Json::Array(array) => {
+                    f.write_char('[')?;
+                    for (i, value) in array.iter().enumerate() {
+                        if i > 0 {
+                            f.write_char(',')?;
+                        }
+                        newline(f, depth + 1)?;
+                        value.write(f, pretty, depth + 1)?;
+                    }
+                    newline(f, depth)?;
+                    f.write_char(']')
+                }
+                Json::Object(object) if object.is_empty() => f.write_str("{}"),
+                Json::Object(object) => {
+                    f.write_char('{')?;
+                    for (i, (key, value)) in object.iter().enumerate() {
+                        if i > 0 {
+                            f.write_char(',')?;
+                        }
+                        newline(f, depth + 1)?;
+                        write_string(f, key)?;
+                        f.write_str(if pretty { ": " } else { ":" })?;
+                        value.write(f, pretty, depth + 1)?;
+                    }
+                    newline(f, depth)?;
+                    f.write_char('}')
+                }
+            }
+        }
+    }
+
+    fn write_string(f: &mut Formatter<'_>, string: &str) -> std::fmt::Result {
+        f.write_char('"')?;
+        for char in string.chars() {
+            match char {
+                '"' => f.write_str("\\\"")?,
+                '\\' => f.write_str("\\\\")?,
+                '\n' => f.write_str("\\n")?,
+                '\r' => f.write_str("\\r")?,
+                '\t' => f.write_str("\\t")?,
+                _ if char.is_control() => write!(f, "\\u{:04x}", u32::from(char))?,
+                _ => f.write_char(char)?,
+            }
+        }
+        f.write_char('"')
+    }
+
+    /// Prints the value as JSON, and with `{:#}` with every value on its own line
+    impl Display for Json {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            self.write(f, f.alternate(), 0)
+        }
+    }
+
+    impl Index<&str> for Json {
+        type Output = Json;
+
+        fn index(&self, key: &str) -> &Json {
+            self.get(key).unwrap_or(&NULL)
+        }
+    }
+
+    impl Index<usize> for Json {
+        type Output = Json;
+
+        fn index(&self, index: usize) -> &Json {
+            self.as_array()
+                .and_then(|array| array.get(index))
+                .unwrap_or(&NULL)
+        }
+    }
+
+    impl From<bool> for Json {
+        fn from(bool: bool) -> Self {
+            Json::Bool(bool)
+        }
+    }
+
+    impl From<f64> for Json {
+        fn from(number: f64) -> Self {
+            Json::Number(number)
+        }
+    }
+
+    impl From<i64> for Json {
+        fn from(number: i64) -> Self {
+            Json::Number(number as f64)
+        }
+    }
+
+    impl From<&str> for Json {
+        fn from(string: &str) -> Self {
+            Json::String(string.to_owned())
+        }
+    }
+
+    impl From<String> for Json {
+        fn from(string: String) -> Self {
+            Json::String(string)
+        }
+    }
+
+    impl<T: Into<Json>> From<Vec<T>> for Json {
+        fn from(values: Vec<T>) -> Self {
+            Json::Array(values.into_iter().map(Into::into).collect())
+        }
+    }
+
+    impl FromStr for Json {
+        type Err = ParseJsonError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let mut parser = Parser { text: s, pos: 0 };
+            parser.skip_whitespace();
+            let value = parser.value(0)?;
+            parser.skip_whitespace();
+            if parser.pos < s.len() {
+                return Err(parser.error("expected the end of the JSON"));
+            }
+            Ok(value)
+        }
+    }
+
+    ///
+    /// Reads a JSON file, see [`Json`]
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::read_json;
+    ///
+    /// // {"levels": [{"name": "The Cave", "enemies": 3}]}
+    /// let game = read_json("game.json");
+    /// for level in game["levels"].as_array().unwrap() {
+    ///     println!("{} has {} enemies", level["name"], level["enemies"]);
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the file can't be read, or if it isn't valid JSON.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`Json`]
+    pub fn read_json(path: impl AsRef<Path>) -> Json {
+        let path = path.as_ref();
+        read_file(path)
+            .parse()
+            .unwrap_or_else(|err| panic!("`{}` is not valid JSON: {}", path.display(), err))
+    }
+
+    /// The error returned when parsing invalid JSON, which says where the problem is
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ParseJsonError {
+        message: &'static str,
+        line: usize,
+        column: usize,
+    }
+
+    impl ParseJsonError {
+        /// Returns the line of the problem, starting at 1
+        pub fn line(&self) -> usize {
+            self.line
+        }
+
+        /// Returns the column of the problem, starting at 1
+        pub fn column(&self) -> usize {
+            self.column
+        }
+    }
+
+    impl Display for ParseJsonError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(
+                f,
+                "{} at line {}, column {}",
+                self.message, self.line, self.column
+            )
+        }
+    }
+
+    impl std::error::Error for ParseJsonError {}
+
+    /// arrays and objects can't be nested deeper than this, so that the stack doesn't overflow
+    const MAX_DEPTH: usize = 128;
+
+    struct Parser<'a> {
+        text: &'a str,
+        pos: usize,
+    }
+
+    impl Parser<'_> {
+        fn error(&self, message: &'static str) -> ParseJsonError {
+            let before = &self.text[..self.pos];
+            let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+            ParseJsonError {
+                message,
+                line: before.matches('\n').count() + 1,
+                column: before[line_start..].chars().count() + 1,
+            }
+        }
+
+        fn peek(&self) -> Option<char> {
+            self.text[self.pos..].chars().next()
+        }
+
+        fn next(&mut self) -> Option<char> {
+            let char = self.peek()?;
+            self.pos += char.len_utf8();
+            Some(char)
+        }
+
+        /// consumes `expected` if the text continues with it
+        fn eat(&mut self, expected: &str) -> bool {
+            let found = self.text[self.pos..].starts_with(expected);
+            if found {
+                self.pos += expected.len();
+            }
+            found
+        }
+
+        fn skip_whitespace(&mut self) {
+            while let Some(' ' | '\t' | '\n' | '\r') = self.peek() {
+                self.pos += 1;
+            }
+        }
+
+        fn value(&mut self, depth: usize) -> Result<Json, ParseJsonError> {
+            if depth > MAX_DEPTH {
+                return Err(self.error("arrays and objects are nested too deeply"));
+            }
+
+            match self.peek() {
+                Some('{') => self.object(depth),
+                Some('[') => self.array(depth),
+                Some('"') => self.string().map(Json::String),
+                Some('-' | '0'..='9') => self.number(),
+                _ if self.eat("null") => Ok(Json::Null),
+                _ if self.eat("true") => Ok(Json::Bool(true)),
+                _ if self.eat("false") => Ok(Json::Bool(false)),
+                _ => Err(self.error("expected a value")),
+            }
+        }
+
+        fn array(&mut self, depth: usize) -> Result<Json, ParseJsonError> {
+            self.pos += 1;
+            let mut array = Vec::new();
+            self.skip_whitespace();
+            if self.eat("]") {
+                return Ok(Json::Array(array));
+            }
+
+            loop {
+                self.skip_whitespace();
+                array.push(self.value(depth + 1)?);
+                self.skip_whitespace();
+                if self.eat("]") {
+                    return Ok(Json::Array(array));
+                }
+                if !self.eat(",") {
+                    return Err(self.error("expected `,` or `]`"));
+                }
+            }
+        }
+
+        fn object(&mut self, depth: usize) -> Result<Json, ParseJsonError> {
+            self.pos += 1;
+            let mut object = BTreeMap::new();
+            self.skip_whitespace();
+            if self.eat("}") {
+                return Ok(Json::Object(object));
+            }
+
+            loop {
+                self.skip_whitespace();
+                if self.peek() != Some('"') {
+                    return Err(self.error("expected a key in double quotes"));
+                }
+                let key = self.string()?;
+                self.skip_whitespace();
+                if !self.eat(":") {
+                    return Err(self.error("expected `:`"));
+                }
+                self.skip_whitespace();
+                object.insert(key, self.value(depth + 1)?);
+                self.skip_whitespace();
+                if self.eat("}") {
+                    return Ok(Json::Object(object));
+                }
+                if !self.eat(",") {
+                    return Err(self.error("expected `,` or `}`"));
+                }
+            }
+        }
+
+        fn string(&mut self) -> Result<String, ParseJsonError> {
+            self.pos += 1;
+            let mut string = String::new();
+
+            loop {
+                match self.next() {
+                    Some('"') => return Ok(string),
+                    Some('\\') => {
+                        let escaped = match self.next() {
+                            Some('"') => '"',
+                            Some('\\') => '\\',
+                            Some('/') => '/',
+                            Some('b') => '\x08',
+                            Some('f') => '\x0c',
+                            Some('n') => '\n',
+                            Some('r') => '\r',
+                            Some('t') => '\t',
+                            Some('u') => self.unicode_escape()?,
+                            _ => return Err(self.error("invalid escape sequence")),
+                        };
+                        string.push(escaped);
+                    }
+                    Some(char) if char.is_control() => {
+                        return Err(self.error("line breaks in strings must be written as `\\n`"))
+                    }
+                    Some(char) => string.push(char),
+                    None => return Err(self.error("the string is missing its closing `\"`")),
+                }
+            }
+        }
+
+        /// the character of a `\\u` escape, after the `u`
+        fn unicode_escape(&mut self) -> Result<char, ParseJsonError> {
+            let first = self.hex_number()?;
+            // characters outside of the basic plane are written as two escapes of UTF-16
+            let code = if (0xd800..0xdc00).contains(&first) && self.eat("\\u") {
+                let second = self.hex_number()?;
+                if !(0xdc00..0xe000).contains(&second) {
+                    return Err(self.error("invalid unicode escape"));
+                }
+                0x10000 + ((first - 0xd800) << 10) + (second - 0xdc00)
+            } else {
+                first
+            };
+            std::char::from_u32(code).ok_or_else(|| self.error("invalid unicode escape"))
+        }
+
+        fn hex_number(&mut self) -> Result<u32, ParseJsonError> {
+            let digits = self
+                .text
+                .get(self.pos..self.pos + 4)
+                .filter(|digits| digits.bytes().all(|byte| byte.is_ascii_hexdigit()));
+            match digits {
+                Some(digits) => {
+                    self.pos += 4;
+                    Ok(u32::from_str_radix(digits, 16).expect("the digits were checked"))
+                }
+                None => Err(self.error("expected four hexadecimal digits")),
+            }
+        }
+
+        fn number(&mut self) -> Result<Json, ParseJsonError> {
+            let start = self.pos;
+            let digits = |parser: &mut Self| {
+                let start = parser.pos;
+                while let Some('0'..='9') = parser.peek() {
+                    parser.pos += 1;
+                }
+                parser.pos > start
+            };
+
+            self.eat("-");
+            if self.eat("0") {
+                if let Some('0'..='9') = self.peek() {
+                    self.pos = start;
+                    return Err(self.error("numbers can't start with 0"));
+                }
+            } else if !digits(self) {
+                return Err(self.error("expected a digit"));
+            }
+            if self.eat(".") && !digits(self) {
+                return Err(self.error("expected a digit after the `.`"));
+            }
+            if self.eat("e") || self.eat("E") {
+                if !self.eat("+") {
+                    self.eat("-");
+                }
+                if !digits(self) {
+                    return Err(self.error("expected the digits of the exponent"));
+                }
+            }
+
+            let number = self.text[start..self.pos]
+                .parse()
+                .expect("the number was checked to be valid");
+            Ok(Json::Number(number))
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::Json;
+        use crate::{read_json, temp_file};
+        use std::collections::BTreeMap;
+
+        fn parse(text: &str) -> Json {
+            text.parse().unwrap()
+        }
+
+        fn error(text: &str) -> String {
+            text.parse::<Json>().unwrap_err().to_string()
+        }
+
+        #[test]
+        fn parsing() {
+            assert_eq!(parse(" null "), Json::Null);
+            assert_eq!(parse("true"), Json::Bool(true));
+            assert_eq!(parse("[false]"), Json::Array(vec![Json::Bool(false)]));
+            assert_eq!(parse("-0.5e2"), Json::Number(-50.0));
+            assert_eq!(parse("0"), Json::Number(0.0));
+            assert_eq!(parse("1E+3"), Json::Number(1000.0));
+            assert_eq!(
+                parse(r#""a\"\\\/\b\f\n\r\t\u00e4\ud83e\udd80ö""#),
+                Json::String("a\"\\/\x08\x0c\n\r\tä🦀ö".to_owned())
+            );
+
+            let json = parse(
+                r#"{
+                    "name": "Ferris",
+                    "age": 8,
+                    "friends": ["Corro", {"name": "Bob"}],
+                    "empty": {},
+                    "none": []
+                }"#,
+            );
+            assert_eq!(json["name"], Json::from("Ferris"));
+            assert_eq!(json["age"].as_i64(), Some(8));
+            assert_eq!(json["friends"][1]["name"].as_str(), Some("Bob"));
+            assert_eq!(json["empty"], Json::Object(BTreeMap::new()));
+            assert_eq!(json["none"].as_array().map(Vec::len), Some(0));
+            assert!(json["friends"][2].is_null());
+            assert!(json["name"]["first"].is_null());
+            assert!(json.get("missing").is_none());
+            assert_eq!(json["age"].as_str(), None);
+            assert_eq!(parse("1.5").as_i64(), None);
+        }
+
+        #[test]
+        fn errors() {
+            assert_eq!(error(""), "expected a value at line 1, column 1");
+            assert_eq!(error("[1,\n 2,]"), "expected a value at line 2, column 4");
+            assert_eq!(error("[1 2]"), "expected `,` or `]` at line 1, column 4");
+            assert_eq!(error("{\"a\" 1}"), "expected `:` at line 1, column 6");
+            assert_eq!(
+                error("{a: 1}"),
+                "expected a key in double quotes at line 1, column 2"
+            );
+            assert_eq!(
+                error("{\"a\": 1 \"b\": 2}"),
+                "expected `,` or `}` at line 1, column 9"
+            );
+            assert_eq!(
+                error("\"abc"),
+                "the string is missing its closing `\"` at line 1, column 5"
+            );
+            assert_eq!(
+                error("\"\\x\""),
+                "invalid escape sequence at line 1, column 4"
+            );
+            assert_eq!(
+                error("\"\\u12\""),
+                "expected four hexadecimal digits at line 1, column 4"
+            );
+            assert_eq!(
+                error("01"),
+                "numbers can't start with 0 at line 1, column 1"
+            );
+            assert_eq!(
+                error("1."),
+                "expected a digit after the `.` at line 1, column 3"
+            );
+            assert_eq!(error("-"), "expected a digit at line 1, column 2");
+            assert_eq!(error("nul"), "expected a value at line 1, column 1");
+            assert_eq!(
+                error("1 2"),
+                "expected the end of the JSON at line 1, column 3"
+            );
+            assert_eq!(error("[1"), "expected `,` or `]` at line 1, column 3");
+            assert!(
+                error(&"[".repeat(1000)).starts_with("arrays and objects are nested too deeply")
+            );
+        }
+
+        #[test]
+        fn printing() {
+            let text = r#"{"a":[1,2.5,"x\ny",null,true],"b":{},"c":[],"d":{"e":"\u0001"}}"#;
+            assert_eq!(parse(text).to_string(), text);
+            assert_eq!(
+                format!("{:#}", parse(text)),
+                r#"{
+  "a": [
+    1,
+    2.5,
+    "x\ny",
+    null,
+    true
+  ],
+  "b": {},
+  "c": [],
+  "d": {
+    "e": "\u0001"
+  }
+}"#
+            );
+            assert_eq!(Json::from(f64::NAN).to_string(), "null");
+            assert_eq!(Json::from(vec![1i64, 2]).to_string(), "[1,2]");
+        }
+
+        #[test]
+        fn reading() {
+            let file = temp_file();
+            std::fs::write(&file, "[1, 2]").unwrap();
+            assert_eq!(read_json(&file)[1].as_f64(), Some(2.0));
+
+            std::fs::write(&file, "[1, 2").unwrap();
+            assert!(std::panic::catch_unwind(|| read_json(&file)).is_err());
+        }
+    }
+}
+
+mod toml {
+    use crate::fs::read_file;
+    use crate::json::Json;
+    use std::collections::BTreeMap;
+    use std::fmt::{Display, Formatter};
+    use std::path::Path;
+
+    type Table = BTreeMap<String, Json>;
+
+    ///
+    /// Reads a TOML file, like `Cargo.toml`, into a [`Json`] value
+    ///
+    /// Tables become objects, and the values without a table are in the outermost object.
+    /// Only a part of TOML is supported, see [`parse_toml`].
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::read_toml;
+    ///
+    /// let manifest = read_toml("Cargo.toml");
+    /// println!("This is version {}", manifest["package"]["version"]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the file can't be read, or if it isn't valid TOML.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`Json`]
+    pub fn read_toml(path: impl AsRef<Path>) -> Json {
+        let path = path.as_ref();
+        parse_toml(&read_file(path))
+            .unwrap_or_else(|err| panic!("`{}` is not valid TOML: {}", path.display(), err))
+    }
+
+    ///
+    /// Parses TOML text into a [`Json`] value, see [`read_toml`]
+    ///
+    /// Tables (`[table]` and `[[array.of.tables]]`), strings, numbers, booleans, arrays and
+    /// inline tables (`{ key = "value" }`) are supported, but no dates and times, and no inline
+    /// tables inside of inline tables. All numbers become [`Json::Number`].
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::parse_toml;
+    ///
+    /// let config = parse_toml(
+    ///     r#"
+    /// title = "Snake"
+    ///
+    /// [window]
+    /// size = [800, 600]
+    /// fullscreen = false
+    /// "#,
+    /// )
+    /// .expect("invalid TOML");
+    ///
+    /// assert_eq!(config["title"].as_str(), Some("Snake"));
+    /// assert_eq!(config["window"]["size"][0].as_i64(), Some(800));
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`Json`]
+    pub fn parse_toml(text: &str) -> Result<Json, ParseTomlError> {
+        let mut parser = Parser { text, pos: 0 };
+        let mut root = Table::new();
+        // the table that the keys belong to, from its header
+        let mut current = Vec::new();
+
+        loop {
+            parser.skip_blank_lines();
+            if parser.pos == text.len() {
+                return Ok(Json::Object(root));
+            }
+
+            let start = parser.pos;
+            if parser.eat("[[") {
+                let path = parser.key()?;
+                if !parser.eat("]]") {
+                    return Err(parser.error("expected `]]`"));
+                }
+                let (last, parent) = path.split_last().expect("keys are never empty");
+                let table =
+                    table_at(&mut root, parent).map_err(|msg| parser.error_at(start, msg))?;
+                match table
+                    .entry(last.clone())
+                    .or_insert_with(|| Json::Array(Vec::new()))
+                {
+                    Json::Array(array) => array.push(Json::Object(Table::new())),
+                    _ => return Err(parser.error_at(start, "the key is not an array of tables")),
+                }
+                current = path;
+            } else if parser.eat("[") {
+                let path = parser.key()?;
+                if !parser.eat("]") {
+                    return Err(parser.error("expected `]`"));
+                }
+                table_at(&mut root, &path).map_err(|msg| parser.error_at(start, msg))?;
+                current = path;
+            } else {
+                let key = parser.key()?;
+                if !parser.eat("=") {
+                    return Err(parser.error("expected `=` after the key"));
+                }
+                parser.skip_spaces();
+                let value = parser.value(0, false)?;
+                table_at(&mut root, &current)
+                    .and_then(|table| insert(table, &key, value))
+                    .map_err(|msg| parser.error_at(start, msg))?;
+            }
+            parser.end_of_line()?;
+        }
+    }
+
+    /// the table at the path, which is created if it doesn't exist yet, where the last table of
+    /// arrays of tables is used
+    fn table_at<'a>(root: &'a mut Table, path: &[String]) -> Result<&'a mut Table, &'static str> {
+        let mut table = root;
+        for key in path {
+            let value = table
+                .entry(key.clone())
+                .or_insert_with(|| Json::Object(Table::new()));
+            let value = match value {
+                Json::Array(array) => array.last_mut().ok_or("the key is not a table")?,
+                value => value,
+            };
+            table = match value {
+                Json::Object(object) => object,
+                _ => return Err("the key is not a table"),
+            };
+        }
+        Ok(table)
+    }
+
+    /// inserts the value of the dotted key, like `a.b = 1`
+    fn insert(table: &mut Table, key: &[String], value: Json) -> Result<(), &'static str> {
+        let (last, parent) = key.split_last().expect("keys are never empty");
+        let table = table_at(table, parent)?;
+        if table.contains_key(last) {
+            return Err("the key is defined twice");
+        }
+        table.insert(last.clone(), value);
+        Ok(())
+    }
+
+    /// The error returned when parsing invalid TOML, which says where the problem is
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ParseTomlError {
+        message: &'static str,
+        line: usize,
+    }
+
+    impl ParseTomlError {
+        /// Returns the line of the problem, starting at 1
+        pub fn line(&self) -> usize {
+            self.line
+        }
+    }
+
+    impl Display for ParseTomlError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{} at line {}", self.message, self.line)
+        }
+    }
+
+    impl std::error::Error for ParseTomlError {}
+
+    /// arrays can't be nested deeper than this, so that the stack doesn't overflow
+    const MAX_DEPTH: usize = 128;
+
+    struct Parser<'a> {
+        text: &'a str,
+        pos: usize,
+    }
+
+    impl Parser<'_> {
+        fn error(&self, message: &'static str) -> ParseTomlError {
+            self.error_at(self.pos, message)
+        }
+
+        fn error_at(&self, pos: usize, message: &'static str) -> ParseTomlError {
+            ParseTomlError {
+                message,
+                line: self.text[..pos].matches('\n').count() + 1,
+            }
+        }
+
+        fn peek(&self) -> Option<char> {
+            self.text[self.pos..].chars().next()
+        }
+
+        fn next(&mut self) -> Option<char> {
+            let char = self.peek()?;
+            self.pos += char.len_utf8();
+            Some(char)
+        }
+
+        /// consumes `expected` if the text continues with it
+        fn eat(&mut self, expected: &str) -> bool {
+            let found = self.text[self.pos..].starts_with(expected);
+            if found {
+                self.pos += expected.len();
+            }
+            found
+        }
+
+        fn skip_spaces(&mut self) {
+            while let Some(' ' | '\t') = self.peek() {
+                self.pos += 1;
+            }
+        }
+
+        fn skip_comment(&mut self) {
+            if self.peek() == Some('#') {
+                let len = self.text[self.pos..]
+                    .find('\n')
+                    .unwrap_or(self.text.len() - self.pos);
+                self.pos += len;
+            }
+        }
+
+        /// skips spaces, comments and line breaks
+        fn skip_blank_lines(&mut self) {
+            loop {
+                self.skip_spaces();
+                self.skip_comment();
+                if !self.eat("\n") && !self.eat("\r\n") {
+                    return;
+                }
+            }
+        }
+
+        fn end_of_line(&mut self) -> Result<(), ParseTomlError> {
+            self.skip_spaces();
+            self.skip_comment();
+            if self.pos == self.text.len() || self.eat("\n") || self.eat("\r\n") {
+                Ok(())
+            } else {
+                Err(self.error("expected the end of the line"))
+            }
+        }
+
+        /// a key with all its parts, like `a."b".c`, and the spaces around it
+        fn key(&mut self) -> Result<Vec<String>, ParseTomlError> {
+            let mut parts = Vec::new();
+            loop {
+                self.skip_spaces();
+                let part = match self.peek() {
+                    Some('"') => self.basic_string()?,
+                    Some('\'') => self.literal_string()?,
+                    _ => {
+                        let len = self.text[self.pos..]
+                            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+                            .unwrap_or(self.text.len() - self.pos);
+                        if len == 0 {
+                            return Err(self.error("expected a key"));
+                        }
+                        self.pos += len;
+                        self.text[self.pos - len..self.pos].to_owned()
+                    }
+                };
+                parts.push(part);
+                self.skip_spaces();
+                if !self.eat(".") {
+                    return Ok(parts);
+                }
+            }
+        }
+
+        fn value(&mut self, depth: usize, in_inline_table: bool) -> Result<Json, ParseTomlError> {
+            if depth > MAX_DEPTH {
+                return Err(self.error("arrays are nested too deeply"));
+            }
+
+            match self.peek() {
+                Some('"') if self.eat("\"\"\"") => self.multiline_string(true).map(Json::String),
+                Some('"') => self.basic_string().map(Json::String),
+                Some('\'') if self.eat("'''") => self.multiline_string(false).map(Json::String),
+                Some('\'') => self.literal_string().map(Json::String),
+                Some('[') => self.array(depth, in_inline_table),
+                Some('{') if in_inline_table => {
+                    Err(self.error("inline tables inside of inline tables are not supported"))
+                }
+                Some('{') => self.inline_table(depth),
+                _ if self.eat("true") => Ok(Json::Bool(true)),
+                _ if self.eat("false") => Ok(Json::Bool(false)),
+                _ => self.number(),
+            }
+        }
+
+        fn array(&mut self, depth: usize, in_inline_table: bool) -> Result<Json, ParseTomlError> {
+            self.pos += 1;
+            let mut array = Vec::new();
+
+            loop {
+                self.skip_blank_lines();
+                if self.eat("]") {
+                    return Ok(Json::Array(array));
+                }
+                array.push(self.value(depth + 1, in_inline_table)?);
+                self.skip_blank_lines();
+                if self.eat("]") {
+                    return Ok(Json::Array(array));
+                }
+                if !self.eat(",") {
+                    return Err(self.error("expected `,` or `]`"));
+                }
+            }
+        }
+
+        fn inline_table(&mut self, depth: usize) -> Result<Json, ParseTomlError> {
+            self.pos += 1;
+            let mut table = Table::new();
+            self.skip_spaces();
+            if self.eat("}") {
+                return Ok(Json::Object(table));
+            }
+
+            loop {
+                let start = self.pos;
+                let key = self.key()?;
+                if !self.eat("=") {
+                    return Err(self.error("expected `=` after the key"));
+                }
+                self.skip_spaces();
+                let value = self.value(depth + 1, true)?;
+                insert(&mut table, &key, value).map_err(|msg| self.error_at(start, msg))?;
+                self.skip_spaces();
+                if self.eat("}") {
+                    return Ok(Json::Object(table));
+                }
+                if !self.eat(",") {
+                    return Err(self.error("expected `,` or `}`"));
+                }
+            }
+        }
+
+        /// a string in `"`, where escape sequences are allowed
+        fn basic_string(&mut self) -> Result<String, ParseTomlError> {
+            self.pos += 1;
+            let mut string = String::new();
+            loop {
+                match self.next() {
+                    Some('"') => return Ok(string),
+                    Some('\\') => string.push(self.escape()?),
+                    Some('\n') | None => {
+                        return Err(self.error("the string is missing its closing `\"`"))
+                    }
+                    Some(char) => string.push(char),
+                }
+            }
+        }
+
+        /// a string in `'`, which is taken as it is
+        fn literal_string(&mut self) -> Result<String, ParseTomlError> {
+            self.pos += 1;
+            let len = self.text[self.pos..]
+                .find(['\'', '\n'])
+                .filter(|&len| self.text[self.pos + len..].starts_with('\''))
+                .ok_or_else(|| self.error("the string is missing its closing `'`"))?;
+            let string = self.text[self.pos..self.pos + len].to_owned();
+            self.pos += len + 1;
+            Ok(string)
+        }
+
+        /// a string in `"""`, or `'''` if it isn't `basic`, after the opening quotes
+        fn multiline_string(&mut self, basic: bool) -> Result<String, ParseTomlError> {
+            let quotes = if basic { "\"\"\"" } else { "'''" };
+            // a line break right after the quotes is not part of the string
+            if !self.eat("\n") {
+                self.eat("\r\n");
+            }
+
+            let mut string = String::new();
+            loop {
+                if self.eat(quotes) {
+                    return Ok(string);
+                }
+                match self.next() {
+                    Some('\\') if basic => {
+                        // a `\` at the end of a line removes the line break and the spaces
+                        // after it
+                        let rest = self.text[self.pos..].trim_start_matches([' ', '\t']);
+                        if rest.starts_with('\n') || rest.starts_with("\r\n") {
+                            let rest = rest.trim_start();
+                            self.pos = self.text.len() - rest.len();
+                        } else {
+                            string.push(self.escape()?);
+                        }
+                    }
+                    Some(char) => string.push(char),
+                    None => return Err(self.error("the string is missing its closing quotes")),
+                }
+            }
+        }
+
+        /// the character of an escape sequence, after the `\`
+        fn escape(&mut self) -> Result<char, ParseTomlError> {
+            let char = match self.next() {
+                Some('b') => '\x08',
+                Some('t') => '\t',
+                Some('n') => '\n',
+                Some('f') => '\x0c',
+                Some('r') => '\r',
+                Some('"') => '"',
+                Some('\\') => '\\',
+                Some('u') => self.unicode_escape(4)?,
+                Some('U') => self.unicode_escape(8)?,
+                _ => return Err(self.error("invalid escape sequence")),
+            };
+            Ok(char)
+        }
+
+        fn unicode_escape(&mut self, len: usize) -> Result<char, ParseTomlError> {
+            let code = self
+                .text
+                .get(self.pos..self.pos + len)
+                .filter(|digits| digits.bytes().all(|byte| byte.is_ascii_hexdigit()))
+                .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+                .and_then(std::char::from_u32)
+                .ok_or_else(|| self.error("invalid unicode escape"))?;
+            self.pos += len;
+            Ok(code)
+        }
+
+        fn number(&mut self) -> Result<Json, ParseTomlError> {
+            let start = self.pos;
+            let len = self.text[self.pos..]
+                .find(|c: char| !(c.is_ascii_alphanumeric() || "_+-.:".contains(c)))
+                .unwrap_or(self.text.len() - self.pos);
+            let token = &self.text[start..start + len];
+
+            if let Some(number) = parse_number(token) {
+                self.pos += len;
+                Ok(Json::Number(number))
+            } else if token.contains(':') || token.matches('-').count() >= 2 {
+                Err(self.error("dates and times are not supported"))
+            } else if token.starts_with(|c: char| c.is_ascii_digit() || c == '+' || c == '-') {
+                Err(self.error("invalid number"))
+            } else {
+                Err(self.error("expected a value"))
+            }
+        }
+    }
+
+    /// parses integers (also with `0x`, `0o` and `0b`), floats, `inf` and `nan`, which can
+    /// contain `_` between digits
+    fn parse_number(token: &str) -> Option<f64> {
+        let (sign, unsigned) = match token.strip_prefix('-') {
+            Some(unsigned) => (-1.0, unsigned),
+            None => (1.0, token.strip_prefix('+').unwrap_or(token)),
+        };
+        match unsigned {
+            "inf" => return Some(sign * f64::INFINITY),
+            "nan" => return Some(f64::NAN),
+            _ => {}
+        }
+
+        let separated = unsigned.split('_').collect::<Vec<_>>();
+        let digits_around = separated
+            .iter()
+            .all(|part| !part.is_empty() && part.starts_with(|c: char| c.is_ascii_alphanumeric()));
+        if !digits_around || !unsigned.starts_with(|c: char| c.is_ascii_digit()) {
+            return None;
+        }
+        let digits = separated.concat();
+
+        for &(prefix, radix) in [("0x", 16), ("0o", 8), ("0b", 2)].iter() {
+            if let Some(digits) = digits.strip_prefix(prefix) {
+                let valid = digits.chars().all(|c| c.is_digit(radix));
+                return u64::from_str_radix(digits, radix)
+                    .ok()
+                    .filter(|_| valid && sign > 0.0)
+                    .map(|number| number as f64);
+            }
+        }
+
+        let leading_zero = digits.len() > 1
+            && digits.starts_with('0')
+            && digits[1..].starts_with(|c: char| c.is_ascii_digit());
+        // there have to be digits on both sides of the `.`
+        let valid_dot = match digits.find('.') {
+            Some(dot) => digits[dot + 1..].starts_with(|c: char| c.is_ascii_digit()),
+            None => true,
+        };
+        let valid_chars = digits
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-'));
+        if leading_zero || !valid_dot || !valid_chars {
+            return None;
+        }
+        digits.parse::<f64>().ok().map(|number| sign * number)
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::parse_number;
+        use crate::{parse_toml, read_toml, Json};
+
+        fn json(text: &str) -> Json {
+            text.parse().unwrap()
+        }
+
+        fn error(text: &str) -> String {
+            parse_toml(text).unwrap_err().to_string()
+        }
+
+        #[test]
+        fn documents() {
+            let toml = r#"
+# a comment
+title = "Snake" # another comment
+"quoted key" = 'C:\no\escapes'
+speed.start = 1.5
+
+[window]
+size = [
+    800,
+    600, # a trailing comma is allowed
+]
+fullscreen = false
+keys = { up = "w", down = "s", all = ["w", "s"] }
+
+[levels.first]
+walls = []
+
+[[enemies]]
+name = "Bob"
+
+[[enemies]]
+name = "Alice"
+friends = [{ name = "Bob" }]
+"#;
+            let expected = r#"{
+                "title": "Snake",
+                "quoted key": "C:\\no\\escapes",
+                "speed": {"start": 1.5},
+                "window": {
+                    "size": [800, 600],
+                    "fullscreen": false,
+                    "keys": {"up": "w", "down": "s", "all": ["w", "s"]}
+                },
+                "levels": {"first": {"walls": []}},
+                "enemies": [{"name": "Bob"}, {"name": "Alice", "friends": [{"name": "Bob"}]}]
+            }"#;
+            assert_eq!(parse_toml(toml).unwrap(), json(expected));
+            assert_eq!(parse_toml("").unwrap(), json("{}"));
+            assert_eq!(
+                parse_toml("a = 1\r\nb = 2").unwrap(),
+                json(r#"{"a": 1, "b": 2}"#)
+            );
+        }
+
+        #[test]
+        fn strings() {
+            let toml = r#"
+basic = "tab\there \"quoted\" \u00e4\U0001F980"
+multiline = """
+first line
+second line"""
+joined = """one \
+         two"""
+literal = '''
+no \escapes here'''
+"#;
+            let expected = r#"{
+                "basic": "tab\there \"quoted\" ä🦀",
+                "multiline": "first line\nsecond line",
+                "joined": "one two",
+                "literal": "no \\escapes here"
+            }"#;
+            assert_eq!(parse_toml(toml).unwrap(), json(expected));
+        }
+
+        #[test]
+        fn numbers() {
+            assert_eq!(parse_number("42"), Some(42.0));
+            assert_eq!(parse_number("+42"), Some(42.0));
+            assert_eq!(parse_number("-17"), Some(-17.0));
+            assert_eq!(parse_number("1_000_000"), Some(1e6));
+            assert_eq!(parse_number("0xff"), Some(255.0));
+            assert_eq!(parse_number("0o17"), Some(15.0));
+            assert_eq!(parse_number("0b101"), Some(5.0));
+            assert_eq!(parse_number("3.25"), Some(3.25));
+            assert_eq!(parse_number("-1e3"), Some(-1000.0));
+            assert_eq!(parse_number("6.02E+2"), Some(602.0));
+            assert_eq!(parse_number("-inf"), Some(f64::NEG_INFINITY));
+            assert!(parse_number("nan").unwrap().is_nan());
+
+            for invalid in [
+                "01", "1__0", "_1", "1_", "1.", ".5", "0x", "-0xff", "0b2", "++1", "1e",
+            ]
+            .iter()
+            {
+                assert_eq!(parse_number(invalid), None, "{}", invalid);
+            }
+        }
+
+        #[test]
+        fn errors() {
+            assert_eq!(error("a = 1\nb"), "expected `=` after the key at line 2");
+            assert_eq!(error("a = 1\na = 2"), "the key is defined twice at line 2");
+            assert_eq!(error("a = 1\n[a]"), "the key is not a table at line 2");
+            assert_eq!(error("a = 1 2"), "expected the end of the line at line 1");
+            assert_eq!(
+                error("a = \"open"),
+                "the string is missing its closing `\"` at line 1"
+            );
+            assert_eq!(
+                error("a = 'open\n'"),
+                "the string is missing its closing `'` at line 1"
+            );
+            assert_eq!(error("a = [1 2]"), "expected `,` or `]` at line 1");
+            assert_eq!(
+                error("a = 1979-05-27"),
+                "dates and times are not supported at line 1"
+            );
+            assert_eq!(
+                error("a = 07:32:00"),
+                "dates and times are not supported at line 1"
+            );
+            assert_eq!(error("a = 0x"), "invalid number at line 1");
+            assert_eq!(error("a = yes"), "expected a value at line 1");
+            assert_eq!(
+                error("a = { b = { c = 1 } }"),
+                "inline tables inside of inline tables are not supported at line 1"
+            );
+            assert_eq!(error("[table"), "expected `]` at line 1");
+            assert_eq!(error("= 1"), "expected a key at line 1");
+            assert_eq!(error("a = \"\\q\""), "invalid escape sequence at line 1");
+        }
+
+        #[test]
+        fn reading() {
+            let manifest = read_toml(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"));
+            assert_eq!(manifest["package"]["name"].as_str(), Some("simple-std"));
+            assert_eq!(manifest["features"]["secure"], json("[]"));
+        }
+    }
+}
+
+mod storage {
+    use crate::fs::{try_read_file, write_file_atomic, FileError};
+    use crate::json::{Json, ParseJsonError};
+    use std::collections::{BTreeMap, HashMap};
+    use std::convert::TryFrom;
+    use std::fmt::{Display, Formatter};
+    use std::path::Path;
+
+    /// the biggest integer up to which every integer can be a JSON number without being rounded
+    const MAX_EXACT_INT: u64 = 1 << 53;
+
+    ///
+    /// Something that can be saved to a file and loaded from it again, like the state of a game
+    ///
+    /// It is stored as [`Json`], so the files can be read and changed with a text editor. It is
+    /// implemented for bools, numbers, chars, strings, and `Option`s, `Vec`s, tuples and maps
+    /// with string keys of them. With the `derive` feature, it can be implemented for structs
+    /// and enums with `#[derive(Storable)]`, if all their fields are `Storable`.
+    ///
+    /// Integers bigger than 2^53 are stored as strings, since JSON numbers can't hold them
+    /// exactly, and so are infinite floats and NaN, like `"inf"`.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::{temp_file, Json, Storable};
+    ///
+    /// struct Player {
+    ///     name: String,
+    ///     level: u32,
+    /// }
+    ///
+    /// // this is what `#[derive(Storable)]` does
+    /// impl Storable for Player {
+    ///     fn to_json(&self) -> Json {
+    ///         let mut object = std::collections::BTreeMap::new();
+    ///         object.insert("name".to_owned(), self.name.to_json());
+    ///         object.insert("level".to_owned(), self.level.to_json());
+    ///         Json::Object(object)
+    ///     }
+    ///
+    ///     fn from_json(json: &Json) -> Option<Self> {
+    ///         Some(Player {
+    ///             name: String::from_json(&json["name"])?,
+    ///             level: u32::from_json(&json["level"])?,
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// let file = temp_file();
+    /// let player = Player {
+    ///     name: "Ferris".to_owned(),
+    ///     level: 3,
+    /// };
+    ///
+    /// player.save(&file);
+    /// let loaded = Player::load(&file);
+    /// assert_eq!(loaded.level, 3);
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// There are many ways to store data, and `std` doesn't pick one of them. Crates like
+    /// `serde` allow storing everything in every format.
+    pub trait Storable: Sized {
+        /// Turns the value into JSON
+        fn to_json(&self) -> Json;
+
+        /// Creates the value from JSON created by [`Storable::to_json`], or returns `None` if
+        /// it doesn't fit
+        fn from_json(json: &Json) -> Option<Self>;
+
+        ///
+        /// Saves the value to the file, replacing what was in it
+        ///
+        /// The file is written with [`write_file_atomic`](crate::write_file_atomic), so the old
+        /// value stays if the program crashes while saving.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the file can't be written.
+        fn save(&self, path: impl AsRef<Path>) {
+            write_file_atomic(path, format!("{:#}\n", self.to_json()));
+        }
+
+        ///
+        /// Loads the value that was saved to the file with [`Storable::save`]
+        ///
+        /// # Panics
+        ///
+        /// Panics if the file can't be read or doesn't contain a value of this type, see
+        /// [`Storable::try_load`].
+        fn load(path: impl AsRef<Path>) -> Self {
+            Self::try_load(path).unwrap_or_else(|err| panic!("{}", err))
+        }
+
+        /// Loads the value like [`Storable::load`], but returns an error instead of panicking
+        fn try_load(path: impl AsRef<Path>) -> Result<Self, LoadError> {
+            let path = path.as_ref();
+            let text = try_read_file(path).map_err(LoadError::File)?;
+            let json = text.parse::<Json>().map_err(LoadError::Json)?;
+            Self::from_json(&json).ok_or_else(|| LoadError::Mismatch(path.display().to_string()))
+        }
+    }
+
+    /// The error returned by [`Storable::try_load`]
+    #[derive(Debug)]
+    pub enum LoadError {
+        /// The file can't be read
+        File(FileError),
+        /// The file doesn't contain valid JSON
+        Json(ParseJsonError),
+        /// The value in the file, which is at the path, is a different type
+        Mismatch(String),
+    }
+
+    impl Display for LoadError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            match self {
+                LoadError::File(err) => write!(f, "{}", err),
+                LoadError::Json(err) => write!(f, "the saved data is not valid JSON: {}", err),
+                LoadError::Mismatch(path) => {
+                    write!(f, "the data saved in `{}` is of a different type", path)
+                }
+            }
+        }
+    }
+
+    impl std::error::Error for LoadError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            match self {
+                LoadError::File(err) => Some(err),
+                LoadError::Json(err) => Some(err),
+                LoadError::Mismatch(_) => None,
+            }
+        }
+    }
+
+    impl Storable for Json {
+        fn to_json(&self) -> Json {
+            self.clone()
+        }
+
+        fn from_json(json: &Json) -> Option<Self> {
+            Some(json.clone())
+        }
+    }
+
+    impl Storable for bool {
+        fn to_json(&self) -> Json {
+            Json::Bool(*self)
+        }
+
+        fn from_json(json: &Json) -> Option<Self> {
+            json.as_bool()
+        }
+    }
+
+    macro_rules! storable_ints {
+        ($($int:ty),*) => {
+            $(
+                impl Storable for $int {
+                    fn to_json(&self) -> Json {
+                        let exact = i64::try_from(*self)
+                            .is_ok_and(|int| int.unsigned_abs() <= MAX_EXACT_INT);
+                        if exact {
+                            Json::Number(*self as f64)
+                        } else {
+                            Json::String(self.to_string())
+                        }
+                    }
+
+                    fn from_json(json: &Json) -> Option<Self> {
+                        if let Some(text) = json.as_str() {
+                            return text.parse().ok();
+                        }
+                        let number = json.as_f64()?;
+                        if number.fract() != 0.0 {
+                            return None;
+                        }
+                        // `as` saturates, so numbers that don't fit are found by converting back
+                        let int = number as $int;
+                        if int as f64 == number {
+                            Some(int)
+                        } else {
+                            None
+                        }
+                    }
+                }
+            )*
+        };
+    }
+
+    storable_ints!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+    impl Storable for f64 {
+        fn to_json(&self) -> Json {
+            // JSON has no numbers for them, they would be written as `null`
+            if self.is_finite() {
+                Json::Number(*self)
+            } else {
+                Json::String(self.to_string())
+            }
+        }
+
+        fn from_json(json: &Json) -> Option<Self> {
+            match json.as_str() {
+                Some(text) => text
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|number| !number.is_finite()),
+                None => json.as_f64(),
+            }
+        }
+    }
+
+    impl Storable for f32 {
+        fn to_json(&self) -> Json {
+            f64::from(*self).to_json()
+        }
+
+        fn from_json(json: &Json) -> Option<Self> {
+            f64::from_json(json).map(|number| number as f32)
+        }
+    }
+
+    impl Storable for char {
+        fn to_json(&self) -> Json {
+            Json::String(self.to_string())
+        }
+
+        fn from_json(json: &Json) -> Option<Self> {
+            let mut chars = json.as_str()?.chars();
+            match (chars.next(), chars.next()) {
+                (Some(char), None) => Some(char),
+                _ => None,
+            }
+        }
+    }
+
+    impl Storable for String {
+        fn to_json(&self) -> Json {
+            Json::String(self.clone())
+        }
+
+        fn from_json(json: &Json) -> Option<Self> {
+            json.as_str().map(str::to_owned)
+        }
+    }
+
+    /// `None` is stored as `null`
+    impl<T: Storable> Storable for Option<T> {
+        fn to_json(&self) -> Json {
+            match self {
+                Some(value) => value.to_json(),
+                None => Json::Null,
+            }
+        }
+
+        fn from_json(json: &Json) -> Option<Self> {
+            match json {
+                Json::Null => Some(None),
+                _ => T::from_json(json).map(Some),
+            }
+        }
+    }
+
+    impl<T: Storable> Storable for Vec<T> {
+        fn to_json(&self) -> Json {
+            Json::Array(self.iter().map(T::to_json).collect())
+        }
+
+        fn from_json(json: &Json) -> Option<Self> {
+            json.as_array()?.iter().map(T::from_json).collect()
+        }
+    }
+
+    impl<T: Storable> Storable for BTreeMap<String, T> {
+        fn to_json(&self) -> Json {
+            let object = self
+                .iter()
+                .map(|(key, value)| (key.clone(), value.to_json()))
+                .collect();
+            Json::Object(object)
+        }
+
+        fn from_json(json: &Json) -> Option<Self> {
+            json.as_object()?
+                .iter()
+                .map(|(key, value)| Some((key.clone(), T::from_json(value)?)))
+                .collect()
+        }
+    }
+
+    impl<T: Storable> Storable for HashMap<String, T> {
+        fn to_json(&self) -> Json {
+            let object = self
+                .iter()
+                .map(|(key, value)| (key.clone(), value.to_json()))
+                .collect();
+            Json::Object(object)
+        }
+
+        fn from_json(json: &Json) -> Option<Self> {
+            json.as_object()?
+                .iter()
+                .map(|(key, value)| Some((key.clone(), T::from_json(value)?)))
+                .collect()
+        }
+    }
+
+    macro_rules! storable_tuples {
+        ($($len:literal: ($($name:ident $index:tt),+))*) => {
+            $(
+                /// stored as an array
+                impl<$($name: Storable),+> Storable for ($($name,)+) {
+                    fn to_json(&self) -> Json {
+                        Json::Array(vec![$(self.$index.to_json()),+])
+                    }
+
+                    fn from_json(json: &Json) -> Option<Self> {
+                        let array = json.as_array()?;
+                        if array.len() != $len {
+                            return None;
+                        }
+                        Some(($($name::from_json(&array[$index])?,)+))
+                    }
+                }
+            )*
+        };
+    }
+
+    storable_tuples! {
+        1: (A 0)
+        2: (A 0, B 1)
+        3: (A 0, B 1, C 2)
+        4: (A 0, B 1, C 2, D 3)
+    }
+
+    #[cfg(test)]
+    mod test {
+        use crate::{temp_file, Json, LoadError, Storable};
+        use std::collections::{BTreeMap, HashMap};
+        use std::fmt::Debug;
+
+        fn round_trip<T: Storable + PartialEq + Debug>(value: T) {
+            assert_eq!(T::from_json(&value.to_json()), Some(value));
+        }
+
+        #[test]
+        fn round_trips() {
+            round_trip(true);
+            round_trip(-12i8);
+            round_trip(u64::from(u32::MAX) + 1);
+            round_trip(u64::MAX - 1);
+            round_trip((1u64 << 53) + 1);
+            round_trip(i64::MIN);
+            round_trip(u128::MAX);
+            round_trip(2.5f32);
+            round_trip(-0.1);
+            round_trip(f64::INFINITY);
+            round_trip(f32::NEG_INFINITY);
+            assert!(f64::from_json(&f64::NAN.to_json()).unwrap().is_nan());
+            round_trip('ä');
+            round_trip("Ferris".to_owned());
+            round_trip(Some(3));
+            round_trip(None::<u8>);
+            round_trip(vec![vec![1, 2], vec![]]);
+            round_trip((1, "a".to_owned(), (false,), Some('x')));
+
+            let mut map = HashMap::new();
+            map.insert("gold".to_owned(), 12);
+            round_trip(map);
+            let mut map = BTreeMap::new();
+            map.insert("sword".to_owned(), Some(1.5));
+            round_trip(map);
+        }
+
+        #[test]
+        fn mismatches() {
+            let json = |text: &str| text.parse::<Json>().unwrap();
+            assert_eq!(u8::from_json(&json("256")), None);
+            assert_eq!(u8::from_json(&json("-1")), None);
+            assert_eq!(i32::from_json(&json("1.5")), None);
+            assert_eq!(char::from_json(&json("\"ab\"")), None);
+            assert_eq!(String::from_json(&json("1")), None);
+            assert_eq!(<(u8, u8)>::from_json(&json("[1]")), None);
+            assert_eq!(<(u8, u8)>::from_json(&json("[1, 2, 3]")), None);
+            assert_eq!(Vec::<u8>::from_json(&json("[1, true]")), None);
+            assert_eq!(Option::<u8>::from_json(&json("true")), None);
+            assert_eq!(u8::from_json(&json("\"256\"")), None);
+            assert_eq!(f64::from_json(&json("\"1.5\"")), None);
+            assert_eq!((1u64 << 53).to_json(), json("9007199254740992"));
+            assert_eq!(((1u64 << 53) + 1).to_json(), json("\"9007199254740993\""));
+        }
+
+        #[test]
+        fn saving_and_loading() {
+            let file = temp_file();
+            let inventory = vec![("apple".to_owned(), 3u32), ("sword".to_owned(), 1)];
+            inventory.save(&file);
+            assert_eq!(
+                std::fs::read_to_string(&file).unwrap(),
+                "[\n  [\n    \"apple\",\n    3\n  ],\n  [\n    \"sword\",\n    1\n  ]\n]\n"
+            );
+            assert_eq!(Vec::<(String, u32)>::load(&file), inventory);
+            (1.0, f64::INFINITY).save(&file);
+            assert_eq!(<(f64, f64)>::load(&file), (1.0, f64::INFINITY));
+
+            assert!(matches!(u32::try_load(&file), Err(LoadError::Mismatch(_))));
+            std::fs::write(&file, "[1,").unwrap();
+            assert!(matches!(u32::try_load(&file), Err(LoadError::Json(_))));
+            let path = file.path().to_owned();
+            drop(file);
+            assert!(matches!(u32::try_load(&path), Err(LoadError::File(_))));
+            assert!(std::panic::catch_unwind(|| u32::load(&path)).is_err());
+        }
+
+        #[test]
+        #[cfg(feature = "derive")]
+        fn derived() {
+            #[derive(Debug, PartialEq, Storable)]
+            enum Item {
+                Key,
+                Potion(u8),
+                Scroll { spell: String },
+            }
+
+            #[derive(Debug, PartialEq, Storable)]
+            struct Slot(u8, Option<Item>);
+
+            #[derive(Debug, PartialEq, Storable)]
+            struct Empty;
+
+            let json = |text: &str| text.parse::<Json>().unwrap();
+            assert_eq!(Item::Key.to_json(), json(r#""Key""#));
+            assert_eq!(Item::Potion(2).to_json(), json(r#"{"Potion":[2]}"#));
+            let scroll = Item::Scroll {
+                spell: "light".to_owned(),
+            };
+            assert_eq!(scroll.to_json(), json(r#"{"Scroll":{"spell":"light"}}"#));
+            assert_eq!(Slot(1, None).to_json(), json("[1,null]"));
+            assert_eq!(Empty.to_json(), Json::Null);
+
+            round_trip(Item::Key);
+            round_trip(Item::Potion(2));
+            round_trip(scroll);
+            round_trip(Slot(4, Some(Item::Key)));
+            round_trip(Empty);
+            assert_eq!(Item::from_json(&json(r#""Sword""#)), None);
+            assert_eq!(Slot::from_json(&json("[1]")), None);
+        }
+    }
+}
+
+mod time {
+    use std::fmt::{Display, Formatter};
+    use std::str::FromStr;
+    use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+    use std::thread::JoinHandle;
+    use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+    ///
+    /// A date in the calendar, like 2024-05-01
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::Date;
+    ///
+    /// let birthday = Date::new(2010, 5, 17);
+    ///
+    /// println!("I was born on {}", birthday);
+    ///
+    /// assert_eq!(birthday.year(), 2010);
+    /// assert!(birthday < Date::new(2010, 12, 24));
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// Dates are surprisingly complicated, with time zones, leap seconds and different calendars,
+    /// so they are left to crates like `chrono`. This one only knows the simple parts.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Date {
+        year: i32,
+        month: u8,
+        day: u8,
+    }
+
+    impl Date {
+        ///
+        /// Creates a date from the year, month (1 to 12) and day (1 to 31)
+        ///
+        /// # Example
+        /// ```
+        /// use simple_std::Date;
+        ///
+        /// let new_year = Date::new(2024, 1, 1);
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// Panics if the date doesn't exist, like `Date::new(2023, 2, 29)`.
+        pub fn new(year: i32, month: u8, day: u8) -> Self {
+            assert!(
+                (1..=12).contains(&month),
+                "the month must be between 1 and 12, but was {}",
+                month
+            );
+            assert!(
+                day >= 1 && day <= days_in_month(year, month),
+                "{}-{:02} has no day {}",
+                year,
+                month,
+                day
+            );
+            Date { year, month, day }
+        }
+
+        /// The year, like 2024
+        pub fn year(&self) -> i32 {
+            self.year
+        }
+
+        /// The month, from 1 (January) to 12 (December)
+        pub fn month(&self) -> u8 {
+            self.month
+        }
+
+        /// The day of the month, from 1 to 31
+        pub fn day(&self) -> u8 {
+            self.day
+        }
+
+        /// the number of days since 1970-01-01
+        pub(crate) fn days_since_epoch(&self) -> i64 {
+            // http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+            let year = i64::from(self.year) - i64::from(self.month <= 2);
+            let era = year.div_euclid(400);
+            let year_of_era = year - era * 400;
+            let month = i64::from(self.month);
+            let day_of_year =
+                (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(self.day) - 1;
+            let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+            era * 146_097 + day_of_era - 719_468
+        }
+
+        /// the date that is `days` days after 1970-01-01
+        pub(crate) fn from_days_since_epoch(days: i64) -> Self {
+            // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+            let days = days + 719_468;
+            let era = days.div_euclid(146_097);
+            let day_of_era = days - era * 146_097;
+            let year_of_era =
+                (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+            let day_of_year =
+                day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+            let month_index = (5 * day_of_year + 2) / 153;
+            let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+            let month = if month_index < 10 {
+                month_index + 3
+            } else {
+                month_index - 9
+            };
+            let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+            Date {
+                year: year as i32,
+                month: month as u8,
+                day: day as u8,
+            }
+        }
+    }
+
+    impl Display for Date {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+        }
+    }
+
+    /// Parses dates like `2024-05-01`, `01.05.2024`, `01/05/2024`, `May 1 2024` or
+    /// `1 May, 2024`
+    ///
+    /// Dates with only numbers are read as year, month, day if they start with the year, and as
+    /// day, month, year otherwise. Months can also be written as their English names, or the
+    /// first three letters of them.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::Date;
+    ///
+    /// let date = "May 1 2024".parse::<Date>().unwrap();
+    /// assert_eq!(date, Date::new(2024, 5, 1));
+    /// assert_eq!("01/05/2024".parse::<Date>().unwrap(), date);
+    ///
+    /// assert!("2023-02-29".parse::<Date>().is_err());
+    /// ```
+    impl FromStr for Date {
+        type Err = ParseDateError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let parts = s
+                .split(|c: char| c.is_whitespace() || "-/.,".contains(c))
+                .filter(|part| !part.is_empty())
+                .collect::<Vec<_>>();
+
+            let (year, month, day) = match parts.as_slice() {
+                [year, month, day]
+                    if year.len() == 4 && year.bytes().all(|b| b.is_ascii_digit()) =>
+                {
+                    (*year, *month, *day)
+                }
+                [month, day, year] if month_from_name(month).is_some() => (*year, *month, *day),
+                [day, month, year] => (*year, *month, *day),
+                _ => return Err(ParseDateError::new(s)),
+            };
+
+            let year = year.parse::<i32>().ok();
+            let month = month_from_name(month).or_else(|| month.parse::<u8>().ok());
+            let day = day.parse::<u8>().ok();
+
+            match (year, month, day) {
+                (Some(year), Some(month), Some(day))
+                    if (1..=12).contains(&month)
+                        && day >= 1
+                        && day <= days_in_month(year, month) =>
+                {
+                    Ok(Date { year, month, day })
+                }
+                _ => Err(ParseDateError::new(s)),
+            }
+        }
+    }
+
+    /// The error returned when parsing an invalid date
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ParseDateError {
+        input: String,
+    }
+
+    impl ParseDateError {
+        fn new(input: &str) -> Self {
+            ParseDateError {
+                input: input.to_owned(),
+            }
+        }
+    }
+
+    impl Display for ParseDateError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(
+                f,
+                "`{}` is not a date, expected something like `2024-05-01` or `May 1 2024`",
+                self.input
+            )
+        }
+    }
+
+    impl std::error::Error for ParseDateError {}
+
+    /// the number of the month from its English name or the first three letters of it
+    fn month_from_name(name: &str) -> Option<u8> {
+        const MONTHS: [&str; 12] = [
+            "january",
+            "february",
+            "march",
+            "april",
+            "may",
+            "june",
+            "july",
+            "august",
+            "september",
+            "october",
+            "november",
+            "december",
+        ];
+
+        let name = name.to_lowercase();
+        if name.len() < 3 {
+            return None;
+        }
+        MONTHS
+            .iter()
+            .position(|month| month.starts_with(&name))
+            .map(|index| index as u8 + 1)
+    }
+
+    ///
+    /// Measures how much time passes, like a stopwatch, for finding out how fast code is
+    ///
+    /// Printing it shows the time that has passed.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::Stopwatch;
+    ///
+    /// let mut stopwatch = Stopwatch::start();
+    /// let mut numbers = (0..100_000).rev().collect::<Vec<_>>();
+    /// numbers.sort();
+    /// println!("Sorting took {:?}", stopwatch.lap());
+    ///
+    /// let sum = numbers.iter().sum::<u64>();
+    /// println!("Adding took {:?}", stopwatch.lap());
+    ///
+    /// println!("Everything took {}", stopwatch);
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// It is mostly there, as [`Instant`], but computing laps and pausing with it needs to keep
+    /// track of several `Instant`s and `Duration`s.
+    #[derive(Debug, Clone)]
+    pub struct Stopwatch {
+        /// when it was started or resumed the last time, `None` while it is paused
+        running_since: Option<Instant>,
+        /// the time that had passed when it was paused the last time
+        before_pause: Duration,
+        /// the time that had passed at the end of the last lap
+        last_lap: Duration,
+    }
+
+    impl Stopwatch {
+        /// Starts measuring the time
+        pub fn start() -> Self {
+            Stopwatch {
+                running_since: Some(Instant::now()),
+                before_pause: Duration::ZERO,
+                last_lap: Duration::ZERO,
+            }
+        }
+
+        /// Returns the time that has passed since it was started, without the time it was paused
+        pub fn elapsed(&self) -> Duration {
+            let running = self
+                .running_since
+                .map_or(Duration::ZERO, |since| since.elapsed());
+            self.before_pause + running
+        }
+
+        /// Returns the time that has passed since the last lap, or since it was started for the
+        /// first lap
+        pub fn lap(&mut self) -> Duration {
+            let elapsed = self.elapsed();
+            let lap = elapsed - self.last_lap;
+            self.last_lap = elapsed;
+            lap
+        }
+
+        /// Stops measuring the time until [`Stopwatch::resume`] is called
+        pub fn pause(&mut self) {
+            if let Some(since) = self.running_since.take() {
+                self.before_pause += since.elapsed();
+            }
+        }
+
+        /// Measures the time again after [`Stopwatch::pause`]
+        pub fn resume(&mut self) {
+            if self.running_since.is_none() {
+                self.running_since = Some(Instant::now());
+            }
+        }
+
+        /// Returns whether it is paused
+        pub fn is_paused(&self) -> bool {
+            self.running_since.is_none()
+        }
+    }
+
+    /// Shows the time that has passed like [`format_duration`], like `1.2s` or `2m 31s`
+    impl Display for Stopwatch {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            f.write_str(&format_duration(self.elapsed()))
+        }
+    }
+
+    ///
+    /// Formats the duration so that it's easy to read, like `450ms`, `2.5s` or `2m 31s`
+    ///
+    /// Only the two biggest units are shown, so `3h 05m` leaves out the seconds, and the rest
+    /// is cut off instead of rounded. See [`format_duration_verbose`] for writing the units out.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::format_duration;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(format_duration(Duration::from_millis(450)), "450ms");
+    /// assert_eq!(format_duration(Duration::from_millis(2500)), "2.5s");
+    /// assert_eq!(format_duration(Duration::from_secs(151)), "2m 31s");
+    /// assert_eq!(format_duration(Duration::from_secs(3 * 3600 + 300)), "3h 05m");
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// It is almost there, as the `Debug` output of [`Duration`], but that only uses seconds
+    /// and smaller units, like `151.000000002s`.
+    pub fn format_duration(duration: Duration) -> String {
+        let parts = duration_parts(duration);
+        let mut text = format!("{}{}", parts[0].0, parts[0].1);
+        if let Some((amount, unit, _)) = parts.get(1) {
+            text += &format!(" {:0>2}{}", amount, unit);
+        }
+        text
+    }
+
+    ///
+    /// Formats the duration like [`format_duration`], but with the units written out, like
+    /// `450 milliseconds` or `2 minutes 31 seconds`
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::format_duration_verbose;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(
+    ///     format_duration_verbose(Duration::from_secs(151)),
+    ///     "2 minutes 31 seconds"
+    /// );
+    /// assert_eq!(format_duration_verbose(Duration::from_secs(3600)), "1 hour");
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`format_duration`]
+    pub fn format_duration_verbose(duration: Duration) -> String {
+        let parts = duration_parts(duration);
+        parts
+            .iter()
+            .enumerate()
+            // `2 minutes` reads better than `2 minutes 0 seconds`
+            .filter(|(i, (amount, _, _))| *i == 0 || amount != "0")
+            .map(|(_, (amount, _, name))| {
+                let plural = if amount == "1" { "" } else { "s" };
+                format!("{} {}{}", amount, name, plural)
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// the amounts of the two biggest units of the duration, with the short and long names of
+    /// the units
+    fn duration_parts(duration: Duration) -> Vec<(String, &'static str, &'static str)> {
+        let nanos = duration.as_nanos();
+        let secs = duration.as_secs();
+        let part = |amount: u128, short, long| (amount.to_string(), short, long);
+
+        match secs {
+            0 if nanos < 1000 => vec![part(nanos, "ns", "nanosecond")],
+            0 if nanos < 1_000_000 => vec![part(nanos / 1000, "µs", "microsecond")],
+            0 => vec![part(nanos / 1_000_000, "ms", "millisecond")],
+            1..=9 => {
+                let tenths = duration.subsec_millis() / 100;
+                let amount = if tenths == 0 {
+                    secs.to_string()
+                } else {
+                    format!("{}.{}", secs, tenths)
+                };
+                vec![(amount, "s", "second")]
+            }
+            10..=59 => vec![part(secs.into(), "s", "second")],
+            60..=3599 => vec![
+                part((secs / 60).into(), "m", "minute"),
+                part((secs % 60).into(), "s", "second"),
+            ],
+            3600..=86399 => vec![
+                part((secs / 3600).into(), "h", "hour"),
+                part((secs / 60 % 60).into(), "m", "minute"),
+            ],
+            _ => vec![
+                part((secs / 86400).into(), "d", "day"),
+                part((secs / 3600 % 24).into(), "h", "hour"),
+            ],
+        }
+    }
+
+    ///
+    /// Runs the function and returns its result together with how long it took
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::measure;
+    ///
+    /// fn fibonacci(n: u64) -> u64 {
+    ///     if n < 2 {
+    ///         n
+    ///     } else {
+    ///         fibonacci(n - 1) + fibonacci(n - 2)
+    ///     }
+    /// }
+    ///
+    /// let (result, time) = measure(|| fibonacci(25));
+    /// println!("fibonacci(25) is {} and took {:?} to compute", result, time);
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`Stopwatch`]
+    pub fn measure<T>(function: impl FnOnce() -> T) -> (T, Duration) {
+        let start = Instant::now();
+        let result = function();
+        (result, start.elapsed())
+    }
+
+    ///
+    /// Runs the function `iterations` times and measures how long the fastest, the average and
+    /// the slowest run took
+    ///
+    /// The result can be printed with [`Benchmark::print`], or used like any other value.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::benchmark;
+    ///
+    /// let numbers = (0..10_000).rev().collect::<Vec<u32>>();
+    /// benchmark(100, || {
+    ///     let mut numbers = numbers.clone();
+    ///     numbers.sort();
+    ///     numbers
+    /// })
+    /// .print();
+    /// // prints something like "100 iterations: min 95µs, avg 110µs, max 240µs"
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `iterations` is 0.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// Benchmarks that can be trusted need a lot more, like warming up and statistics, which
+    /// crates like `criterion` do. This is only a first look at how fast something is.
+    pub fn benchmark<T>(iterations: u32, mut function: impl FnMut() -> T) -> Benchmark {
+        assert!(iterations > 0, "can't benchmark with 0 iterations");
+
+        let mut min = Duration::MAX;
+        let mut max = Duration::ZERO;
+        let mut total = Duration::ZERO;
+        for _ in 0..iterations {
+            let start = Instant::now();
+            // so that the compiler doesn't remove the work because the result isn't used
+            std::hint::black_box(function());
+            let time = start.elapsed();
+            min = min.min(time);
+            max = max.max(time);
+            total += time;
+        }
+        Benchmark {
+            iterations,
+            min,
+            max,
+            total,
+        }
+    }
+
+    /// How long the runs of [`benchmark`] took
+    ///
+    /// Printing it shows all of it, like `100 iterations: min 95µs, avg 110µs, max 240µs`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Benchmark {
+        iterations: u32,
+        min: Duration,
+        max: Duration,
+        total: Duration,
+    }
+
+    impl Benchmark {
+        /// Returns how often the function was run
+        pub fn iterations(&self) -> u32 {
+            self.iterations
+        }
+
+        /// Returns the time of the fastest run
+        pub fn min(&self) -> Duration {
+            self.min
+        }
+
+        /// Returns the average time of a run
+        pub fn average(&self) -> Duration {
+            self.total / self.iterations
+        }
+
+        /// Returns the time of the slowest run
+        pub fn max(&self) -> Duration {
+            self.max
+        }
+
+        /// Returns the time of all runs together
+        pub fn total(&self) -> Duration {
+            self.total
+        }
+
+        /// Prints the times on one line, like `100 iterations: min 95µs, avg 110µs, max 240µs`
+        pub fn print(&self) {
+            outputln!("{}", self);
+        }
+    }
+
+    impl Display for Benchmark {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(
+                f,
+                "{} iteration{}: min {}, avg {}, max {}",
+                self.iterations,
+                if self.iterations == 1 { "" } else { "s" },
+                format_duration(self.min),
+                format_duration(self.average()),
+                format_duration(self.max)
+            )
+        }
+    }
+
+    ///
+    /// Waits for the number of seconds, which can have a fractional part
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::sleep_secs;
+    ///
+    /// println!("The door opens slowly...");
+    /// sleep_secs(1.5);
+    /// println!("A monster appears!");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `seconds` is negative, not a number, or too big, like infinity.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// It is, as [`std::thread::sleep`] together with [`Duration::from_secs_f64`], which is
+    /// re-exported as [`sleep`](crate::sleep) here, but having to create a `Duration` makes
+    /// something so simple look complicated.
+    pub fn sleep_secs(seconds: f64) {
+        assert!(
+            seconds >= 0.0,
+            "can't sleep for {} seconds, it must be a positive number or 0",
+            seconds
+        );
+        let duration = Duration::try_from_secs_f64(seconds).unwrap_or_else(|_| {
+            panic!(
+                "can't sleep for {} seconds, that is too long to wait",
+                seconds
+            )
+        });
+        std::thread::sleep(duration);
+    }
+
+    ///
+    /// Waits for the number of milliseconds, of which there are 1000 in a second
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::sleep_millis;
+    ///
+    /// for frame in ["|", "/", "-", "\\"].iter().cycle().take(8) {
+    ///     print!("\r{}", frame);
+    ///     sleep_millis(100);
+    /// }
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`sleep_secs`]
+    pub fn sleep_millis(millis: u64) {
+        std::thread::sleep(Duration::from_millis(millis));
+    }
+
+    ///
+    /// Counts down, calling the callback with the time that is left every second, and returns
+    /// when the time is up
+    ///
+    /// The callback is first called right away with the whole `duration`. Use
+    /// [`countdown_in_background`] to do something else during the countdown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::countdown;
+    /// use std::time::Duration;
+    ///
+    /// println!("The rocket starts in");
+    /// countdown(Duration::from_secs(3), |remaining| {
+    ///     println!("{}...", remaining.as_secs());
+    /// });
+    /// println!("Liftoff!");
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// It can be written with [`sleep`](crate::sleep) and a loop, but the loop has to use
+    /// [`Instant`] so that the time the callback takes doesn't make the countdown too slow.
+    pub fn countdown(duration: Duration, callback: impl FnMut(Duration)) {
+        run_countdown(duration, callback, |time| {
+            std::thread::sleep(time);
+            true
+        });
+    }
+
+    ///
+    /// Counts down on another thread like [`countdown`], and returns right away
+    ///
+    /// The countdown stops when the returned [`Countdown`] is dropped.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::{countdown_in_background, input};
+    /// use std::time::Duration;
+    ///
+    /// let timer = countdown_in_background(Duration::from_secs(10), |remaining| {
+    ///     if remaining.as_secs() <= 3 {
+    ///         println!("{} seconds left!", remaining.as_secs());
+    ///     }
+    /// });
+    /// println!("What is 7 * 8?");
+    /// let answer = input();
+    /// if timer.is_finished() {
+    ///     println!("Too slow!");
+    /// } else if answer == "56" {
+    ///     println!("Correct, with {:.1?} left", timer.remaining());
+    /// }
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`countdown`]
+    pub fn countdown_in_background(
+        duration: Duration,
+        callback: impl FnMut(Duration) + Send + 'static,
+    ) -> Countdown {
+        let (stop, stopped) = mpsc::channel::<()>();
+        let end = Instant::now() + duration;
+        let thread = std::thread::spawn(move || {
+            // both a message and the `Countdown` being dropped stop it
+            run_countdown(duration, callback, |time| {
+                matches!(stopped.recv_timeout(time), Err(RecvTimeoutError::Timeout))
+            });
+        });
+        Countdown {
+            end,
+            stop: Some(stop),
+            thread: Some(thread),
+        }
+    }
+
+    /// calls the callback every second, and calls `wait` with the time until the next call,
+    /// which returns `false` if the countdown should stop
+    fn run_countdown(
+        duration: Duration,
+        mut callback: impl FnMut(Duration),
+        mut wait: impl FnMut(Duration) -> bool,
+    ) {
+        let start = Instant::now();
+        let mut passed = Duration::ZERO;
+        while passed < duration {
+            callback(duration - passed);
+            passed = (passed + Duration::from_secs(1)).min(duration);
+            // waiting until a fixed point in time means that the callback doesn't add up
+            if !wait((start + passed).saturating_duration_since(Instant::now())) {
+                return;
+            }
+        }
+    }
+
+    /// A countdown running on another thread, see [`countdown_in_background`]
+    ///
+    /// It stops when it is dropped.
+    #[derive(Debug)]
+    pub struct Countdown {
+        end: Instant,
+        stop: Option<Sender<()>>,
+        thread: Option<JoinHandle<()>>,
+    }
+
+    impl Countdown {
+        /// Returns the time that is left until the countdown is done
+        pub fn remaining(&self) -> Duration {
+            self.end.saturating_duration_since(Instant::now())
+        }
+
+        /// Returns whether the time is up, or the countdown was stopped
+        pub fn is_finished(&self) -> bool {
+            self.thread.as_ref().map_or(true, JoinHandle::is_finished)
+        }
+
+        /// Waits until the time is up
+        pub fn wait(mut self) {
+            if let Some(thread) = self.thread.take() {
+                let _ = thread.join();
+            }
+        }
+
+        /// Stops the countdown, so that the callback isn't called anymore
+        pub fn stop(self) {}
+    }
+
+    impl Drop for Countdown {
+        fn drop(&mut self) {
+            drop(self.stop.take());
+            if let Some(thread) = self.thread.take() {
+                let _ = thread.join();
+            }
+        }
+    }
+
+    ///
+    /// Calls the callback on another thread again and again, with `interval` between the calls,
+    /// until the returned [`Repeater`] is dropped
+    ///
+    /// The first call is right away. If a call takes longer than `interval`, the next one is
+    /// right after it.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use simple_std::{input, repeat_every, Stopwatch};
+    /// use std::time::Duration;
+    ///
+    /// let stopwatch = Stopwatch::start();
+    /// let clock = repeat_every(Duration::from_secs(1), move || {
+    ///     println!("{} since the start", stopwatch);
+    /// });
+    /// println!("Press enter to stop the clock");
+    /// input();
+    /// drop(clock);
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// It can be done with [`std::thread::spawn`] and a loop, but stopping the thread again
+    /// needs a channel or an atomic.
+    pub fn repeat_every(
+        interval: Duration,
+        mut callback: impl FnMut() + Send + 'static,
+    ) -> Repeater {
+        let (stop, stopped) = mpsc::channel::<()>();
+        let thread = std::thread::spawn(move || {
+            let mut next = Instant::now();
+            loop {
+                callback();
+                // a fixed schedule means that the time the callback takes doesn't add up
+                next = (next + interval).max(Instant::now());
+                let wait = next.saturating_duration_since(Instant::now());
+                // both a message and the `Repeater` being dropped stop it
+                if !matches!(stopped.recv_timeout(wait), Err(RecvTimeoutError::Timeout)) {
+                    return;
+                }
+            }
+        });
+        Repeater {
+            stop: Some(stop),
+            thread: Some(thread),
+        }
+    }
+
+    ///
+    /// Runs the function on another thread, and returns its result, or `None` if it takes
+    /// longer than `timeout`
+    ///
+    /// The function keeps running in the background after the timeout, since threads can't be
+    /// stopped from the outside, but its result is thrown away.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::with_timeout;
+    /// use std::time::Duration;
+    ///
+    /// fn collatz_steps(mut n: u64) -> u64 {
+    ///     let mut steps = 0;
+    ///     while n != 1 {
+    ///         n = if n % 2 == 0 { n / 2 } else { 3 * n + 1 };
+    ///         steps += 1;
+    ///     }
+    ///     steps
+    /// }
+    ///
+    /// match with_timeout(Duration::from_secs(1), || collatz_steps(27)) {
+    ///     Some(steps) => println!("27 needs {} steps", steps),
+    ///     None => println!("that took too long, maybe it never ends"),
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function panics before the timeout.
+    ///
+    /// # Why is this not in std?
+    ///
+    /// It can be done with [`std::thread::spawn`] and [`mpsc::Receiver::recv_timeout`], but
+    /// that's a lot to know about threads and channels.
+    pub fn with_timeout<T: Send + 'static>(
+        timeout: Duration,
+        function: impl FnOnce() -> T + Send + 'static,
+    ) -> Option<T> {
+        let (sender, receiver) = mpsc::channel();
+        let thread = std::thread::spawn(move || {
+            // nobody is waiting anymore if it fails
+            let _ = sender.send(function());
+        });
+        match receiver.recv_timeout(timeout) {
+            Ok(value) => Some(value),
+            Err(RecvTimeoutError::Timeout) => None,
+            // the function panicked before it could send anything
+            Err(RecvTimeoutError::Disconnected) => match thread.join() {
+                Err(panic) => std::panic::resume_unwind(panic),
+                Ok(()) => unreachable!("the thread ended without sending its result"),
+            },
+        }
+    }
+
+    /// Stops calling the callback when it is dropped, see [`repeat_every`]
+    #[derive(Debug)]
+    pub struct Repeater {
+        stop: Option<Sender<()>>,
+        thread: Option<JoinHandle<()>>,
+    }
+
+    impl Repeater {
+        /// Stops calling the callback, like dropping it
+        pub fn stop(self) {}
+    }
+
+    impl Drop for Repeater {
+        fn drop(&mut self) {
+            drop(self.stop.take());
+            if let Some(thread) = self.thread.take() {
+                // the callback can't be called anymore after the repeater is gone
+                if thread.thread().id() != std::thread::current().id() {
+                    let _ = thread.join();
+                }
+            }
+        }
+    }
+
+    ///
+    /// Returns the current date and time, like `2024-05-01 13:37:09`
+    ///
+    /// The time is the local time of the computer, in the time zone that it is set to.
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::now_string;
+    ///
+    /// println!("The program started at {}", now_string());
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// The time zone rules that are needed for the local time are complicated and change all
+    /// the time, so they are left to crates like `chrono` or `time`. [`SystemTime`] only gives
+    /// the time since 1970 in UTC.
+    pub fn now_string() -> String {
+        format_timestamp(local_seconds())
+    }
+
+    ///
+    /// Returns the current date in the local time of the computer
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::today;
+    ///
+    /// let today = today();
+    /// println!("Today is {}", today);
+    /// if (today.month(), today.day()) == (12, 24) {
+    ///     println!("Merry Christmas!");
+    /// }
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`now_string`]
+    pub fn today() -> Date {
+        Date::from_days_since_epoch(local_seconds().div_euclid(86400))
+    }
+
+    ///
+    /// Returns the current time of the day in the local time of the computer, like `13:37:09`
+    ///
+    /// # Example
+    /// ```
+    /// use simple_std::time_now;
+    ///
+    /// println!("It is {} o'clock", &time_now()[..5]);
+    /// ```
+    ///
+    /// # Why is this not in std?
+    ///
+    /// See [`now_string`]
+    pub fn time_now() -> String {
+        format_time_of_day(local_seconds())
+    }
+
+    /// the seconds since 1970-01-01 00:00:00 in the local time
+    fn local_seconds() -> i64 {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_secs() as i64);
+        seconds + local_offset::utc_offset(seconds)
+    }
+
+    /// formats the time that is `seconds` after 1970-01-01 00:00:00 like [`now_string`]
+    fn format_timestamp(seconds: i64) -> String {
+        let date = Date::from_days_since_epoch(seconds.div_euclid(86400));
+        format!("{} {}", date, format_time_of_day(seconds))
+    }
+
+    /// formats the time of the day of the time that is `seconds` after 1970-01-01 00:00:00 like
+    /// [`time_now`]
+    fn format_time_of_day(seconds: i64) -> String {
+        let time = seconds.rem_euclid(86400);
+        format!("{:02}:{:02}:{:02}", time / 3600, time / 60 % 60, time % 60)
+    }
+
+    /// how many seconds the local time is ahead of UTC, from the operating system
+    mod local_offset {
+        #[cfg(unix)]
+        pub(super) fn utc_offset(utc_seconds: i64) -> i64 {
+            use std::os::raw::{c_int, c_long};
+
+            /// `struct tm`, of which only the fields that every platform has are used
+            #[repr(C)]
+            struct Tm {
+                second: c_int,
+                minute: c_int,
+                hour: c_int,
+                day: c_int,
+                month: c_int,
+                years_since_1900: c_int,
+                weekday: c_int,
+                yearday: c_int,
+                is_dst: c_int,
+                // room for the fields that only some platforms have, like `tm_gmtoff`
+                _rest: [usize; 4],
+            }
+
+            extern "C" {
+                fn localtime_r(time: *const c_long, result: *mut Tm) -> *mut Tm;
+            }
+
+            let time = utc_seconds as c_long;
+            // SAFETY: `Tm` only contains integers, so all zeros are a valid value
+            let mut tm = unsafe { std::mem::zeroed::<Tm>() };
+            // SAFETY: both pointers are valid, and `Tm` is at least as big as `struct tm`
+            let result = unsafe { localtime_r(&time, &mut tm) };
+            if result.is_null() {
+                return 0;
+            }
+
+            let date =
+                super::Date::new(tm.years_since_1900 + 1900, tm.month as u8 + 1, tm.day as u8);
+            let local_seconds = date.days_since_epoch() * 86400
+                + i64::from(tm.hour) * 3600
+                + i64::from(tm.minute) * 60
+                + i64::from(tm.second);
+            local_seconds - utc_seconds
+        }
+
+        #[cfg(windows)]
+        pub(super) fn utc_offset(_: i64) -> i64 {
+            /// `SYSTEMTIME`
+            #[repr(C)]
+            #[derive(Default)]
+            struct SystemTime {
+                year: u16,
+                month: u16,
+                weekday: u16,
+                day: u16,
+                hour: u16,
+                minute: u16,
+                second: u16,
+                milliseconds: u16,
+            }
+
+            #[link(name = "kernel32")]
+            extern "system" {
+                fn GetSystemTime(time: *mut SystemTime);
+                fn GetLocalTime(time: *mut SystemTime);
+            }
+
+            let seconds = |time: &SystemTime| {
+                let date = super::Date::new(time.year.into(), time.month as u8, time.day as u8);
+                date.days_since_epoch() * 86400
+                    + i64::from(time.hour) * 3600
+                    + i64::from(time.minute) * 60
+                    + i64::from(time.second)
+            };
+
+            let mut utc = SystemTime::default();
+            let mut local = SystemTime::default();
+            // SAFETY: both functions only write a `SYSTEMTIME`, which has the layout of
+            // `SystemTime`
+            unsafe {
+                GetSystemTime(&mut utc);
+                GetLocalTime(&mut local);
+            }
+            // the second might have changed between the two calls, but time zones are always
+            // whole minutes away from UTC
+            (seconds(&local) - seconds(&utc) + 30).div_euclid(60) * 60
+        }
+
+        // there is no known way to get the time zone on the other platforms, so they use UTC
+        #[cfg(not(any(unix, windows)))]
+        pub(super) fn utc_offset(_: i64) -> i64 {
+            0
+        }
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+    }
+
+    fn days_in_month(year: i32, month: u8) -> u8 {
+        match month {
+            2 if is_leap_year(year) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::{format_time_of_day, format_timestamp, local_offset, Benchmark};
+        use crate::testing::capture_output;
+        use crate::{benchmark, countdown, countdown_in_background, now_string};
+        use crate::{format_duration, format_duration_verbose, measure};
+        use crate::{repeat_every, time_now, today, with_timeout, Date, Stopwatch};
+        use crate::{sleep_millis, sleep_secs};
+        use std::time::Duration;
+
+        #[test]
+        fn display() {
+            assert_eq!(Date::new(2024, 5, 1).to_string(), "2024-05-01");
+            assert_eq!(Date::new(999, 12, 31).to_string(), "0999-12-31");
+        }
+
+        #[test]
+        fn leap_years() {
+            Date::new(2024, 2, 29);
+            Date::new(2000, 2, 29);
+            assert!(std::panic::catch_unwind(|| Date::new(1900, 2, 29)).is_err());
+            assert!(std::panic::catch_unwind(|| Date::new(2023, 2, 29)).is_err());
+            assert!(std::panic::catch_unwind(|| Date::new(2023, 4, 31)).is_err());
+            assert!(std::panic::catch_unwind(|| Date::new(2023, 13, 1)).is_err());
+            assert!(std::panic::catch_unwind(|| Date::new(2023, 1, 0)).is_err());
+        }
+
+        #[test]
+        fn parse() {
+            let may_first = Ok(Date::new(2024, 5, 1));
+            [
+                "2024-05-01",
+                "2024-5-1",
+                "01.05.2024",
+                "01/05/2024",
+                "1/5/2024",
+                "May 1 2024",
+                "may 1, 2024",
+                "1 May 2024",
+                "1. May 2024",
+                " 2024 05 01 ",
+            ]
+            .iter()
+            .for_each(|date| assert_eq!(date.parse::<Date>(), may_first, "{}", date));
+
+            assert_eq!("Sept 30 2023".parse(), Ok(Date::new(2023, 9, 30)));
+            assert_eq!("29 feb 2024".parse(), Ok(Date::new(2024, 2, 29)));
+
+            [
+                "",
+                "2024",
+                "2024-05",
+                "2024-05-01-01",
+                "2023-02-29",
+                "2024-13-01",
+                "32/01/2024",
+                "0/1/2024",
+                "Ma 1 2024",
+                "Mayday 1 2024",
+                "yesterday",
+            ]
+            .iter()
+            .for_each(|date| assert!(date.parse::<Date>().is_err(), "{}", date));
+        }
+
+        #[test]
+        fn durations() {
+            let millis = Duration::from_millis;
+            let secs = Duration::from_secs;
+            assert_eq!(format_duration(Duration::ZERO), "0ns");
+            assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+            assert_eq!(format_duration(Duration::from_micros(1500)), "1ms");
+            assert_eq!(format_duration(Duration::from_nanos(51_200)), "51µs");
+            assert_eq!(format_duration(millis(999)), "999ms");
+            assert_eq!(format_duration(millis(1000)), "1s");
+            assert_eq!(format_duration(millis(9_999)), "9.9s");
+            assert_eq!(format_duration(millis(59_999)), "59s");
+            assert_eq!(format_duration(secs(60)), "1m 00s");
+            assert_eq!(format_duration(secs(3 * 3600 + 5 * 60 + 9)), "3h 05m");
+            assert_eq!(format_duration(secs(2 * 86400 + 3600)), "2d 01h");
+
+            assert_eq!(format_duration_verbose(Duration::ZERO), "0 nanoseconds");
+            assert_eq!(format_duration_verbose(millis(450)), "450 milliseconds");
+            assert_eq!(format_duration_verbose(millis(1000)), "1 second");
+            assert_eq!(format_duration_verbose(millis(2500)), "2.5 seconds");
+            assert_eq!(format_duration_verbose(secs(61)), "1 minute 1 second");
+            assert_eq!(format_duration_verbose(secs(120)), "2 minutes");
+            assert_eq!(format_duration_verbose(secs(86400 + 7200)), "1 day 2 hours");
+        }
+
+        #[test]
+        fn stopwatch() {
+            let mut stopwatch = Stopwatch::start();
+            std::thread::sleep(Duration::from_millis(20));
+            let first = stopwatch.lap();
+            assert!(first >= Duration::from_millis(20));
+
+            stopwatch.pause();
+            assert!(stopwatch.is_paused());
+            let paused = stopwatch.elapsed();
+            std::thread::sleep(Duration::from_millis(20));
+            assert_eq!(stopwatch.elapsed(), paused);
+            assert_eq!(stopwatch.to_string(), format_duration(paused));
+
+            stopwatch.resume();
+            stopwatch.resume();
+            assert!(!stopwatch.is_paused());
+            std::thread::sleep(Duration::from_millis(5));
+            let second = stopwatch.lap();
+            assert!(second >= Duration::from_millis(5));
+            assert!(first + second <= stopwatch.elapsed());
+        }
+
+        #[test]
+        fn measuring() {
+            let (result, time) = measure(|| {
+                sleep_millis(10);
+                7
+            });
+            assert_eq!(result, 7);
+            assert!(time >= Duration::from_millis(10));
+
+            let output = capture_output(|| {
+                let sum = time_it!("adding", {
+                    let one = 1;
+                    one + 2
+                });
+                assert_eq!(sum, 3);
+                let numbers = time_it!(vec![1, 2]);
+                assert_eq!(numbers, [1, 2]);
+            });
+            let lines = output.lines().collect::<Vec<_>>();
+            assert!(lines[0].starts_with("adding took "), "{}", lines[0]);
+            assert!(lines[1].starts_with("vec![1, 2] took "), "{}", lines[1]);
+        }
+
+        #[test]
+        fn countdowns() {
+            let mut calls = Vec::new();
+            let stopwatch = Stopwatch::start();
+            countdown(Duration::from_millis(1500), |remaining| {
+                calls.push(remaining)
+            });
+            assert!(stopwatch.elapsed() >= Duration::from_millis(1500));
+            assert_eq!(
+                calls,
+                [Duration::from_millis(1500), Duration::from_millis(500)]
+            );
+
+            countdown(Duration::ZERO, |_| panic!("there is no time to count down"));
+        }
+
+        #[test]
+        fn background_countdowns() {
+            let (sender, receiver) = std::sync::mpsc::channel();
+            let timer = countdown_in_background(Duration::from_millis(100), move |remaining| {
+                sender.send(remaining).unwrap();
+            });
+            assert!(timer.remaining() <= Duration::from_millis(100));
+            timer.wait();
+            assert_eq!(
+                receiver.try_iter().collect::<Vec<_>>(),
+                [Duration::from_millis(100)]
+            );
+
+            let (sender, receiver) = std::sync::mpsc::channel();
+            let stopwatch = Stopwatch::start();
+            let timer = countdown_in_background(Duration::from_secs(60), move |remaining| {
+                sender.send(remaining).unwrap();
+            });
+            assert_eq!(receiver.recv(), Ok(Duration::from_secs(60)));
+            assert!(!timer.is_finished());
+            timer.stop();
+            assert!(stopwatch.elapsed() < Duration::from_secs(1));
+            // the callback was dropped together with the thread
+            assert!(receiver.recv().is_err());
+        }
+
+        #[test]
+        fn repeating() {
+            use std::sync::atomic::{AtomicU32, Ordering};
+            use std::sync::Arc;
+
+            let calls = Arc::new(AtomicU32::new(0));
+            let counter = Arc::clone(&calls);
+            let repeater = repeat_every(Duration::from_millis(20), move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+            sleep_millis(110);
+            repeater.stop();
+            let count = calls.load(Ordering::SeqCst);
+            assert!((2..=7).contains(&count), "{}", count);
+
+            // no calls after it was stopped
+            sleep_millis(50);
+            assert_eq!(calls.load(Ordering::SeqCst), count);
+        }
+
+        #[test]
+        fn timeouts() {
+            assert_eq!(with_timeout(Duration::from_secs(10), || 1 + 2), Some(3));
+            let stopwatch = Stopwatch::start();
+            let result = with_timeout(Duration::from_millis(20), || {
+                sleep_secs(1.0);
+                "too late"
+            });
+            assert_eq!(result, None);
+            assert!(stopwatch.elapsed() < Duration::from_secs(1));
+
+            let panicked = std::panic::catch_unwind(|| {
+                with_timeout(Duration::from_secs(10), || panic!("oh no"));
+            });
+            assert_eq!(panicked.unwrap_err().downcast_ref(), Some(&"oh no"));
+        }
+
+        #[test]
+        fn benchmarks() {
+            let mut runs = 0;
+            let result = benchmark(5, || {
+                runs += 1;
+                sleep_millis(runs);
+            });
+            assert_eq!(runs, 5);
+            assert_eq!(result.iterations(), 5);
+            assert!(result.min() >= Duration::from_millis(1));
+            assert!(result.min() <= result.average() && result.average() <= result.max());
+            assert!(result.max() >= Duration::from_millis(5));
+            assert!(result.total() >= Duration::from_millis(1 + 2 + 3 + 4 + 5));
+
+            let result = Benchmark {
+                iterations: 4,
+                min: Duration::from_millis(1),
+                max: Duration::from_millis(7),
+                total: Duration::from_millis(10),
+            };
+            assert_eq!(
+                capture_output(|| result.print()),
+                "4 iterations: min 1ms, avg 2ms, max 7ms\n"
+            );
+            assert!(benchmark(1, || ())
+                .to_string()
+                .starts_with("1 iteration: min "));
+            assert!(std::panic::catch_unwind(|| benchmark(0, || ())).is_err());
+        }
+
+        #[test]
+        fn sleeping() {
+            let stopwatch = Stopwatch::start();
+            sleep_secs(0.01);
+            sleep_millis(10);
+            sleep_secs(0.0);
+            assert!(stopwatch.elapsed() >= Duration::from_millis(20));
+            let message = |seconds| {
+                let panic = std::panic::catch_unwind(|| sleep_secs(seconds)).unwrap_err();
+                panic.downcast_ref::<String>().unwrap().clone()
+            };
+            assert_eq!(
+                message(-1.0),
+                "can't sleep for -1 seconds, it must be a positive number or 0"
+            );
+            assert_eq!(
+                message(f64::NAN),
+                "can't sleep for NaN seconds, it must be a positive number or 0"
+            );
+            assert_eq!(
+                message(f64::INFINITY),
+                "can't sleep for inf seconds, that is too long to wait"
+            );
+            assert_eq!(
+                message(1e30),
+                "can't sleep for 1000000000000000000000000000000 seconds, that is too long to wait"
+            );
+        }
+
+        #[test]
+        fn timestamps() {
+            assert_eq!(format_timestamp(0), "1970-01-01 00:00:00");
+            assert_eq!(format_timestamp(1_714_570_629), "2024-05-01 13:37:09");
+            assert_eq!(format_timestamp(-1), "1969-12-31 23:59:59");
+            assert_eq!(format_time_of_day(1_714_570_629), "13:37:09");
+        }
+
+        #[test]
+        fn now() {
+            let now = now_string();
+            let (date, time) = now.split_once(' ').unwrap();
+            // the day might change in between
+            assert!(date.parse::<Date>().is_ok(), "{}", now);
+            assert!(date == today().to_string() || now_string() > now);
+            assert_eq!(time.len(), 8);
+            assert_eq!(time_now().len(), 8);
+
+            let offset = local_offset::utc_offset(1_714_570_629);
+            assert!(offset.abs() <= 14 * 3600, "{}", offset);
+            assert_eq!(offset % 60, 0, "{}", offset);
+        }
+
+        #[test]
+        fn days_since_epoch() {
+            assert_eq!(Date::new(1970, 1, 1).days_since_epoch(), 0);
+            assert_eq!(Date::new(1970, 1, 2).days_since_epoch(), 1);
+            assert_eq!(Date::new(1969, 12, 31).days_since_epoch(), -1);
+            assert_eq!(Date::new(2000, 3, 1).days_since_epoch(), 11017);
+
+            (-800_000..800_000).step_by(7).for_each(|days| {
+                let date = Date::from_days_since_epoch(days);
+                assert_eq!(Date::new(date.year(), date.month(), date.day()), date);
+                assert_eq!(date.days_since_epoch(), days);
+            });
+        }
+    }
+}
+
+/// the platform specific parts of handling the terminal
+mod sys {
+    /// restores the previous mode of the terminal when dropped
+    pub(crate) struct ModeGuard {
+        #[cfg(unix)]
+        saved: String,
+        #[cfg(windows)]
+        saved: u32,
+    }
+
+    /// stops showing typed characters until the guard is dropped, or returns `None` if the input
+    /// isn't a terminal
+    pub(crate) fn disable_echo() -> Option<ModeGuard> {
+        imp::set_mode(imp::DISABLE_ECHO)
+    }
+
+    /// makes every key press available immediately without showing it, until the guard is
+    /// dropped, or returns `None` if the input isn't a terminal
+    ///
+    /// Ctrl+C is still sent as a signal on Windows, but arrives as `\x03` on unix.
+    pub(crate) fn raw_mode() -> Option<ModeGuard> {
+        imp::set_mode(imp::RAW_MODE)
+    }
+
+    impl Drop for ModeGuard {
+        fn drop(&mut self) {
+            imp::restore(self);
+        }
+    }
+
+    /// something that the Windows console can do without escape sequences
+    #[derive(Debug, Clone, Copy)]
+    // the fields are only used on Windows
+    #[cfg_attr(not(windows), allow(dead_code))]
+    pub(crate) enum ConsoleCommand {
+        Clear,
+        MoveCursor { row: u16, column: u16 },
+        ShowCursor(bool),
+    }
+
+    /// runs the command with the functions of the Windows console, or returns `false` if the
+    /// output isn't one, or this isn't Windows
+    pub(crate) fn run_console_command(command: ConsoleCommand) -> bool {
+        imp::run_console_command(command)
+    }
+
+    /// the columns and rows of the terminal that the output goes to
+    pub(crate) fn terminal_size() -> Option<(u16, u16)> {
+        imp::terminal_size()
+    }
+
+    /// makes the terminal understand escape sequences for colors and moving the cursor, or
+    /// returns `false` if it can't
+    ///
+    /// This is only needed on Windows, where the console only understands them if it is told
+    /// to, and older versions don't understand them at all.
+    pub(crate) fn enable_escape_sequences() -> bool {
+        static ENABLED: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
+        *ENABLED.get_or_init(imp::enable_escape_sequences)
+    }
+
+    #[cfg(unix)]
+    mod imp {
+        use super::ModeGuard;
+        use std::process::{Command, Stdio};
+
+        // `stty` is used instead of calling `tcsetattr` directly, since the layout of `termios`
+        // is different on every platform and `stty` is available on every unix
+
+        pub(super) const DISABLE_ECHO: &[&str] = &["-echo"];
+        // `-isig` so that Ctrl+C doesn't kill the program while the terminal is in raw mode,
+        // which would leave it like that
+        pub(super) const RAW_MODE: &[&str] =
+            &["-icanon", "-echo", "-isig", "min", "1", "time", "0"];
+
+        pub(super) fn set_mode(args: &[&str]) -> Option<ModeGuard> {
+            let saved = stty(&["-g"])?;
+            stty(args)?;
+            Some(ModeGuard {
+                saved: saved.trim().to_owned(),
+            })
+        }
+
+        pub(super) fn restore(guard: &ModeGuard) {
+            let _ = stty(&[&guard.saved]);
+        }
+
+        pub(super) fn run_console_command(_: super::ConsoleCommand) -> bool {
+            false
+        }
+
+        pub(super) fn enable_escape_sequences() -> bool {
+            true
+        }
+
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        const TIOCGWINSZ: std::os::raw::c_ulong = 0x5413;
+        #[cfg(any(
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "freebsd",
+            target_os = "openbsd",
+            target_os = "netbsd",
+            target_os = "dragonfly"
+        ))]
+        const TIOCGWINSZ: std::os::raw::c_ulong = 0x40087468;
+
+        #[cfg(any(
+            target_os = "linux",
+            target_os = "android",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "freebsd",
+            target_os = "openbsd",
+            target_os = "netbsd",
+            target_os = "dragonfly"
+        ))]
+        pub(super) fn terminal_size() -> Option<(u16, u16)> {
+            extern "C" {
+                fn ioctl(fd: i32, request: std::os::raw::c_ulong, ...) -> i32;
+            }
+
+            /// `winsize`
+            #[repr(C)]
+            #[derive(Default)]
+            struct WindowSize {
+                rows: u16,
+                columns: u16,
+                width: u16,
+                height: u16,
+            }
+
+            // stdout first, but if it is redirected, the others might still be the terminal
+            [1, 2, 0].iter().find_map(|&fd| {
+                let mut size = WindowSize::default();
+                // SAFETY: TIOCGWINSZ only writes a `winsize`, which has the layout of `WindowSize`
+                let result = unsafe { ioctl(fd, TIOCGWINSZ, &mut size as *mut WindowSize) };
+                if result == 0 {
+                    Some((size.columns, size.rows))
+                } else {
+                    None
+                }
+            })
+        }
+
+        // the number of the request is different on the other platforms
+        #[cfg(not(any(
+            target_os = "linux",
+            target_os = "android",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "freebsd",
+            target_os = "openbsd",
+            target_os = "netbsd",
+            target_os = "dragonfly"
+        )))]
+        pub(super) fn terminal_size() -> Option<(u16, u16)> {
+            None
+        }
+
+        /// runs `stty` on the terminal of stdin, returning its output if it was successful
+        fn stty(args: &[&str]) -> Option<String> {
+            let output = Command::new("stty")
+                .args(args)
+                .stdin(Stdio::inherit())
+                .stderr(Stdio::null())
+                .output()
+                .ok()?;
+
+            if output.status.success() {
+                String::from_utf8(output.stdout).ok()
+            } else {
+                None
+            }
+        }
+    }
+
+    #[cfg(windows)]
+    mod imp {
+        use super::{ConsoleCommand, ModeGuard};
+        use std::ffi::c_void;
+        use std::io::Write;
+
+        const STD_INPUT_HANDLE: u32 = -10i32 as u32;
+        const STD_OUTPUT_HANDLE: u32 = -11i32 as u32;
+        const STD_ERROR_HANDLE: u32 = -12i32 as u32;
+        const ENABLE_VIRTUAL_TERMINAL_PROCESSING: u32 = 0x0004;
+        const ENABLE_LINE_INPUT: u32 = 0x0002;
+        const ENABLE_ECHO_INPUT: u32 = 0x0004;
+        const ENABLE_VIRTUAL_TERMINAL_INPUT: u32 = 0x0200;
+
+        /// the flags that are removed from and added to the console mode
+        pub(super) const DISABLE_ECHO: (u32, u32) = (ENABLE_ECHO_INPUT, 0);
+        // with virtual terminal input, the arrow keys are sent as the same escape sequences as
+        // on unix
+        pub(super) const RAW_MODE: (u32, u32) = (
+            ENABLE_LINE_INPUT | ENABLE_ECHO_INPUT,
+            ENABLE_VIRTUAL_TERMINAL_INPUT,
+        );
+
+        #[link(name = "kernel32")]
+        extern "system" {
+            fn GetStdHandle(std_handle: u32) -> *mut c_void;
+            fn GetConsoleMode(console: *mut c_void, mode: *mut u32) -> i32;
+            fn SetConsoleMode(console: *mut c_void, mode: u32) -> i32;
+            fn GetConsoleScreenBufferInfo(
+                console: *mut c_void,
+                info: *mut ConsoleScreenBufferInfo,
+            ) -> i32;
+            fn FillConsoleOutputCharacterW(
+                console: *mut c_void,
+                character: u16,
+                length: u32,
+                start: Coord,
+                written: *mut u32,
+            ) -> i32;
+            fn FillConsoleOutputAttribute(
+                console: *mut c_void,
+                attribute: u16,
+                length: u32,
+                start: Coord,
+                written: *mut u32,
+            ) -> i32;
+            fn SetConsoleCursorPosition(console: *mut c_void, position: Coord) -> i32;
+            fn GetConsoleCursorInfo(console: *mut c_void, info: *mut ConsoleCursorInfo) -> i32;
+            fn SetConsoleCursorInfo(console: *mut c_void, info: *const ConsoleCursorInfo) -> i32;
+        }
+
+        #[repr(C)]
+        #[derive(Clone, Copy, Default)]
+        struct Coord {
+            x: i16,
+            y: i16,
+        }
+
+        #[repr(C)]
+        #[derive(Clone, Copy, Default)]
+        struct SmallRect {
+            left: i16,
+            top: i16,
+            right: i16,
+            bottom: i16,
+        }
+
+        #[repr(C)]
+        #[derive(Clone, Copy, Default)]
+        struct ConsoleScreenBufferInfo {
+            size: Coord,
+            cursor_position: Coord,
+            attributes: u16,
+            window: SmallRect,
+            maximum_window_size: Coord,
+        }
+
+        #[repr(C)]
+        #[derive(Clone, Copy, Default)]
+        struct ConsoleCursorInfo {
+            size: u32,
+            visible: i32,
+        }
+
+        /// the handle of the console that the output goes to, and information about it
+        fn output_console() -> Option<(*mut c_void, ConsoleScreenBufferInfo)> {
+            // SAFETY: GetStdHandle has no preconditions
+            let console = unsafe { GetStdHandle(STD_OUTPUT_HANDLE) };
+            let mut info = ConsoleScreenBufferInfo::default();
+            // SAFETY: GetConsoleScreenBufferInfo only writes to `info`
+            if unsafe { GetConsoleScreenBufferInfo(console, &mut info) } == 0 {
+                // not a console
+                None
+            } else {
+                Some((console, info))
+            }
+        }
+
+        pub(super) fn enable_escape_sequences() -> bool {
+            let enable = |std_handle| {
+                // SAFETY: GetStdHandle has no preconditions, and the console functions only
+                // write to `mode`, or fail if the handle isn't a console
+                unsafe {
+                    let console = GetStdHandle(std_handle);
+                    let mut mode = 0;
+                    if GetConsoleMode(console, &mut mode) == 0 {
+                        // not a console, so the escape sequences end up in a file or pipe
+                        return true;
+                    }
+                    mode & ENABLE_VIRTUAL_TERMINAL_PROCESSING != 0
+                        || SetConsoleMode(console, mode | ENABLE_VIRTUAL_TERMINAL_PROCESSING) != 0
+                }
+            };
+            // consoles older than Windows 10 don't know the flag and fail to set it
+            let stderr = enable(STD_ERROR_HANDLE);
+            enable(STD_OUTPUT_HANDLE) && stderr
+        }
+
+        pub(super) fn terminal_size() -> Option<(u16, u16)> {
+            let (_, info) = output_console()?;
+            // the window only shows a part of the buffer
+            let columns = info.window.right - info.window.left + 1;
+            let rows = info.window.bottom - info.window.top + 1;
+            Some((columns.max(0) as u16, rows.max(0) as u16))
+        }
+
+        pub(super) fn run_console_command(command: ConsoleCommand) -> bool {
+            let (console, info) = match output_console() {
+                Some(console) => console,
+                None => return false,
+            };
+            // the text that is still buffered has to be written before the console is changed
+            let _ = std::io::stdout().flush();
+
+            // if any of these fail, the escape sequence is printed instead
+            // SAFETY: the handle is a console, as GetConsoleScreenBufferInfo worked, and all
+            // pointers point to local variables
+            unsafe {
+                match command {
+                    ConsoleCommand::Clear => {
+                        let length = info.size.x as u32 * info.size.y as u32;
+                        let start = Coord::default();
+                        let mut written = 0;
+                        FillConsoleOutputCharacterW(
+                            console,
+                            u16::from(b' '),
+                            length,
+                            start,
+                            &mut written,
+                        ) != 0
+                            && FillConsoleOutputAttribute(
+                                console,
+                                info.attributes,
+                                length,
+                                start,
+                                &mut written,
+                            ) != 0
+                            && SetConsoleCursorPosition(console, start) != 0
+                    }
+                    ConsoleCommand::MoveCursor { row, column } => {
+                        // the rows of the buffer that scrolled out of the window come first
+                        let position = Coord {
+                            x: column.min(i16::MAX as u16) as i16,
+                            y: info
+                                .window
+                                .top
+                                .saturating_add(row.min(i16::MAX as u16) as i16),
+                        };
+                        SetConsoleCursorPosition(console, position) != 0
+                    }
+                    ConsoleCommand::ShowCursor(visible) => {
+                        let mut cursor = ConsoleCursorInfo::default();
+                        GetConsoleCursorInfo(console, &mut cursor) != 0 && {
+                            cursor.visible = i32::from(visible);
+                            SetConsoleCursorInfo(console, &cursor) != 0
+                        }
+                    }
+                }
+            }
+        }
+
+        pub(super) fn set_mode((disable, enable): (u32, u32)) -> Option<ModeGuard> {
+            let saved = console_mode()?;
+            let mode = saved & !disable | enable;
+            // SAFETY: the handle is the standard input, which is a console as GetConsoleMode worked
+            let success = unsafe { SetConsoleMode(GetStdHandle(STD_INPUT_HANDLE), mode) };
+            if success == 0 {
+                None
+            } else {
+                Some(ModeGuard { saved })
+            }
+        }
+
+        pub(super) fn restore(guard: &ModeGuard) {
+            // SAFETY: the handle is the standard input, which was a console when the mode was saved
+            unsafe {
+                SetConsoleMode(GetStdHandle(STD_INPUT_HANDLE), guard.saved);
+            }
+        }
+
+        /// the current mode of the console, or `None` if stdin is not a console
+        fn console_mode() -> Option<u32> {
+            let mut mode = 0;
+            // SAFETY: GetStdHandle has no preconditions, and GetConsoleMode only writes to `mode`
+            let success = unsafe { GetConsoleMode(GetStdHandle(STD_INPUT_HANDLE), &mut mode) };
+            if success == 0 {
+                None
+            } else {
+                Some(mode)
+            }
+        }
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    mod imp {
+        use super::ModeGuard;
+
+        pub(super) const DISABLE_ECHO: () = ();
+        pub(super) const RAW_MODE: () = ();
+
+        pub(super) fn set_mode(_: ()) -> Option<ModeGuard> {
+            None
+        }
+
+        pub(super) fn restore(_: &ModeGuard) {}
+
+        pub(super) fn run_console_command(_: super::ConsoleCommand) -> bool {
+            false
+        }
+
+        pub(super) fn terminal_size() -> Option<(u16, u16)> {
+            None
+        }
+
+        pub(super) fn enable_escape_sequences() -> bool {
+            true
         }
     }
 }